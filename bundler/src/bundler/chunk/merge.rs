@@ -7,6 +7,7 @@ use crate::modules::Modules;
 use crate::{
     bundler::{
         chunk::plan::NormalPlan,
+        finalize::relative_src,
         load::{Imports, Source, Specifier, TransformedModule},
     },
     id::{Id, ModuleId},
@@ -59,7 +60,7 @@ where
                             format!("failed to merge {:?} (circular import)", module_id)
                         })?;
                     if is_entry {
-                        self.replace_import_specifiers(&info, &mut module);
+                        self.replace_import_specifiers(ctx, &info, &mut module);
                         self.finalize_merging_of_entry(ctx, info.id, &mut module);
                     }
                     return Ok(module);
@@ -70,7 +71,7 @@ where
                 .get_module_for_merging(ctx, module_id, is_entry)
                 .with_context(|| format!("failed to clone {:?} for merging", module_id))?;
 
-            self.prepare(&info, &mut module);
+            self.prepare(ctx, &info, &mut module);
 
             {
                 let plan = ctx.plan.normal.get(&module_id);
@@ -105,7 +106,7 @@ where
             // );
 
             if is_entry {
-                self.replace_import_specifiers(&info, &mut module);
+                self.replace_import_specifiers(ctx, &info, &mut module);
                 self.finalize_merging_of_entry(ctx, info.id, &mut module);
             }
 
@@ -130,7 +131,7 @@ where
         // Now we handle imports
         let mut module = if wrapped {
             let mut module: Modules = self.get_module_for_merging(ctx, dep_id, false)?;
-            self.prepare(&dep_info, &mut module);
+            self.prepare(ctx, &dep_info, &mut module);
             module = self.wrap_esm(ctx, dep_id, module.into())?.into();
 
             let plan = ctx.plan.normal.get(&dep_id);
@@ -526,6 +527,57 @@ where
         })
     }
 
+    /// Exports members of a chunk group merged into the bundle `entry_id`.
+    ///
+    /// Members are merged as transitive dependencies, so their exports are
+    /// only available as variables in the export context at this point.
+    fn export_group_members(&self, ctx: &Ctx, entry_id: ModuleId, entry: &mut Modules) {
+        let injected_ctxt = self.injected_ctxt;
+
+        let mut members = ctx
+            .plan
+            .group_members
+            .iter()
+            .filter(|(_, &bundle_id)| bundle_id == entry_id)
+            .map(|(&member, _)| member)
+            .collect::<Vec<_>>();
+        members.sort();
+
+        for member in members {
+            let info = self.scope.get_module(member).unwrap();
+            let mut names = self
+                .exported_names(member)
+                .unwrap_or_default()
+                .into_iter()
+                .collect::<Vec<_>>();
+            names.sort();
+
+            let specifiers = names
+                .into_iter()
+                .map(|name| {
+                    let exported = Ident::new(name, DUMMY_SP.with_ctxt(info.export_ctxt()));
+
+                    ExportSpecifier::Named(ExportNamedSpecifier {
+                        span: DUMMY_SP,
+                        orig: exported.clone(),
+                        exported: Some(exported),
+                    })
+                })
+                .collect();
+
+            entry.append(
+                member,
+                ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+                    span: DUMMY_SP.with_ctxt(injected_ctxt),
+                    specifiers,
+                    src: None,
+                    type_only: false,
+                    asserts: None,
+                })),
+            );
+        }
+    }
+
     /// This should only be called after everything is merged.
     ///
     /// This method does not care about orders of statement, and it's expected
@@ -756,6 +808,7 @@ where
 
     fn finalize_merging_of_entry(&self, ctx: &Ctx, id: ModuleId, entry: &mut Modules) {
         log::debug!("All modules are merged");
+        self.export_group_members(ctx, id, entry);
         self.handle_reexport_of_entry(ctx, id, entry);
 
         // print_hygiene("before inline", &self.cm, &entry.clone().into());
@@ -787,7 +840,9 @@ where
                 }
 
                 ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
-                    if self.config.external_modules.contains(&import.src.value) {
+                    if self.config.external_modules.contains(&import.src.value)
+                        || self.is_chunk_import(ctx, id, import)
+                    {
                         return true;
                     }
 
@@ -830,6 +885,8 @@ where
             true
         });
 
+        self.rewrite_chunk_imports(ctx, id, entry);
+
         entry.visit_mut_with(&mut KeywordRenamer::default());
 
         // print_hygiene(
@@ -842,6 +899,54 @@ where
         // );
     }
 
+    /// Returns true if `import` in `module_id` is an import from a module
+    /// emitted in another bundle.
+    fn is_chunk_import(&self, ctx: &Ctx, module_id: ModuleId, import: &ImportDecl) -> bool {
+        let bundle_id = ctx.plan.bundle_of(module_id);
+
+        ctx.plan
+            .entries
+            .iter()
+            .chain(ctx.plan.group_members.keys())
+            .any(|&id| {
+                ctx.plan.is_split(id)
+                    && ctx.plan.bundle_of(id) != bundle_id
+                    && import.span.ctxt == self.scope.get_module(id).unwrap().export_ctxt()
+            })
+    }
+
+    /// Changes sources of imports from other bundles, including `import()`,
+    /// to paths relative to the entry. Those are changed to the names of the
+    /// emitted bundles while finalizing.
+    fn rewrite_chunk_imports(&self, ctx: &Ctx, entry_id: ModuleId, entry: &mut Modules) {
+        let base = match &self.scope.get_module(entry_id).unwrap().fm.name {
+            FileName::Real(path) => path.clone(),
+            _ => return,
+        };
+
+        let mut srcs = AHashMap::default();
+        for &id in ctx.plan.entries.iter().chain(ctx.plan.group_members.keys()) {
+            let module = self.scope.get_module(id).unwrap();
+            // Members of a chunk group are imported from the bundle of the group.
+            let bundle_id = ctx.plan.bundle_of(id);
+            let bundle = self.scope.get_module(bundle_id).unwrap();
+            let path = match &bundle.fm.name {
+                FileName::Real(path) => path,
+                _ => continue,
+            };
+
+            srcs.insert(
+                module.export_ctxt(),
+                (
+                    relative_src(&base, path),
+                    bundle_id != entry_id && ctx.plan.is_split(id),
+                ),
+            );
+        }
+
+        entry.visit_mut_with(&mut ChunkImportRewriter { srcs: &srcs });
+    }
+
     /// This method handles imports and exports.
     ///
     ///
@@ -851,7 +956,7 @@ where
     ///
     ///
     /// TODO: We convert all exports to variable at here.
-    pub(crate) fn prepare(&self, info: &TransformedModule, module: &mut Modules) {
+    pub(super) fn prepare(&self, ctx: &Ctx, info: &TransformedModule, module: &mut Modules) {
         let injected_ctxt = self.injected_ctxt;
 
         if !info.is_es6 {
@@ -870,6 +975,24 @@ where
                             continue;
                         }
 
+                        // Preserve imports from other bundles.
+                        if self.is_chunk_import(ctx, info.id, &import) {
+                            for s in &mut import.specifiers {
+                                match s {
+                                    ImportSpecifier::Named(s) => match &s.imported {
+                                        Some(imported) if imported.sym == s.local.sym => {
+                                            s.imported = None;
+                                        }
+                                        _ => {}
+                                    },
+                                    _ => {}
+                                }
+                            }
+
+                            new.push(ModuleItem::ModuleDecl(ModuleDecl::Import(import)));
+                            continue;
+                        }
+
                         if let Some((src, _)) = info
                             .imports
                             .specifiers
@@ -1261,7 +1384,12 @@ where
         // );
     }
 
-    pub(super) fn replace_import_specifiers(&self, info: &TransformedModule, module: &mut Modules) {
+    pub(super) fn replace_import_specifiers(
+        &self,
+        ctx: &Ctx,
+        info: &TransformedModule,
+        module: &mut Modules,
+    ) {
         let injected_ctxt = self.injected_ctxt;
 
         let mut vars = vec![];
@@ -1271,7 +1399,9 @@ where
             for stmt in stmts {
                 match &stmt {
                     ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
-                        if self.config.external_modules.contains(&import.src.value) {
+                        if self.config.external_modules.contains(&import.src.value)
+                            || self.is_chunk_import(ctx, info.id, import)
+                        {
                             new.push(stmt);
                             continue;
                         }
@@ -1651,6 +1781,76 @@ fn vars_from_exports(
     vars
 }
 
+/// Changes sources of imports using the map from the export context of the
+/// imported module to the new source and whether static imports should be
+/// changed.
+struct ChunkImportRewriter<'a> {
+    srcs: &'a AHashMap<SyntaxContext, (String, bool)>,
+}
+
+impl ChunkImportRewriter<'_> {
+    fn src(&self, src: &Str, ctxt: SyntaxContext) -> Str {
+        let (value, _) = &self.srcs[&ctxt];
+
+        Str {
+            value: value.clone().into(),
+            has_escape: false,
+            kind: Default::default(),
+            ..src.clone()
+        }
+    }
+}
+
+impl VisitMut for ChunkImportRewriter<'_> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_call_expr(&mut self, e: &mut CallExpr) {
+        e.visit_mut_children_with(self);
+
+        let callee = match &mut e.callee {
+            ExprOrSuper::Expr(callee) => match &mut **callee {
+                Expr::Ident(
+                    callee
+                    @
+                    Ident {
+                        sym: js_word!("import"),
+                        ..
+                    },
+                ) => callee,
+                _ => return,
+            },
+            _ => return,
+        };
+
+        if !self.srcs.contains_key(&callee.span.ctxt) || e.args.len() != 1 {
+            return;
+        }
+        let ctxt = callee.span.ctxt;
+        callee.span.ctxt = SyntaxContext::empty();
+
+        let arg = &mut e.args[0];
+        if arg.spread.is_some() {
+            return;
+        }
+
+        match &mut *arg.expr {
+            Expr::Lit(Lit::Str(src)) => {
+                *src = self.src(src, ctxt);
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_mut_import_decl(&mut self, import: &mut ImportDecl) {
+        match self.srcs.get(&import.span.ctxt) {
+            Some((_, true)) => {}
+            _ => return,
+        }
+
+        import.src = self.src(&import.src, import.span.ctxt);
+    }
+}
+
 pub(super) struct ImportDropper<'a> {
     pub imports: &'a Imports,
 }
@@ -86,8 +86,57 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::bundler::tests::suite;
-    use swc_common::FileName;
+    use crate::{bundler::tests::suite, BundleKind, ChunkGroup, SplitChunksConfig};
+    use regex::Regex;
+    use std::collections::HashMap;
+    use swc_atoms::js_word;
+    use swc_common::{FileName, DUMMY_SP};
+    use swc_ecma_ast::*;
+    use swc_ecma_visit::{Node, Visit, VisitWith};
+
+    /// Collects sources of imports and names of functions.
+    #[derive(Default)]
+    struct SrcCollector {
+        imports: Vec<String>,
+        dynamic_imports: Vec<String>,
+        fns: Vec<String>,
+    }
+
+    impl Visit for SrcCollector {
+        fn visit_import_decl(&mut self, i: &ImportDecl, _: &dyn Node) {
+            self.imports.push(i.src.value.to_string());
+        }
+
+        fn visit_call_expr(&mut self, e: &CallExpr, _: &dyn Node) {
+            e.visit_children_with(self);
+
+            match &e.callee {
+                ExprOrSuper::Expr(callee) => match &**callee {
+                    Expr::Ident(Ident {
+                        sym: js_word!("import"),
+                        ..
+                    }) => match &*e.args[0].expr {
+                        Expr::Lit(Lit::Str(s)) => self.dynamic_imports.push(s.value.to_string()),
+                        _ => {}
+                    },
+                    _ => {}
+                },
+                _ => {}
+            }
+        }
+
+        fn visit_fn_decl(&mut self, f: &FnDecl, _: &dyn Node) {
+            f.visit_children_with(self);
+
+            self.fns.push(f.ident.sym.to_string());
+        }
+    }
+
+    fn collect(m: &Module) -> SrcCollector {
+        let mut v = SrcCollector::default();
+        m.visit_with(&Invalid { span: DUMMY_SP }, &mut v);
+        v
+    }
 
     #[test]
     fn cjs_chunk() {
@@ -116,4 +165,179 @@ mod tests {
                 Ok(())
             });
     }
+
+    #[test]
+    fn dynamic_import() {
+        suite()
+            .split_dynamic_imports()
+            .file(
+                "main.js",
+                "
+                import { log } from './common';
+                log('main');
+                import('./lazy');
+                ",
+            )
+            .file(
+                "lazy.js",
+                "
+                import { log } from './common';
+                export default function () {
+                    log('lazy');
+                }
+                ",
+            )
+            .file(
+                "common.js",
+                "
+                export function log(msg) {
+                    console.log(msg);
+                }
+                ",
+            )
+            .run(|t| {
+                let mut entries = HashMap::default();
+                entries.insert("main.js".to_string(), FileName::Real("main.js".into()));
+
+                let bundles = t.bundler.bundle(entries)?;
+                assert_eq!(bundles.len(), 3);
+
+                let mut main = None;
+                let mut lazy = None;
+                let mut common = None;
+                for bundle in &bundles {
+                    match &bundle.kind {
+                        // Libraries are emitted as named bundles.
+                        BundleKind::Named { name } if name.starts_with("common-") => {
+                            common = Some((name, bundle))
+                        }
+                        BundleKind::Named { .. } => main = Some(bundle),
+                        BundleKind::Dynamic { name } => lazy = Some((name, bundle)),
+                        BundleKind::Lib { .. } => unreachable!("libraries should be renamed"),
+                    }
+                }
+                let main = collect(&main.expect("should emit the entry").module);
+                let (lazy_name, lazy) = lazy.expect("should emit a dynamic bundle");
+                let (common_name, _) = common.expect("should emit a shared library");
+                let lazy = collect(&lazy.module);
+
+                assert!(lazy_name.starts_with("lazy-"), "{}", lazy_name);
+                assert!(common_name.starts_with("common-"), "{}", common_name);
+
+                assert_eq!(main.dynamic_imports, vec![format!("./{}", lazy_name)]);
+                assert_eq!(main.imports, vec![format!("./{}", common_name)]);
+                assert_eq!(lazy.imports, vec![format!("./{}", common_name)]);
+
+                // `log` should not be duplicated.
+                assert!(!main.fns.contains(&"log".to_string()), "{:?}", main.fns);
+                assert!(!lazy.fns.contains(&"log".to_string()), "{:?}", lazy.fns);
+
+                Ok(())
+            });
+    }
+
+    #[test]
+    fn dynamic_import_of_entry() {
+        suite()
+            .split_dynamic_imports()
+            .file("main.js", "import('./b');")
+            .file("b.js", "import('./main');")
+            .run(|t| {
+                let mut entries = HashMap::default();
+                entries.insert("main".to_string(), FileName::Real("main.js".into()));
+                entries.insert("entry-b".to_string(), FileName::Real("b.js".into()));
+
+                let bundles = t.bundler.bundle(entries)?;
+                assert_eq!(bundles.len(), 2);
+
+                for bundle in &bundles {
+                    let v = collect(&bundle.module);
+                    match &bundle.kind {
+                        // Entries are emitted as `main.js` and `entry-b.js`.
+                        BundleKind::Named { name } if name == "main" => {
+                            assert_eq!(v.dynamic_imports, vec!["./entry-b.js".to_string()])
+                        }
+                        BundleKind::Named { .. } => {
+                            assert_eq!(v.dynamic_imports, vec!["./main".to_string()])
+                        }
+                        kind => unreachable!("unexpected bundle: {:?}", kind),
+                    }
+                }
+
+                Ok(())
+            });
+    }
+
+    #[test]
+    fn chunk_group() {
+        suite()
+            .split_chunks(SplitChunksConfig {
+                groups: vec![ChunkGroup {
+                    name: "vendor".to_string(),
+                    test: Regex::new("^vendor_").unwrap(),
+                }],
+                ..Default::default()
+            })
+            .file(
+                "a.js",
+                "
+                import { foo } from './vendor_a';
+                foo();
+                ",
+            )
+            .file(
+                "b.js",
+                "
+                import { bar } from './vendor_b';
+                bar();
+                ",
+            )
+            .file(
+                "vendor_a.js",
+                "
+                export function foo() {}
+                ",
+            )
+            .file(
+                "vendor_b.js",
+                "
+                export function bar() {}
+                ",
+            )
+            .run(|t| {
+                let mut entries = HashMap::default();
+                entries.insert("a".to_string(), FileName::Real("a.js".into()));
+                entries.insert("b".to_string(), FileName::Real("b.js".into()));
+
+                let bundles = t.bundler.bundle(entries)?;
+                assert_eq!(bundles.len(), 3);
+
+                let mut entries = AHashMap::default();
+                let mut vendor = None;
+                for bundle in &bundles {
+                    match &bundle.kind {
+                        BundleKind::Named { name } if name.starts_with("vendor-") => {
+                            assert!(vendor.is_none(), "should emit one bundle for a group");
+                            vendor = Some((name, collect(&bundle.module)));
+                        }
+                        BundleKind::Named { name } => {
+                            entries.insert(name.clone(), collect(&bundle.module));
+                        }
+                        kind => unreachable!("unexpected bundle: {:?}", kind),
+                    }
+                }
+                let (vendor_name, vendor) = vendor.expect("should emit a bundle for the group");
+
+                assert_eq!(entries["a"].imports, vec![format!("./{}", vendor_name)]);
+                assert_eq!(entries["b"].imports, vec![format!("./{}", vendor_name)]);
+                assert!(!entries["a"].fns.contains(&"foo".to_string()));
+                assert!(!entries["b"].fns.contains(&"bar".to_string()));
+
+                let mut fns = vendor.fns.clone();
+                fns.sort();
+                assert_eq!(fns, vec!["bar".to_string(), "foo".to_string()]);
+
+                Ok(())
+            });
+    }
 }
@@ -1,12 +1,15 @@
 use self::lca::least_common_ancestor;
 use crate::dep_graph::ModuleGraph;
 use crate::{
-    bundler::{load::TransformedModule, scope::Metadata},
-    BundleKind, Bundler, Load, ModuleId, Resolve,
+    bundler::{
+        load::{Specifier, TransformedModule},
+        scope::Metadata,
+    },
+    BundleKind, Bundler, ChunkGroup, Load, ModuleId, Resolve,
 };
 use ahash::AHashMap;
 use ahash::AHashSet;
-use anyhow::{bail, Error};
+use anyhow::{anyhow, bail, Error};
 use petgraph::{
     algo::all_simple_paths,
     visit::Bfs,
@@ -16,6 +19,8 @@ use std::{
     collections::hash_map::Entry,
     ops::{Deref, DerefMut},
 };
+use swc_atoms::{js_word, JsWord};
+use swc_common::FileName;
 
 mod lca;
 #[cfg(test)]
@@ -39,6 +44,10 @@ struct PlanBuilder {
     circular: Circulars,
 
     kinds: AHashMap<ModuleId, BundleKind>,
+
+    /// Modules of chunk groups merged into the bundle of another module of
+    /// the group. Value is the module emitted as the bundle.
+    group_members: AHashMap<ModuleId, ModuleId>,
 }
 
 #[derive(Debug, Default)]
@@ -103,6 +112,31 @@ pub(super) struct Plan {
     pub circular: AHashMap<ModuleId, CircularPlan>,
 
     pub bundle_kinds: AHashMap<ModuleId, BundleKind>,
+
+    /// Modules of chunk groups merged into the bundle of another module of
+    /// the group. Value is the module emitted as the bundle.
+    pub group_members: AHashMap<ModuleId, ModuleId>,
+}
+
+impl Plan {
+    /// Returns true if `id` is emitted in a separate bundle, which should be
+    /// imported instead of being merged.
+    pub fn is_split(&self, id: ModuleId) -> bool {
+        if self.group_members.contains_key(&id) {
+            return true;
+        }
+
+        match self.bundle_kinds.get(&id) {
+            Some(BundleKind::Lib { .. }) | Some(BundleKind::Dynamic { .. }) => true,
+            _ => false,
+        }
+    }
+
+    /// Returns the module emitted as the bundle containing `id`, if `id` is
+    /// merged into the bundle of a chunk group.
+    pub fn bundle_of(&self, id: ModuleId) -> ModuleId {
+        self.group_members.get(&id).copied().unwrap_or(id)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -165,44 +199,311 @@ where
             self.add_to_graph(&mut builder, module.id, &mut vec![], true);
         }
 
-        let mut metadata = AHashMap::<ModuleId, Metadata>::default();
+        self.add_dynamic_entries(&mut builder);
+
+        // Root module and exported names of the bundle of each chunk group.
+        let mut groups = AHashMap::<String, (ModuleId, AHashSet<JsWord>)>::default();
+
+        // Promote modules to entry.
+        let metadata = loop {
+            let metadata = self.count_bundles(&builder);
+
+            let mut splittable = AHashSet::default();
+            for (&id, md) in &metadata {
+                if self.should_be_split(id, md.bundle_cnt)? && self.can_be_split(&builder, id) {
+                    splittable.insert(id);
+                }
+            }
+
+            // A module used only by other shared modules may end up in one library, so
+            // we promote the outermost ones first.
+            let mut promoted = vec![];
+            for &id in &splittable {
+                let mut dependants = builder.direct_deps.neighbors_directed(id, Incoming);
+
+                let is_outermost = match self.chunk_group(id)? {
+                    // Modules in a group are merged into the module importing them from
+                    // outside of the group.
+                    Some(group) if metadata[&id].bundle_cnt == 1 => {
+                        let mut found = false;
+                        for dependant in dependants {
+                            if self
+                                .chunk_group(dependant)?
+                                .map_or(true, |g| g.name != group.name)
+                            {
+                                found = true;
+                                break;
+                            }
+                        }
+                        found
+                    }
+                    _ => dependants.any(|dependant| !splittable.contains(&dependant)),
+                };
+
+                if is_outermost {
+                    promoted.push(id);
+                }
+            }
+            promoted.sort();
+
+            if promoted.is_empty() {
+                break metadata;
+            }
 
-        // Draw dependency graph to calculte
-        for (id, _) in &builder.kinds {
-            let mut bfs = Bfs::new(&builder.direct_deps, *id);
+            for id in promoted {
+                if let Some(group) = self.chunk_group(id)? {
+                    let exported = self.exported_names(id);
+
+                    match groups.entry(group.name.clone()) {
+                        Entry::Occupied(mut e) => {
+                            let (root, names) = e.get_mut();
+                            let root = *root;
+
+                            // Exports of members are merged into one module, so they should not
+                            // conflict.
+                            if let Some(exported) = exported.filter(|exported| {
+                                exported.iter().all(|name| !names.contains(name))
+                                    && !builder.all_deps.contains_key(&(id, root))
+                            }) {
+                                names.extend(exported);
+                                builder.group_members.insert(id, root);
+                                builder.direct_deps.add_edge(root, id, ());
+                                continue;
+                            }
 
-            while let Some(dep) = bfs.next(&builder.direct_deps) {
-                if dep == *id {
-                    // Useless
+                            log::debug!(
+                                "Cannot merge {:?} into the bundle of chunk group `{}`",
+                                id,
+                                group.name
+                            );
+                        }
+                        Entry::Vacant(e) => {
+                            if let Some(exported) = exported {
+                                e.insert((id, exported));
+                            }
+                        }
+                    }
+                }
+
+                let module = self.get_module(id)?;
+                let name = match (self.chunk_group(id)?, &module.fm.name) {
+                    (Some(group), FileName::Real(path)) => path
+                        .with_file_name(format!("{}.js", group.name))
+                        .to_string_lossy()
+                        .to_string(),
+                    (Some(group), _) => format!("{}.js", group.name),
+                    (None, name) => name.to_string(),
+                };
+                builder.kinds.insert(id, BundleKind::Lib { name });
+            }
+        };
+
+        // Other bundles are imported instead of being merged.
+        for (&id, kind) in &builder.kinds {
+            match kind {
+                BundleKind::Named { .. } => continue,
+                BundleKind::Lib { .. } | BundleKind::Dynamic { .. } => {}
+            }
+
+            let dependants = builder
+                .direct_deps
+                .neighbors_directed(id, Incoming)
+                .collect::<Vec<_>>();
+
+            for dependant in dependants {
+                if let Some(members) = builder.circular.get(id) {
+                    if members.contains(&dependant) {
+                        continue;
+                    }
+                }
+
+                builder.direct_deps.remove_edge(dependant, id);
+            }
+        }
+
+        // Members of a chunk group are imported from the bundle of the group.
+        for (&id, &root) in &builder.group_members {
+            let dependants = builder
+                .direct_deps
+                .neighbors_directed(id, Incoming)
+                .collect::<Vec<_>>();
+
+            for dependant in dependants {
+                if dependant == root || builder.group_members.get(&dependant) == Some(&root) {
                     continue;
                 }
 
-                metadata.entry(dep).or_default().bundle_cnt += 1;
+                builder.direct_deps.remove_edge(dependant, id);
             }
         }
 
-        // Promote modules to entry.
-        for (id, md) in &metadata {
-            if md.bundle_cnt > 1 {
-                // TODO: Dynamic import
-                let module = self.scope.get_module(*id).unwrap();
-                match builder.kinds.insert(
-                    *id,
-                    BundleKind::Lib {
+        let graph = builder.direct_deps.clone();
+
+        Ok((self.build_plan(&metadata, builder), graph))
+    }
+
+    /// Adds modules imported with `import()` as entries.
+    fn add_dynamic_entries(&self, builder: &mut PlanBuilder) {
+        let mut done = AHashSet::new();
+
+        loop {
+            let mut dynamic_imports = vec![];
+
+            for id in builder.direct_deps.nodes() {
+                if !done.insert(id) {
+                    continue;
+                }
+
+                let m = self.scope.get_module(id).expect("failed to get module");
+                dynamic_imports.extend(
+                    m.imports
+                        .specifiers
+                        .iter()
+                        .filter(|(src, _)| !src.is_loaded_synchronously)
+                        .map(|(src, _)| src.module_id),
+                );
+            }
+
+            if dynamic_imports.is_empty() {
+                return;
+            }
+
+            for id in dynamic_imports {
+                if builder.kinds.contains_key(&id) {
+                    continue;
+                }
+
+                let module = self.scope.get_module(id).expect("failed to get module");
+                log::debug!("Dynamic entry: {}", module.fm.name);
+
+                builder.kinds.insert(
+                    id,
+                    BundleKind::Dynamic {
                         name: module.fm.name.to_string(),
                     },
-                ) {
-                    Some(v) => {
-                        bail!("An entry cannot be imported: {:?}", v);
+                );
+                self.add_to_graph(builder, id, &mut vec![], true);
+            }
+        }
+    }
+
+    /// Counts bundles which will contain each module.
+    ///
+    /// Named entries are merged into the bundles importing them, but other
+    /// entries are not.
+    fn count_bundles(&self, builder: &PlanBuilder) -> AHashMap<ModuleId, Metadata> {
+        let mut metadata = AHashMap::<ModuleId, Metadata>::default();
+
+        for (&id, _) in &builder.kinds {
+            let mut done = AHashSet::new();
+            let mut queue = vec![id];
+
+            while let Some(module_id) = queue.pop() {
+                for dep in builder.direct_deps.neighbors_directed(module_id, Outgoing) {
+                    if dep == id || !done.insert(dep) {
+                        continue;
+                    }
+
+                    match builder.kinds.get(&dep) {
+                        Some(BundleKind::Named { .. }) => {}
+                        Some(..) => continue,
+                        None => match builder.group_members.get(&dep) {
+                            Some(&root) if root != id => continue,
+                            Some(..) => {}
+                            None => {
+                                metadata.entry(dep).or_default().bundle_cnt += 1;
+                            }
+                        },
                     }
-                    None => {}
+
+                    queue.push(dep);
                 }
             }
         }
 
-        let graph = builder.direct_deps.clone();
+        metadata
+    }
 
-        Ok((self.build_plan(&metadata, builder), graph))
+    /// Returns true if `id` should be emitted as a separate bundle according to
+    /// [crate::SplitChunksConfig].
+    fn should_be_split(&self, id: ModuleId, bundle_cnt: usize) -> Result<bool, Error> {
+        if self.chunk_group(id)?.is_some() {
+            return Ok(true);
+        }
+
+        let config = &self.config.split_chunks;
+        let module = self.get_module(id)?;
+
+        Ok(bundle_cnt >= config.min_chunks.max(2) && module.fm.src.len() >= config.min_size)
+    }
+
+    /// Returns the first group matching `id`.
+    fn chunk_group(&self, id: ModuleId) -> Result<Option<&ChunkGroup>, Error> {
+        let groups = &self.config.split_chunks.groups;
+        if groups.is_empty() {
+            return Ok(None);
+        }
+
+        let module = self.get_module(id)?;
+        let name = module.fm.name.to_string().replace('\\', "/");
+
+        Ok(groups.iter().find(|group| group.test.is_match(&name)))
+    }
+
+    fn get_module(&self, id: ModuleId) -> Result<TransformedModule, Error> {
+        self.scope
+            .get_module(id)
+            .ok_or_else(|| anyhow!("module {:?} is not loaded", id))
+    }
+
+    /// Returns names exported by `id`, or [None] if they are unknown because of
+    /// `export *`.
+    pub(super) fn exported_names(&self, id: ModuleId) -> Option<AHashSet<JsWord>> {
+        let module = self.scope.get_module(id).unwrap();
+        let mut names = AHashSet::default();
+
+        if module.exports.reexports.iter().any(|(_, v)| v.is_empty()) {
+            return None;
+        }
+
+        for specifiers in Some(&module.exports.items)
+            .into_iter()
+            .chain(module.exports.reexports.iter().map(|(_, v)| v))
+        {
+            for specifier in specifiers {
+                let name = match specifier {
+                    Specifier::Specific {
+                        alias: Some(alias), ..
+                    } if *alias.sym() == js_word!("default") => alias.sym(),
+                    Specifier::Specific { local, .. } => local.sym(),
+                    Specifier::Namespace { local, .. } => local.sym(),
+                };
+                names.insert(name.clone());
+            }
+        }
+
+        Some(names)
+    }
+
+    /// Returns true if `id` can be imported from other bundles.
+    fn can_be_split(&self, builder: &PlanBuilder, id: ModuleId) -> bool {
+        let module = self.scope.get_module(id).unwrap();
+        if !module.is_es6 || self.scope.should_be_wrapped_with_a_fn(id) || builder.is_circular(id) {
+            return false;
+        }
+
+        // Reexports and `require` calls are merged.
+        builder
+            .direct_deps
+            .neighbors_directed(id, Incoming)
+            .all(|dependant| {
+                self.scope.get_module(dependant).unwrap().is_es6
+                    && !builder
+                        .all_deps
+                        .get(&(dependant, id))
+                        .copied()
+                        .unwrap_or(false)
+            })
     }
 
     fn build_plan(&self, _metadata: &AHashMap<ModuleId, Metadata>, builder: PlanBuilder) -> Plan {
@@ -212,10 +513,20 @@ where
             plans.entries.push(*id);
             plans.bundle_kinds.insert(*id, kind.clone());
         }
+        plans.group_members = builder.group_members.clone();
 
         // Convert graph to plan
         for (root_entry, _) in &builder.kinds {
             let root_entry = *root_entry;
+
+            // Modules shared by bundles are merged into each of them, so we only care
+            // about dependants in this bundle.
+            let mut members = AHashSet::new();
+            let mut bfs = Bfs::new(&builder.direct_deps, root_entry);
+            while let Some(id) = bfs.next(&builder.direct_deps) {
+                members.insert(id);
+            }
+
             let mut bfs = Bfs::new(&builder.direct_deps, root_entry);
 
             let mut done = AHashSet::new();
@@ -267,6 +578,7 @@ where
                     let mut dependants = builder
                         .direct_deps
                         .neighbors_directed(dep, Incoming)
+                        .filter(|dependant| members.contains(dependant))
                         .collect::<Vec<_>>();
                     dependants.sort();
 
@@ -443,6 +755,23 @@ where
             }
         }
 
+        // Members of a chunk group are merged with their exports.
+        for (&id, &root) in &builder.group_members {
+            for (_, normal_plan) in &mut plans.normal {
+                normal_plan.chunks.retain(|dep| dep.id != id);
+            }
+
+            plans
+                .normal
+                .entry(root)
+                .or_default()
+                .chunks
+                .push(Dependancy {
+                    id,
+                    ty: DepType::Transitive,
+                });
+        }
+
         // Sort transitive chunks topologically.
         for (_, normal_plan) in &mut plans.normal {
             toposort(&builder, &mut normal_plan.chunks);
@@ -576,6 +905,7 @@ where
             .imports
             .specifiers
             .iter()
+            .filter(|v| v.0.is_loaded_synchronously)
             .map(|v| (&v.0, false))
             .chain(m.exports.reexports.iter().map(|v| (&v.0, true)))
         {
@@ -612,6 +942,7 @@ where
             .imports
             .specifiers
             .iter()
+            .filter(|v| v.0.is_loaded_synchronously)
             .chain(m.exports.reexports.iter())
         {
             if builder.all_deps.contains_key(&(src.module_id, module_id)) {
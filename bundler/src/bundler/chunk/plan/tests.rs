@@ -1,9 +1,14 @@
 use super::Plan;
-use crate::bundler::{
-    chunk::plan::DepType,
-    tests::{suite, Tester},
+use crate::{
+    bundler::{
+        chunk::plan::{DepType, Dependancy},
+        tests::{suite, TestBuilder, Tester},
+    },
+    BundleKind, ChunkGroup, SplitChunksConfig,
 };
 use ahash::AHashMap;
+use anyhow::Error;
+use regex::Regex;
 use std::collections::HashSet;
 use swc_common::FileName;
 
@@ -1093,3 +1098,265 @@ fn deno_9307_2() {
             Ok(())
         });
 }
+
+#[test]
+fn dynamic_import_001() {
+    suite()
+        .split_dynamic_imports()
+        .file(
+            "main.js",
+            "
+            import { a } from './a';
+            console.log(a);
+            import('./b');
+            ",
+        )
+        .file(
+            "a.js",
+            "
+            export const a = '1';
+            ",
+        )
+        .file(
+            "b.js",
+            "
+            import { a } from './a';
+            export const b = a;
+            ",
+        )
+        .run(|t| {
+            let module = t
+                .bundler
+                .load_transformed(&FileName::Real("main.js".into()))?
+                .unwrap();
+            let mut entries = AHashMap::default();
+            entries.insert("main.js".to_string(), module);
+
+            let p = t.bundler.calculate_plan(entries)?;
+
+            assert_eq!(
+                p.0.bundle_kinds[&t.id("b.js")],
+                BundleKind::Dynamic {
+                    name: "b.js".to_string()
+                }
+            );
+            assert_eq!(
+                p.0.bundle_kinds[&t.id("a.js")],
+                BundleKind::Lib {
+                    name: "a.js".to_string()
+                }
+            );
+
+            assert_normal(t, &p.0, "main", &[]);
+            assert_normal(t, &p.0, "b", &[]);
+
+            Ok(())
+        });
+}
+
+fn split_chunks_suite(config: SplitChunksConfig) -> TestBuilder {
+    suite()
+        .split_chunks(config)
+        .file(
+            "a.js",
+            "
+            import { foo } from './common';
+            foo();
+            ",
+        )
+        .file(
+            "b.js",
+            "
+            import { foo } from './common';
+            foo();
+            ",
+        )
+        .file(
+            "common.js",
+            "
+            export function foo() {}
+            ",
+        )
+}
+
+fn plan_for_entries(t: &mut Tester, names: &[&str]) -> Result<Plan, Error> {
+    let mut entries = AHashMap::default();
+    for name in names {
+        let module = t
+            .bundler
+            .load_transformed(&FileName::Real((*name).into()))?
+            .unwrap();
+        entries.insert(name.to_string(), module);
+    }
+
+    Ok(t.bundler.calculate_plan(entries)?.0)
+}
+
+#[test]
+fn split_chunks_default() {
+    split_chunks_suite(Default::default()).run(|t| {
+        let p = plan_for_entries(t, &["a.js", "b.js"])?;
+
+        assert_eq!(
+            p.bundle_kinds[&t.id("common.js")],
+            BundleKind::Lib {
+                name: "common.js".to_string()
+            }
+        );
+        assert_normal(t, &p, "a", &[]);
+        assert_normal(t, &p, "b", &[]);
+
+        Ok(())
+    });
+}
+
+#[test]
+fn split_chunks_min_chunks() {
+    split_chunks_suite(SplitChunksConfig {
+        min_chunks: 3,
+        ..Default::default()
+    })
+    .run(|t| {
+        let p = plan_for_entries(t, &["a.js", "b.js"])?;
+
+        assert!(!p.bundle_kinds.contains_key(&t.id("common.js")));
+        assert_normal(t, &p, "a", &["common"]);
+        assert_normal(t, &p, "b", &["common"]);
+
+        Ok(())
+    });
+}
+
+#[test]
+fn split_chunks_min_size() {
+    split_chunks_suite(SplitChunksConfig {
+        min_size: 1000,
+        ..Default::default()
+    })
+    .run(|t| {
+        let p = plan_for_entries(t, &["a.js", "b.js"])?;
+
+        assert!(!p.bundle_kinds.contains_key(&t.id("common.js")));
+        assert_normal(t, &p, "a", &["common"]);
+        assert_normal(t, &p, "b", &["common"]);
+
+        Ok(())
+    });
+}
+
+#[test]
+fn split_chunks_group() {
+    suite()
+        .split_chunks(SplitChunksConfig {
+            groups: vec![ChunkGroup {
+                name: "vendor".to_string(),
+                test: Regex::new("^vendor_").unwrap(),
+            }],
+            ..Default::default()
+        })
+        .file(
+            "main.js",
+            "
+            import { a } from './vendor_a';
+            console.log(a);
+            ",
+        )
+        .file(
+            "vendor_a.js",
+            "
+            import { b } from './vendor_b';
+            export const a = b;
+            ",
+        )
+        .file(
+            "vendor_b.js",
+            "
+            export const b = 1;
+            ",
+        )
+        .run(|t| {
+            let p = plan_for_entries(t, &["main.js"])?;
+
+            assert_eq!(
+                p.bundle_kinds[&t.id("vendor_a.js")],
+                BundleKind::Lib {
+                    name: "vendor.js".to_string()
+                }
+            );
+            assert!(!p.bundle_kinds.contains_key(&t.id("vendor_b.js")));
+            assert_normal(t, &p, "main", &[]);
+            assert_normal(t, &p, "vendor_a", &["vendor_b"]);
+
+            Ok(())
+        });
+}
+
+#[test]
+fn split_chunks_group_members() {
+    suite()
+        .split_chunks(SplitChunksConfig {
+            groups: vec![ChunkGroup {
+                name: "vendor".to_string(),
+                test: Regex::new("^vendor_").unwrap(),
+            }],
+            ..Default::default()
+        })
+        .file(
+            "a.js",
+            "
+            import { a } from './vendor_a';
+            console.log(a);
+            ",
+        )
+        .file(
+            "b.js",
+            "
+            import { b } from './vendor_b';
+            console.log(b);
+            ",
+        )
+        .file(
+            "vendor_a.js",
+            "
+            export const a = 1;
+            ",
+        )
+        .file(
+            "vendor_b.js",
+            "
+            export const b = 2;
+            ",
+        )
+        .run(|t| {
+            let p = plan_for_entries(t, &["a.js", "b.js"])?;
+
+            let (root, member) = if p.group_members.contains_key(&t.id("vendor_a.js")) {
+                ("vendor_b.js", "vendor_a.js")
+            } else {
+                ("vendor_a.js", "vendor_b.js")
+            };
+
+            assert_eq!(
+                p.bundle_kinds[&t.id(root)],
+                BundleKind::Lib {
+                    name: "vendor.js".to_string()
+                }
+            );
+            assert!(!p.bundle_kinds.contains_key(&t.id(member)));
+            assert_eq!(p.group_members[&t.id(member)], t.id(root));
+            assert!(p.is_split(t.id(member)));
+            assert_eq!(p.bundle_of(t.id(member)), t.id(root));
+
+            assert_normal(t, &p, "a", &[]);
+            assert_normal(t, &p, "b", &[]);
+            assert_eq!(
+                p.normal[&t.id(root)].chunks,
+                vec![Dependancy {
+                    id: t.id(member),
+                    ty: DepType::Transitive,
+                }]
+            );
+
+            Ok(())
+        });
+}
@@ -1,15 +1,17 @@
-use crate::{hash::calc_hash, Bundle, BundleKind, Bundler, Load, ModuleType, Resolve};
-use ahash::AHashMap;
-use anyhow::Error;
+use crate::{hash::calc_hash, Bundle, BundleKind, Bundler, Load, ModuleId, ModuleType, Resolve};
+use ahash::{AHashMap, AHashSet};
+use anyhow::{anyhow, bail, Error};
 use relative_path::RelativePath;
 use std::path::{Path, PathBuf};
-use swc_atoms::js_word;
-use swc_common::{util::move_map::MoveMap, FileName, DUMMY_SP};
+use swc_atoms::{js_word, JsWord};
+use swc_common::{util::move_map::MoveMap, FileName, Mark, Span, DUMMY_SP};
 use swc_ecma_ast::*;
+use swc_ecma_parser::{lexer::Lexer, Parser, StringInput};
 use swc_ecma_transforms::{
     fixer,
     helpers::{inject_helpers, HELPERS},
     hygiene,
+    modules::{common_js, umd},
 };
 use swc_ecma_utils::{find_ids, private_ident, ExprFactory};
 use swc_ecma_visit::{noop_fold_type, noop_visit_type, Fold, FoldWith, Node, Visit, VisitWith};
@@ -32,9 +34,11 @@ where
             for mut bundle in bundles {
                 bundle.module = self.optimize(bundle.module);
 
+                bundle.module = self.may_convert_module(bundle.id, bundle.module)?;
+
                 bundle.module = bundle.module.fold_with(&mut hygiene());
 
-                bundle.module = self.may_wrap_with_iife(bundle.module);
+                bundle.module = self.may_wrap_with_iife(bundle.module)?;
 
                 bundle.module = bundle.module.fold_with(&mut fixer(None));
 
@@ -52,8 +56,27 @@ where
                         HELPERS.set(&swc_helpers, || module.fold_with(&mut inject_helpers()));
                 }
 
+                let info = self
+                    .scope
+                    .get_module(bundle.id)
+                    .ok_or_else(|| anyhow!("module {:?} is not loaded", bundle.id))?;
+
                 match bundle.kind {
-                    BundleKind::Named { .. } => {
+                    BundleKind::Named { ref name } => {
+                        // Entries are emitted as `name`, with `.js` added if it has no extension.
+                        if let FileName::Real(path) = &info.fm.name {
+                            let file_name = if Path::new(name).extension().is_some() {
+                                PathBuf::from(name)
+                            } else {
+                                PathBuf::from(format!("{}.js", name))
+                            };
+                            let new_name = path.parent().unwrap_or(path).join(file_name);
+                            if new_name != *path {
+                                renamed
+                                    .insert(path.clone(), new_name.to_string_lossy().to_string());
+                            }
+                        }
+
                         // Inject helpers
                         let helpers = self
                             .scope
@@ -65,11 +88,15 @@ where
 
                         new.push(Bundle { ..bundle });
                     }
-                    BundleKind::Lib { name } => {
+                    BundleKind::Lib { ref name } | BundleKind::Dynamic { ref name } => {
                         let hash = calc_hash(self.cm.clone(), &bundle.module)?;
-                        let mut new_name = PathBuf::from(name);
-                        let key = new_name.clone();
-                        let file_name = new_name
+                        // Bundles of a chunk group are not named after the module.
+                        let key = match &info.fm.name {
+                            FileName::Real(path) => path.clone(),
+                            _ => PathBuf::from(name),
+                        };
+                        let mut new_name = key.clone();
+                        let file_name = Path::new(name)
                             .file_name()
                             .map(|path| -> PathBuf {
                                 let path = Path::new(path);
@@ -96,14 +123,14 @@ where
 
                         renamed.insert(key, new_name.to_string_lossy().to_string());
 
-                        new.push(Bundle {
-                            kind: BundleKind::Named {
-                                name: file_name.display().to_string(),
-                            },
-                            ..bundle
-                        })
+                        let name = file_name.display().to_string();
+                        let kind = match bundle.kind {
+                            BundleKind::Dynamic { .. } => BundleKind::Dynamic { name },
+                            _ => BundleKind::Named { name },
+                        };
+
+                        new.push(Bundle { kind, ..bundle })
                     }
-                    _ => new.push(bundle),
                 }
             }
 
@@ -137,9 +164,55 @@ where
         })
     }
 
-    fn may_wrap_with_iife(&self, module: Module) -> Module {
+    /// Converts imports and exports for [ModuleType::Cjs] and
+    /// [ModuleType::Umd].
+    fn may_convert_module(&self, id: ModuleId, module: Module) -> Result<Module, Error> {
+        match self.config.module {
+            ModuleType::Es | ModuleType::Iife => return Ok(module),
+            ModuleType::Cjs | ModuleType::Umd => {}
+        }
+
+        let info = self
+            .scope
+            .get_module(id)
+            .ok_or_else(|| anyhow!("module {:?} is not loaded", id))?;
+
+        // The umd pass determines the global name using the file name of the span.
+        let module = Module {
+            span: Span::new(info.fm.start_pos, info.fm.end_pos, Default::default()),
+            ..module
+        };
+
+        HELPERS.set(&info.swc_helpers, || match self.config.module {
+            ModuleType::Cjs => {
+                Ok(module.fold_with(&mut common_js(Mark::root(), Default::default())))
+            }
+            _ => {
+                match info.fm.name {
+                    FileName::Real(..) => {}
+                    _ => bail!(
+                        "cannot determine the global name of an umd bundle: `{}` is not a file",
+                        info.fm.name
+                    ),
+                }
+                // The umd pass panics on invalid expressions.
+                for (src, global) in &self.config.globals {
+                    self.parse_global(src, global)?;
+                }
+
+                let config = umd::Config {
+                    globals: self.config.globals.clone(),
+                    ..Default::default()
+                };
+
+                Ok(module.fold_with(&mut umd(self.cm.clone(), Mark::root(), config)))
+            }
+        })
+    }
+
+    fn may_wrap_with_iife(&self, module: Module) -> Result<Module, Error> {
         if self.config.module != ModuleType::Iife {
-            return module;
+            return Ok(module);
         }
 
         let mut top_level_await_finder = TopLevelAwaitFinder::default();
@@ -147,8 +220,13 @@ where
 
         let is_async = top_level_await_finder.found;
 
+        let mut used_names = IdentCollector::default();
+        module.visit_with(&Invalid { span: DUMMY_SP }, &mut used_names);
+
         // Properties of returned object
         let mut props = vec![];
+        // Imports of external modules, which are passed to the iife.
+        let mut imports = vec![];
 
         let mut body = BlockStmt {
             span: module.span,
@@ -165,8 +243,14 @@ where
                         ModuleDecl::ExportNamed(NamedExport { src: Some(..), .. })
                         | ModuleDecl::TsImportEquals(_)
                         | ModuleDecl::TsExportAssignment(_)
-                        | ModuleDecl::TsNamespaceExport(_)
-                        | ModuleDecl::Import(_) => None,
+                        | ModuleDecl::TsNamespaceExport(_) => None,
+
+                        ModuleDecl::Import(import) => {
+                            if self.config.external_modules.contains(&import.src.value) {
+                                imports.push(import);
+                            }
+                            None
+                        }
 
                         ModuleDecl::ExportDecl(export) => {
                             match &export.decl {
@@ -302,6 +386,10 @@ where
                 })
                 .collect(),
         };
+
+        let (params, args, bindings) = self.globals_for_iife(imports, used_names.names)?;
+        body.stmts = bindings.into_iter().chain(body.stmts).collect();
+
         body.stmts.push(Stmt::Return(ReturnStmt {
             span: DUMMY_SP,
             arg: Some(Box::new(Expr::Object(ObjectLit {
@@ -313,7 +401,7 @@ where
         let f = Function {
             is_generator: false,
             is_async,
-            params: Default::default(),
+            params,
             decorators: Default::default(),
             span: DUMMY_SP,
             body: Some(body),
@@ -329,18 +417,177 @@ where
         let iife = Box::new(Expr::Call(CallExpr {
             span: DUMMY_SP,
             callee: invoked_fn_expr.as_callee(),
-            args: Default::default(),
+            args,
             type_args: Default::default(),
         }));
 
-        Module {
+        Ok(Module {
             span: DUMMY_SP,
             shebang: None,
             body: vec![ModuleItem::Stmt(Stmt::Expr(ExprStmt {
                 span: DUMMY_SP,
                 expr: iife,
             }))],
+        })
+    }
+
+    /// Creates parameters and arguments of the iife for external modules,
+    /// using [Config::globals][crate::Config::globals].
+    ///
+    /// Default and namespace imports are bound to the global itself, and named
+    /// imports are bound to its properties. Side-effect imports only pass the
+    /// global to the iife.
+    fn globals_for_iife(
+        &self,
+        imports: Vec<ImportDecl>,
+        mut used_names: AHashSet<JsWord>,
+    ) -> Result<(Vec<Param>, Vec<ExprOrSpread>, Vec<Stmt>), Error> {
+        let mut params: Vec<(JsWord, Ident)> = vec![];
+        let mut args = vec![];
+        let mut bindings = vec![];
+
+        for import in &imports {
+            let src = import.src.value.clone();
+
+            let param = match params.iter().find(|(s, _)| *s == src) {
+                Some((_, param)) => param.clone(),
+                None => {
+                    let global = match self.config.globals.get(&*src) {
+                        Some(v) => v,
+                        None => bail!(
+                            "external module `{}` is used by an iife bundle, but it's not mapped \
+                             to a global variable\nhint: add it to `Config.globals`",
+                            src
+                        ),
+                    };
+                    args.push(self.parse_global(&src, global)?.as_arg());
+
+                    let param = imports
+                        .iter()
+                        .filter(|i| i.src.value == src)
+                        .flat_map(|i| &i.specifiers)
+                        .find_map(|s| match s {
+                            ImportSpecifier::Default(ImportDefaultSpecifier { local, .. })
+                            | ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. }) => {
+                                Some(local.clone())
+                            }
+                            ImportSpecifier::Named(..) => None,
+                        })
+                        .unwrap_or_else(|| {
+                            // Names are fixed by hygiene at this point, so we pick an unused one.
+                            let base: String = src
+                                .chars()
+                                .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+                                .collect();
+                            let mut name: JsWord = format!("_{}", base).into();
+                            let mut i = 0;
+                            while used_names.contains(&name) {
+                                i += 1;
+                                name = format!("_{}{}", base, i).into();
+                            }
+                            Ident::new(name, DUMMY_SP)
+                        });
+                    used_names.insert(param.sym.clone());
+
+                    params.push((src.clone(), param.clone()));
+                    param
+                }
+            };
+
+            for s in &import.specifiers {
+                let (local, init) = match s.clone() {
+                    ImportSpecifier::Default(ImportDefaultSpecifier { local, .. })
+                    | ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. }) => {
+                        if local.sym == param.sym {
+                            continue;
+                        }
+                        (local, Expr::Ident(param.clone()))
+                    }
+                    ImportSpecifier::Named(ImportNamedSpecifier {
+                        local, imported, ..
+                    }) => {
+                        let imported = imported.unwrap_or_else(|| local.clone());
+                        (local, param.clone().make_member(imported))
+                    }
+                };
+
+                bindings.push(Stmt::Decl(Decl::Var(VarDecl {
+                    span: DUMMY_SP,
+                    kind: VarDeclKind::Const,
+                    declare: false,
+                    decls: vec![VarDeclarator {
+                        span: DUMMY_SP,
+                        name: Pat::Ident(local.into()),
+                        init: Some(Box::new(init)),
+                        definite: false,
+                    }],
+                })));
+            }
         }
+
+        let params = params
+            .into_iter()
+            .map(|(_, param)| Param {
+                span: DUMMY_SP,
+                decorators: Default::default(),
+                pat: Pat::Ident(param.into()),
+            })
+            .collect();
+
+        Ok((params, args, bindings))
+    }
+
+    /// Parses an expression for a global variable, like `window.React`.
+    fn parse_global(&self, src: &str, global: &str) -> Result<Box<Expr>, Error> {
+        let fm = self.cm.new_source_file(
+            FileName::Custom(format!("<global for {}>", src)),
+            global.to_string(),
+        );
+        let lexer = Lexer::new(
+            Default::default(),
+            Default::default(),
+            StringInput::from(&*fm),
+            None,
+        );
+        let mut parser = Parser::new_from(lexer);
+        let script = parser.parse_script().map_err(|err| {
+            anyhow!(
+                "failed to parse `{}`, the global variable for `{}`: {}",
+                global,
+                src,
+                err.into_kind().msg()
+            )
+        })?;
+        if !parser.take_errors().is_empty() {
+            bail!(
+                "failed to parse `{}`, the global variable for `{}`",
+                global,
+                src
+            )
+        }
+
+        match &*script.body {
+            [Stmt::Expr(ExprStmt { expr, .. })] => Ok(expr.clone()),
+            _ => bail!(
+                "`{}`, the global variable for `{}`, should be a single expression",
+                global,
+                src
+            ),
+        }
+    }
+}
+
+/// Collects all names of identifiers.
+#[derive(Default)]
+struct IdentCollector {
+    names: AHashSet<JsWord>,
+}
+
+impl Visit for IdentCollector {
+    noop_visit_type!();
+
+    fn visit_ident(&mut self, i: &Ident, _: &dyn Node) {
+        self.names.insert(i.sym.clone());
     }
 }
 
@@ -375,46 +622,265 @@ where
 {
     noop_fold_type!();
 
+    fn fold_call_expr(&mut self, e: CallExpr) -> CallExpr {
+        let mut e = e.fold_children_with(self);
+
+        // `require` is used by commonjs and umd bundles.
+        match &e.callee {
+            ExprOrSuper::Expr(callee) => match &**callee {
+                Expr::Ident(Ident {
+                    sym: js_word!("import"),
+                    ..
+                })
+                | Expr::Ident(Ident {
+                    sym: js_word!("require"),
+                    ..
+                }) => {}
+                _ => return e,
+            },
+            _ => return e,
+        }
+
+        if e.args.len() != 1 || e.args[0].spread.is_some() {
+            return e;
+        }
+
+        match &mut *e.args[0].expr {
+            Expr::Lit(Lit::Str(src)) => {
+                if let Some(new) = self.rename(src) {
+                    *src = new;
+                }
+            }
+            _ => {}
+        }
+
+        e
+    }
+
     fn fold_import_decl(&mut self, import: ImportDecl) -> ImportDecl {
+        match self.rename(&import.src) {
+            Some(src) => ImportDecl { src, ..import },
+            None => import,
+        }
+    }
+}
+
+impl<R> Renamer<'_, R>
+where
+    R: Resolve,
+{
+    /// Returns the new source if `src` is a renamed bundle.
+    fn rename(&self, src: &Str) -> Option<Str> {
         let resolved = match self
             .resolver
-            .resolve(&FileName::Real(self.base.clone()), &import.src.value)
+            .resolve(&FileName::Real(self.base.clone()), &src.value)
         {
             Ok(v) => match v {
                 FileName::Real(v) => v,
                 _ => panic!("rename_bundles called with non-path module"),
             },
-            Err(_) => return import,
+            Err(_) => return None,
         };
 
-        if let Some(v) = self.renamed.get(&resolved) {
-            // We use parent because RelativePath uses ../common-[hash].js
-            // if we use `entry-a.js` as a base.
-            //
-            // entry-a.js
-            // common.js
-            let base = self
-                .base
-                .parent()
-                .unwrap_or(self.base)
-                .as_os_str()
-                .to_string_lossy();
-            let base = RelativePath::new(&*base);
-            let v = base.relative(&*v);
-            let value = v.as_str();
-            return ImportDecl {
-                src: Str {
-                    value: if value.starts_with(".") {
-                        value.into()
-                    } else {
-                        format!("./{}", value).into()
-                    },
-                    ..import.src
-                },
-                ..import
-            };
+        let v = self.renamed.get(&resolved)?;
+
+        Some(Str {
+            value: relative_src(self.base, Path::new(v)).into(),
+            has_escape: false,
+            kind: Default::default(),
+            ..src.clone()
+        })
+    }
+}
+
+/// Returns a path to `path` which can be imported from `base`.
+pub(super) fn relative_src(base: &Path, path: &Path) -> String {
+    // We use parent because RelativePath uses ../common-[hash].js
+    // if we use `entry-a.js` as a base.
+    //
+    // entry-a.js
+    // common.js
+    let base = base.parent().unwrap_or(base).as_os_str().to_string_lossy();
+    let base = RelativePath::new(&*base);
+    let v = base.relative(&*path.to_string_lossy());
+    let value = v.as_str();
+
+    if value.starts_with(".") {
+        value.into()
+    } else {
+        format!("./{}", value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{bundler::tests::suite, ModuleType};
+    use std::collections::HashMap;
+    use swc_common::{sync::Lrc, FileName, SourceMap};
+    use swc_ecma_ast::*;
+    use swc_ecma_codegen::{text_writer::JsWriter, Emitter};
+
+    fn print(cm: &Lrc<SourceMap>, m: &Module) -> String {
+        let mut buf = vec![];
+        Emitter {
+            cfg: Default::default(),
+            cm: cm.clone(),
+            comments: None,
+            wr: Box::new(JsWriter::new(cm.clone(), "\n", &mut buf, None)),
         }
+        .emit_module(m)
+        .unwrap();
+
+        String::from_utf8(buf).unwrap()
+    }
+
+    fn bundle(module: ModuleType) -> String {
+        bundle_main(
+            module,
+            "
+            import React from 'react';
+            import { a } from './a';
+            export const b = React.createElement(a);
+            ",
+        )
+    }
+
+    fn bundle_main(module: ModuleType, main: &str) -> String {
+        let mut output = None;
+
+        suite()
+            .module(module)
+            .external("react")
+            .global("react", "window.React")
+            .file("main.js", main)
+            .file("a.js", "export const a = 'div';")
+            .run(|t| {
+                let mut entries = HashMap::default();
+                entries.insert("main".to_string(), FileName::Real("main.js".into()));
+
+                let bundles = t.bundler.bundle(entries)?;
+                assert_eq!(bundles.len(), 1);
+
+                let m = &bundles[0].module;
+                assert!(
+                    m.body.iter().all(|item| match item {
+                        ModuleItem::Stmt(..) => true,
+                        ModuleItem::ModuleDecl(..) => false,
+                    }),
+                    "imports and exports should be converted"
+                );
+
+                output = Some(print(&t.cm, m));
+
+                Ok(())
+            });
+
+        output.unwrap()
+    }
+
+    fn bundle_err(global: Option<&str>, src: &str) -> String {
+        let mut output = None;
+
+        let mut builder = suite()
+            .module(ModuleType::Iife)
+            .external("react")
+            .file("main.js", src);
+        if let Some(global) = global {
+            builder = builder.global("react", global);
+        }
+
+        builder.run(|t| {
+            let mut entries = HashMap::default();
+            entries.insert("main".to_string(), FileName::Real("main.js".into()));
+
+            let err = t.bundler.bundle(entries).expect_err("bundling should fail");
+            output = Some(format!("{:?}", err));
+
+            Ok(())
+        });
+
+        output.unwrap()
+    }
+
+    #[test]
+    fn cjs() {
+        let code = bundle(ModuleType::Cjs);
+
+        assert!(code.contains("require(\"react\")"), "{}", code);
+        assert!(code.contains("exports.b ="), "{}", code);
+        assert!(!code.contains("window.React"), "{}", code);
+    }
+
+    #[test]
+    fn umd() {
+        let code = bundle(ModuleType::Umd);
+
+        assert!(code.contains("define.amd"), "{}", code);
+        assert!(code.contains("require(\"react\")"), "{}", code);
+        assert!(code.contains("window.React"), "{}", code);
+        assert!(code.contains("exports.b ="), "{}", code);
+    }
+
+    #[test]
+    fn iife_globals() {
+        let code = bundle(ModuleType::Iife);
+
+        assert!(code.contains("function(React)"), "{}", code);
+        assert!(code.contains("})(window.React)"), "{}", code);
+        assert!(!code.contains("require"), "{}", code);
+    }
+
+    #[test]
+    fn iife_globals_named() {
+        let code = bundle_main(
+            ModuleType::Iife,
+            "
+            import { createElement } from 'react';
+            import * as R from 'react';
+            export const b = createElement(R);
+            ",
+        );
+
+        assert!(code.contains("function(R)"), "{}", code);
+        assert!(code.contains("createElement = R.createElement"), "{}", code);
+        assert!(code.contains("})(window.React)"), "{}", code);
+    }
+
+    #[test]
+    fn iife_globals_side_effect() {
+        let code = bundle_main(ModuleType::Iife, "import 'react'; export const b = 1;");
+
+        assert!(code.contains("function(_react)"), "{}", code);
+        assert!(code.contains("})(window.React)"), "{}", code);
+
+        let err = bundle_err(None, "import 'react'; console.log(1);");
+
+        assert!(err.contains("external module `react`"), "{}", err);
+        assert!(err.contains("Config.globals"), "{}", err);
+    }
+
+    #[test]
+    fn iife_globals_missing() {
+        let err = bundle_err(None, "import React from 'react'; React.render();");
+
+        assert!(err.contains("external module `react`"), "{}", err);
+        assert!(err.contains("Config.globals"), "{}", err);
+    }
+
+    #[test]
+    fn iife_globals_invalid() {
+        let err = bundle_err(
+            Some("window.React; alert()"),
+            "import React from 'react'; React.render();",
+        );
+
+        assert!(err.contains("should be a single expression"), "{}", err);
+
+        let err = bundle_err(
+            Some("window."),
+            "import React from 'react'; React.render();",
+        );
 
-        import
+        assert!(err.contains("failed to parse `window.`"), "{}", err);
     }
 }
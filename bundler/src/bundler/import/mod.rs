@@ -263,19 +263,29 @@ where
                         return;
                     }
 
-                    // TODO: Uncomment this after implementing an option to make swc_bundler
-                    // includes dynamic imports
-                    //
-                    //
-                    // ExprOrSuper::Expr(ref e) => match &**e {
-                    //     Expr::Ident(Ident {
-                    //         sym: js_word!("import"),
-                    //         ..
-                    //     }) => {
-                    //         self.info.dynamic_imports.push(src.clone());
-                    //     }
-                    //     _ => {}
-                    // },
+                    ExprOrSuper::Expr(callee)
+                        if self.bundler.config.split_dynamic_imports
+                            && match &**callee {
+                                Expr::Ident(Ident {
+                                    sym: js_word!("import"),
+                                    ..
+                                }) => true,
+                                _ => false,
+                            } =>
+                    {
+                        // We mark the callee so the import can be changed to the name of the
+                        // emitted bundle.
+                        if let Some((_, export_ctxt)) = self.ctxt_for(&src.value) {
+                            match &mut **callee {
+                                Expr::Ident(i) => {
+                                    i.span = i.span.with_ctxt(export_ctxt);
+                                }
+                                _ => {}
+                            }
+
+                            self.info.dynamic_imports.push(src.clone());
+                        }
+                    }
                     _ => {}
                 }
             }
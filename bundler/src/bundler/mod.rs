@@ -2,6 +2,7 @@ use self::scope::Scope;
 use crate::{Hook, Load, ModuleId, Resolve};
 use ahash::AHashMap;
 use anyhow::{Context, Error};
+use regex::Regex;
 use std::collections::HashMap;
 use swc_atoms::JsWord;
 use swc_common::{sync::Lrc, FileName, Globals, Mark, SourceMap, SyntaxContext, DUMMY_SP, GLOBALS};
@@ -33,14 +34,77 @@ pub struct Config {
     /// List of modules which should be preserved.
     pub external_modules: Vec<JsWord>,
 
+    /// If it's true, [Bundler] creates a bundle for each module imported with
+    /// `import('./foo')`.
+    pub split_dynamic_imports: bool,
+
+    /// Controls extraction of modules shared by bundles.
+    ///
+    /// By default, every module used by two or more bundles is extracted, as
+    /// it was emitted as a [BundleKind::Lib] before this option existed.
+    pub split_chunks: SplitChunksConfig,
+
     /// Type of emitted module
     pub module: ModuleType,
+
+    /// Maps external modules to expressions for global variables, like
+    /// `react` to `window.React`.
+    ///
+    /// Used by [ModuleType::Umd] and [ModuleType::Iife]. External modules
+    /// imported by an iife bundle must have an entry.
+    pub globals: HashMap<String, String>,
+}
+
+/// Modules used by multiple bundles are emitted as [BundleKind::Lib] if
+/// they match this policy, and merged into each bundle otherwise.
+#[derive(Debug)]
+pub struct SplitChunksConfig {
+    /// Minimum number of bundles using a module. Values less than 2 are
+    /// treated as 2.
+    pub min_chunks: usize,
+
+    /// Minimum size of the source file of a module, in bytes.
+    pub min_size: usize,
+
+    /// Modules matching a group are extracted regardless of `min_chunks` and
+    /// `min_size`, and emitted as one bundle per group. The first matching
+    /// group is used.
+    pub groups: Vec<ChunkGroup>,
+}
+
+/// Extracts every module used by two or more bundles, without groups.
+impl Default for SplitChunksConfig {
+    fn default() -> Self {
+        SplitChunksConfig {
+            min_chunks: 2,
+            min_size: 0,
+            groups: vec![],
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ChunkGroup {
+    /// Name of the emitted bundle, without an extension.
+    ///
+    /// A module exporting a name already exported by another module of the
+    /// group is emitted as a separate bundle with the same name.
+    pub name: String,
+
+    /// Matched against file names of modules.
+    pub test: Regex,
 }
 
 #[derive(Debug, PartialEq, Eq, Hash)]
 pub enum ModuleType {
     Es,
     Iife,
+    /// Exports are assigned to `module.exports`, and external modules are
+    /// loaded with `require`.
+    Cjs,
+    /// Works as an AMD module, a CommonJS module, or a script assigning
+    /// exports to a global variable named after the entry.
+    Umd,
 }
 
 impl Default for ModuleType {
@@ -54,7 +118,7 @@ pub enum BundleKind {
     /// User-provided entry
     Named { name: String },
     /// Auto-generated entry (created by import expression)
-    Dynamic,
+    Dynamic { name: String },
     /// A lazy-loaded shared library
     Lib { name: String },
 }
@@ -153,9 +217,6 @@ where
             })
             .collect::<Vec<_>>();
 
-        // We collect at here to handle dynamic imports
-        // TODO: Handle dynamic imports
-
         let local = {
             let mut output = AHashMap::default();
 
@@ -1,12 +1,12 @@
 //! Utilities for testing.
-use super::{load::TransformedModule, Bundler, Config};
+use super::{load::TransformedModule, Bundler, Config, ModuleType, SplitChunksConfig};
 use crate::{load::ModuleData, util::HygieneRemover, Load, ModuleId, ModuleRecord, Resolve};
 use anyhow::Error;
 use indexmap::IndexMap;
-use std::path::PathBuf;
+use std::{collections::HashMap, path::PathBuf};
 use swc_common::{sync::Lrc, FileName, SourceMap, Span, GLOBALS};
 use swc_ecma_ast::*;
-use swc_ecma_parser::{lexer::Lexer, JscTarget, Parser, StringInput};
+use swc_ecma_parser::{lexer::Lexer, EsConfig, JscTarget, Parser, StringInput, Syntax};
 use swc_ecma_utils::drop_span;
 use swc_ecma_visit::VisitMutWith;
 
@@ -29,7 +29,10 @@ impl Load for Loader {
         let fm = self.cm.new_source_file(f.clone(), v.to_string());
 
         let lexer = Lexer::new(
-            Default::default(),
+            Syntax::Es(EsConfig {
+                dynamic_import: true,
+                ..Default::default()
+            }),
             JscTarget::Es2020,
             StringInput::from(&*fm),
             None,
@@ -111,9 +114,39 @@ pub(crate) fn suite() -> TestBuilder {
 #[derive(Default)]
 pub(crate) struct TestBuilder {
     files: IndexMap<String, String>,
+    split_dynamic_imports: bool,
+    split_chunks: SplitChunksConfig,
+    module: ModuleType,
+    external_modules: Vec<String>,
+    globals: HashMap<String, String>,
 }
 
 impl TestBuilder {
+    pub fn split_dynamic_imports(mut self) -> Self {
+        self.split_dynamic_imports = true;
+        self
+    }
+
+    pub fn split_chunks(mut self, config: SplitChunksConfig) -> Self {
+        self.split_chunks = config;
+        self
+    }
+
+    pub fn module(mut self, module: ModuleType) -> Self {
+        self.module = module;
+        self
+    }
+
+    pub fn external(mut self, name: &str) -> Self {
+        self.external_modules.push(name.to_string());
+        self
+    }
+
+    pub fn global(mut self, name: &str, global: &str) -> Self {
+        self.globals.insert(name.to_string(), global.to_string());
+        self
+    }
+
     pub fn file(mut self, name: &str, src: &str) -> Self {
         self.files.insert(name.to_string(), src.to_string());
         self
@@ -136,8 +169,15 @@ impl TestBuilder {
                     Config {
                         require: true,
                         disable_inliner: true,
-                        external_modules: vec![],
-                        module: Default::default(),
+                        external_modules: self
+                            .external_modules
+                            .into_iter()
+                            .map(From::from)
+                            .collect(),
+                        module: self.module,
+                        split_dynamic_imports: self.split_dynamic_imports,
+                        split_chunks: self.split_chunks,
+                        globals: self.globals,
                     },
                     Box::new(Hook),
                 );
@@ -1,5 +1,5 @@
 pub use self::{
-    bundler::{Bundle, BundleKind, Bundler, Config, ModuleType},
+    bundler::{Bundle, BundleKind, Bundler, ChunkGroup, Config, ModuleType, SplitChunksConfig},
     hook::{Hook, ModuleRecord},
     id::ModuleId,
     load::{Load, ModuleData},
@@ -1030,7 +1030,10 @@ fn bundle(url: &str) -> String {
             let mut buf = vec![];
             {
                 Emitter {
-                    cfg: swc_ecma_codegen::Config { minify: false },
+                    cfg: swc_ecma_codegen::Config {
+                        minify: false,
+                        ..Default::default()
+                    },
                     cm: cm.clone(),
                     comments: None,
                     wr: Box::new(JsWriter::new(cm.clone(), "\n", &mut buf, None)),
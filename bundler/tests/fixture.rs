@@ -185,6 +185,9 @@ fn do_test(entry: &DirEntry, entries: HashMap<String, FileName>, inline: bool) {
                 .map(From::from)
                 .collect(),
                 module: Default::default(),
+                split_dynamic_imports: false,
+                split_chunks: Default::default(),
+                globals: Default::default(),
             },
             Box::new(Hook),
         );
@@ -219,8 +222,9 @@ fn do_test(entry: &DirEntry, entries: HashMap<String, FileName>, inline: bool) {
             };
 
             let name = match bundled.kind {
-                BundleKind::Named { name } | BundleKind::Lib { name } => PathBuf::from(name),
-                BundleKind::Dynamic => format!("dynamic.{}.js", bundled.id).into(),
+                BundleKind::Named { name }
+                | BundleKind::Lib { name }
+                | BundleKind::Dynamic { name } => PathBuf::from(name),
             };
 
             let output_dir = entry.path().join("output");
@@ -22,7 +22,10 @@ pub use self::{
         SourceFileAndBytePos, SourceFileAndLine, Span, SpanLinesError, Spanned, SyntaxContext,
         DUMMY_SP, GLOBALS, NO_EXPANSION,
     },
-    source_map::{FileLines, FileLoader, FilePathMapping, SourceMap, SpanSnippetError},
+    source_map::{
+        DefaultSourceMapGenConfig, FileLines, FileLoader, FilePathMapping, SourceMap,
+        SourceMapGenConfig, SpanSnippetError,
+    },
     syntax_pos::LineCol,
 };
 pub use ast_node::ast_serde;
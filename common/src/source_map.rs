@@ -1025,12 +1025,13 @@ impl SourceMap {
     ) -> sourcemap::SourceMap {
         let mut builder = SourceMapBuilder::new(None);
 
-        let mut src_id = 0u32;
+        // Source of `cur_file`, used for tokens which are not in `cur_orig`.
+        let mut file_src_id = 0u32;
 
         if let Some(orig) = orig {
             for (idx, src) in orig.sources().enumerate() {
                 builder.set_source(idx as _, src);
-                src_id = idx as u32 + 1;
+                file_src_id = idx as u32 + 1;
             }
             for (idx, contents) in orig.source_contents().enumerate() {
                 builder.set_source_contents(idx as _, contents);
@@ -1041,6 +1042,7 @@ impl SourceMap {
         // mappings.sort_by_key(|v| v.0);
 
         let mut cur_file: Option<Lrc<SourceFile>> = None;
+        let mut cur_orig = orig;
 
         let mut ch_start = 0;
         let mut line_ch_start = 0;
@@ -1059,8 +1061,9 @@ impl SourceMap {
                 Some(ref f) if f.start_pos <= pos && pos < f.end_pos => f,
                 _ => {
                     f = self.lookup_source_file(pos);
-                    src_id = builder.add_source(&config.file_name_to_source(&f.name));
-                    builder.set_source_contents(src_id, Some(&f.src));
+                    file_src_id = builder.add_source(&config.file_name_to_source(&f.name));
+                    builder.set_source_contents(file_src_id, Some(&f.src));
+                    cur_orig = config.orig_source_map(&f.name).or(orig);
                     cur_file = Some(f.clone());
                     ch_start = 0;
                     line_ch_start = 0;
@@ -1087,13 +1090,18 @@ impl SourceMap {
                 linebpos.to_u32() - self.calc_extra_bytes(&f, &mut line_ch_start, linebpos);
 
             let mut col = max(chpos, linechpos) - min(chpos, linechpos);
+            let mut src_id = file_src_id;
 
-            if let Some(orig) = &orig {
-                if let Some(token) = orig.lookup_token(line, col) {
+            if let Some(orig) = cur_orig {
+                // Lines of source maps start at 0.
+                if let Some(token) = orig.lookup_token(a, col) {
                     line = token.get_src_line() + 1;
                     col = token.get_src_col();
                     if let Some(src) = token.get_source() {
                         src_id = builder.add_source(src);
+                        if let Some(contents) = orig.get_source_contents(token.get_src_id()) {
+                            builder.set_source_contents(src_id, Some(contents));
+                        }
                     }
                 }
             }
@@ -1167,6 +1175,15 @@ pub trait SourceMapGenConfig {
     ///
     /// This should **not** return content of the file.
     fn file_name_to_source(&self, f: &FileName) -> String;
+
+    /// Returns the input source map of `f`, if any.
+    ///
+    /// Mappings into `f` are chained with this instead of the `orig` passed to
+    /// [SourceMap::build_source_map_with_config].
+    #[cfg(feature = "sourcemap")]
+    fn orig_source_map(&self, _f: &FileName) -> Option<&sourcemap::SourceMap> {
+        None
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -1181,6 +1198,11 @@ macro_rules! impl_ref {
             fn file_name_to_source(&self, f: &FileName) -> String {
                 (**self).file_name_to_source(f)
             }
+
+            #[cfg(feature = "sourcemap")]
+            fn orig_source_map(&self, f: &FileName) -> Option<&sourcemap::SourceMap> {
+                (**self).orig_source_map(f)
+            }
         }
     };
 }
@@ -64,6 +64,9 @@ pub enum ClassMember {
     TsIndexSignature(TsIndexSignature),
     #[tag("EmptyStatement")]
     Empty(EmptyStmt),
+    /// es2022
+    #[tag("StaticBlock")]
+    StaticBlock(StaticBlock),
 }
 
 #[ast_node("ClassProperty")]
@@ -217,6 +220,16 @@ pub struct Constructor {
     pub is_optional: bool,
 }
 
+/// `static { }`
+#[ast_node("StaticBlock")]
+#[derive(Eq, Hash, EqIgnoreSpan)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct StaticBlock {
+    pub span: Span,
+
+    pub body: BlockStmt,
+}
+
 #[ast_node("Decorator")]
 #[derive(Eq, Hash, EqIgnoreSpan)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
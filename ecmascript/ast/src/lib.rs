@@ -9,7 +9,7 @@
 pub use self::{
     class::{
         Class, ClassMember, ClassMethod, ClassProp, Constructor, Decorator, MethodKind,
-        PrivateMethod, PrivateProp,
+        PrivateMethod, PrivateProp, StaticBlock,
     },
     decl::{ClassDecl, Decl, FnDecl, VarDecl, VarDeclKind, VarDeclarator},
     expr::{
@@ -112,13 +112,17 @@ pub enum EsVersion {
     Es2019,
     #[serde(rename = "es2020")]
     Es2020,
+    #[serde(rename = "es2021")]
+    Es2021,
+    #[serde(rename = "es2022")]
+    Es2022,
 }
 
 impl EsVersion {
-    /// Get the latest version. This is `es2020` for now, but it will be changed
+    /// Get the latest version. This is `es2022` for now, but it will be changed
     /// if a new version of specification is released.
     pub const fn latest() -> Self {
-        EsVersion::Es2020
+        EsVersion::Es2022
     }
 }
 
@@ -166,19 +166,44 @@ impl arbitrary::Arbitrary for Regex {
 }
 
 #[ast_node("NumericLiteral")]
-#[derive(Copy, EqIgnoreSpan)]
-#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Number {
     pub span: Span,
     /// **Note**: This should not be `NaN`. Use [crate::Ident] to represent NaN.
     ///
     /// If you store `NaN` in this field, a hash map will behave strangely.
-    #[use_eq]
     pub value: f64,
+
+    /// The source text of the literal, e.g. `0xFF` or `1e3`.
+    ///
+    /// This is [None] for synthesized numbers. If you modify `value`, you
+    /// should also set this to [None].
+    #[serde(default)]
+    pub raw: Option<JsWord>,
+}
+
+#[cfg(feature = "arbitrary")]
+impl arbitrary::Arbitrary for Number {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
+        let span = u.arbitrary()?;
+        let value = u.arbitrary()?;
+
+        Ok(Self {
+            span,
+            value,
+            raw: None,
+        })
+    }
 }
 
 impl Eq for Number {}
 
+/// `raw` is ignored, as `0x10` and `16` are the same number.
+impl EqIgnoreSpan for Number {
+    fn eq_ignore_span(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
 impl Hash for Number {
     fn hash<H: Hasher>(&self, state: &mut H) {
         fn integer_decode(val: f64) -> (u64, i16, i8) {
@@ -9,6 +9,10 @@ macro_rules! write_comments {
         };
 
         for cmt in cmts.iter() {
+            if !$e.cfg.comments.retains(cmt) {
+                continue;
+            }
+
             match cmt.kind {
                 CommentKind::Line => {
                     if $prefix_space {
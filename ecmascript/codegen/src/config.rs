@@ -1,4 +1,148 @@
-#[derive(Debug, Default, Clone, Copy)]
+use regex::Regex;
+use swc_common::comments::Comment;
+
+#[derive(Debug, Default, Clone)]
 pub struct Config {
     pub minify: bool,
+
+    /// Comments which are emitted.
+    ///
+    /// Note that comments are emitted only if [Emitter::comments] is
+    /// [Some].
+    ///
+    /// [Emitter::comments]: crate::Emitter::comments
+    pub comments: CommentPolicy,
+
+    /// Indentation of a nesting level.
+    ///
+    /// Applied by [JsWriter::with_config].
+    ///
+    /// [JsWriter::with_config]: crate::text_writer::JsWriter::with_config
+    pub indent: Indent,
+
+    pub quote_style: QuoteStyle,
+
+    /// Writes a trailing comma after the last element of object literals,
+    /// array literals and named imports / exports which span multiple lines.
+    pub trailing_comma: bool,
+
+    /// Omits semicolons at the end of lines, and writes a leading semicolon
+    /// for lines which would otherwise continue the previous statement.
+    ///
+    /// Applied by [JsWriter::with_config].
+    ///
+    /// [JsWriter::with_config]: crate::text_writer::JsWriter::with_config
+    pub omit_semi: bool,
+
+    /// Escapes characters outside of ASCII in strings, template literals,
+    /// regular expressions and identifiers.
+    pub ascii_only: bool,
+
+    /// Breaks lines longer than this after `;`, `,` or `{`, where a line
+    /// break doesn't change the meaning of the code.
+    ///
+    /// Applied by [JsWriter::with_config].
+    ///
+    /// [JsWriter::with_config]: crate::text_writer::JsWriter::with_config
+    pub max_line_len: Option<usize>,
+
+    /// Lists in brackets (e.g. arguments or parameters) which would exceed
+    /// this width are printed one element per line.
+    ///
+    /// This is ignored while minifying.
+    pub line_width: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Indent {
+    Spaces(usize),
+    Tab,
+}
+
+impl Default for Indent {
+    fn default() -> Self {
+        Indent::Spaces(4)
+    }
+}
+
+impl Indent {
+    pub(crate) fn to_str(self) -> String {
+        match self {
+            Indent::Spaces(n) => " ".repeat(n),
+            Indent::Tab => String::from("\t"),
+        }
+    }
+}
+
+/// Quotes used for string literals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuoteStyle {
+    /// Keeps quotes of the original source. Synthesized strings use double
+    /// quotes.
+    Preserve,
+    /// Uses double quotes, unless the string contains more double quotes than
+    /// single quotes.
+    Double,
+    /// Uses single quotes, unless the string contains more single quotes than
+    /// double quotes.
+    Single,
+}
+
+impl Default for QuoteStyle {
+    fn default() -> Self {
+        QuoteStyle::Preserve
+    }
+}
+
+impl QuoteStyle {
+    /// Returns [None] if the quotes of the original source should be used.
+    pub(crate) fn single_quote(self, value: &str) -> Option<bool> {
+        let count = |q| value.chars().filter(|&c| c == q).count();
+
+        match self {
+            QuoteStyle::Preserve => None,
+            QuoteStyle::Double => Some(count('"') > count('\'')),
+            QuoteStyle::Single => Some(count('\'') <= count('"')),
+        }
+    }
+}
+
+/// Determines which comments are retained.
+#[derive(Debug, Clone)]
+pub enum CommentPolicy {
+    None,
+    /// Retains license comments. See [is_license_comment].
+    Some,
+    All,
+    /// Retains comments matching the regex.
+    Regex(Regex),
+}
+
+impl Default for CommentPolicy {
+    fn default() -> Self {
+        CommentPolicy::All
+    }
+}
+
+impl CommentPolicy {
+    pub fn retains(&self, c: &Comment) -> bool {
+        match self {
+            CommentPolicy::None => false,
+            CommentPolicy::Some => is_license_comment(&c.text),
+            CommentPolicy::All => true,
+            CommentPolicy::Regex(re) => re.is_match(&c.text),
+        }
+    }
+}
+
+/// Returns true if `text` starts with `!` (e.g. `/*! ... */`), or contains
+/// `@license`, `@preserve` or `@cc_on`.
+///
+/// Comments are stored without `//` and `/*`, so `text` should not contain
+/// them.
+pub fn is_license_comment(text: &str) -> bool {
+    text.trim_start_matches('*').starts_with('!')
+        || text.contains("@license")
+        || text.contains("@preserve")
+        || text.contains("@cc_on")
 }
@@ -25,14 +25,20 @@ impl<'a> Emitter<'a> {
     fn emit_class_decl(&mut self, node: &ClassDecl) -> Result {
         self.emit_leading_comments_of_pos(node.span().lo(), false)?;
 
+        for dec in &node.class.decorators {
+            emit!(dec);
+        }
+
         if node.declare {
             keyword!("declare");
             space!();
         }
 
-        for dec in &node.class.decorators {
-            emit!(dec);
+        if node.class.is_abstract {
+            keyword!("abstract");
+            space!();
         }
+
         keyword!("class");
         space!();
         emit!(node.ident);
@@ -125,4 +131,12 @@ mod tests {
             "function*f(){yield({x})=>x}",
         );
     }
+
+    #[test]
+    fn static_block() {
+        assert_min(
+            "class A { static { a(); } static() {} }",
+            "class A{static{a()}static(){}}",
+        );
+    }
 }
@@ -1,4 +1,4 @@
-use super::{Emitter, Result};
+use super::{to_html_entities, Emitter, Result};
 use crate::list::ListFormat;
 use swc_common::Spanned;
 use swc_ecma_ast::*;
@@ -40,7 +40,7 @@ impl<'a> Emitter<'a> {
     #[emitter]
     fn emit_jsx_element_name(&mut self, node: &JSXElementName) -> Result {
         match *node {
-            JSXElementName::Ident(ref n) => emit!(n),
+            JSXElementName::Ident(ref n) => self.emit_jsx_ident(n)?,
             JSXElementName::JSXMemberExpr(ref n) => emit!(n),
             JSXElementName::JSXNamespacedName(ref n) => emit!(n),
         }
@@ -60,6 +60,7 @@ impl<'a> Emitter<'a> {
     #[emitter]
     fn emit_jsx_attr_value(&mut self, node: &JSXAttrValue) -> Result {
         match *node {
+            JSXAttrValue::Lit(Lit::Str(ref n)) => self.emit_jsx_attr_str(n)?,
             JSXAttrValue::Lit(ref n) => emit!(n),
             JSXAttrValue::JSXExprContainer(ref n) => emit!(n),
             JSXAttrValue::JSXElement(ref n) => emit!(n),
@@ -70,7 +71,7 @@ impl<'a> Emitter<'a> {
     #[emitter]
     fn emit_jsx_attr_name(&mut self, node: &JSXAttrName) -> Result {
         match *node {
-            JSXAttrName::Ident(ref n) => emit!(n),
+            JSXAttrName::Ident(ref n) => self.emit_jsx_ident(n)?,
             JSXAttrName::JSXNamespacedName(ref n) => emit!(n),
         }
     }
@@ -153,9 +154,9 @@ impl<'a> Emitter<'a> {
 
     #[emitter]
     fn emit_jsx_namespaced_name(&mut self, node: &JSXNamespacedName) -> Result {
-        emit!(node.ns);
+        self.emit_jsx_ident(&node.ns)?;
         punct!(":");
-        emit!(node.name);
+        self.emit_jsx_ident(&node.name)?;
     }
 
     #[emitter]
@@ -163,21 +164,54 @@ impl<'a> Emitter<'a> {
 
     #[emitter]
     fn emit_jsx_text(&mut self, node: &JSXText) -> Result {
-        self.emit_js_word(node.span(), &node.value)?;
+        if self.cfg.ascii_only {
+            self.wr
+                .write_str_lit(node.span, &to_html_entities(&node.value))?;
+        } else {
+            self.emit_js_word(node.span(), &node.value)?;
+        }
     }
 
     #[emitter]
     fn emit_jsx_member_expr(&mut self, node: &JSXMemberExpr) -> Result {
         emit!(node.obj);
         punct!(".");
-        emit!(node.prop);
+        self.emit_jsx_ident(&node.prop)?;
     }
 
     #[emitter]
     fn emit_jsx_object(&mut self, node: &JSXObject) -> Result {
         match *node {
-            JSXObject::Ident(ref n) => emit!(n),
+            JSXObject::Ident(ref n) => self.emit_jsx_ident(n)?,
             JSXObject::JSXMemberExpr(ref n) => emit!(n),
         }
     }
+
+    /// JSX names do not support `\u` escapes, so they are emitted as is even
+    /// if [crate::config::Config::ascii_only] is enabled.
+    fn emit_jsx_ident(&mut self, ident: &Ident) -> Result {
+        self.emit_leading_comments_of_pos(ident.span.lo(), false)?;
+        self.wr.write_symbol(ident.span, &ident.sym)?;
+
+        Ok(())
+    }
+
+    /// Emits a string attribute value. Non-ASCII characters are written as
+    /// HTML entities if [crate::config::Config::ascii_only] is enabled.
+    fn emit_jsx_attr_str(&mut self, node: &Str) -> Result {
+        if !self.cfg.ascii_only {
+            return self.emit_str_lit(node);
+        }
+
+        self.emit_leading_comments_of_pos(node.span.lo(), false)?;
+
+        let (single_quote, value) = self.str_lit_value(node);
+        let quote = if single_quote { "'" } else { "\"" };
+        self.wr.write_punct(quote)?;
+        self.wr
+            .write_str_lit(node.span, &to_html_entities(&value))?;
+        self.wr.write_punct(quote)?;
+
+        Ok(())
+    }
 }
@@ -1,13 +1,13 @@
 #![recursion_limit = "1024"]
 #![allow(unused_variables)]
 
-pub use self::config::Config;
+pub use self::config::{is_license_comment, CommentPolicy, Config, Indent, QuoteStyle};
 use self::{
     list::ListFormat,
     text_writer::WriteJs,
     util::{SourceMapperExt, SpanExt, StartsWithAlphaNum},
 };
-use std::{fmt::Write, io, sync::Arc};
+use std::{borrow::Cow, fmt::Write, io, sync::Arc};
 use swc_atoms::JsWord;
 use swc_common::{
     comments::Comments, sync::Lrc, BytePos, SourceMap, Span, Spanned, SyntaxContext, DUMMY_SP,
@@ -145,7 +145,14 @@ impl<'a> Emitter<'a> {
             DefaultDecl::Fn(ref n) => emit!(n),
             DefaultDecl::TsInterfaceDecl(ref n) => emit!(n),
         }
-        formatting_semi!();
+        // A function without body (overload signature) is terminated already.
+        match node.decl {
+            DefaultDecl::Fn(FnExpr {
+                function: Function { body: None, .. },
+                ..
+            }) => {}
+            _ => formatting_semi!(),
+        }
     }
 
     #[emitter]
@@ -155,6 +162,11 @@ impl<'a> Emitter<'a> {
         keyword!("import");
         space!();
 
+        if node.type_only {
+            keyword!("type");
+            space!();
+        }
+
         let mut specifiers = vec![];
         let mut emitted_default = false;
         let mut emitted_ns = false;
@@ -303,6 +315,10 @@ impl<'a> Emitter<'a> {
 
         keyword!("export");
         formatting_space!();
+        if node.type_only {
+            keyword!("type");
+            formatting_space!();
+        }
         if let Some(spec) = namespace_spec {
             emit!(spec);
             if has_named_specs {
@@ -365,7 +381,13 @@ impl<'a> Emitter<'a> {
             Lit::Num(ref n) => emit!(n),
             Lit::Regex(ref n) => {
                 punct!("/");
-                self.wr.write_str(&n.exp)?;
+                if self.cfg.ascii_only {
+                    // Escaped surrogate pairs are not allowed in unicode mode.
+                    let unicode = n.flags.contains('u');
+                    self.wr.write_str(&to_ascii(&n.exp, !unicode))?;
+                } else {
+                    self.wr.write_str(&n.exp)?;
+                }
                 punct!("/");
                 self.wr.write_str(&n.flags)?;
             }
@@ -383,12 +405,33 @@ impl<'a> Emitter<'a> {
     fn emit_str_lit(&mut self, node: &Str) -> Result {
         self.emit_leading_comments_of_pos(node.span().lo(), false)?;
 
-        let (single_quote, value) = match node.kind {
+        let (single_quote, value) = self.str_lit_value(node);
+        let value = if self.cfg.ascii_only {
+            to_ascii(&value, self.wr.target() < JscTarget::Es2015).into_owned()
+        } else {
+            value
+        };
+
+        if single_quote {
+            punct!("'");
+            self.wr.write_str_lit(node.span, &value)?;
+            punct!("'");
+        } else {
+            punct!("\"");
+            self.wr.write_str_lit(node.span, &value)?;
+            punct!("\"");
+        }
+    }
+
+    /// Returns whether `node` should be quoted with single quotes, and its
+    /// escaped value.
+    fn str_lit_value(&self, node: &Str) -> (bool, String) {
+        match node.kind {
             StrKind::Normal { contains_quote } => {
-                let single_quote = if contains_quote {
-                    is_single_quote(&self.cm, node.span)
-                } else {
-                    None
+                let single_quote = match self.cfg.quote_style.single_quote(&node.value) {
+                    Some(single_quote) => Some(single_quote),
+                    None if contains_quote => is_single_quote(&self.cm, node.span),
+                    None => None,
                 };
 
                 let value = escape_with_source(
@@ -402,21 +445,15 @@ impl<'a> Emitter<'a> {
                 (single_quote.unwrap_or(false), value)
             }
             StrKind::Synthesized => {
-                let single_quote = false;
+                let single_quote = self
+                    .cfg
+                    .quote_style
+                    .single_quote(&node.value)
+                    .unwrap_or(false);
                 let value = escape_without_source(&node.value, self.wr.target(), single_quote);
 
                 (single_quote, value)
             }
-        };
-
-        if single_quote {
-            punct!("'");
-            self.wr.write_str_lit(node.span, &value)?;
-            punct!("'");
-        } else {
-            punct!("\"");
-            self.wr.write_str_lit(node.span, &value)?;
-            punct!("\"");
         }
     }
 
@@ -424,19 +461,38 @@ impl<'a> Emitter<'a> {
     fn emit_num_lit(&mut self, num: &Number) -> Result {
         self.emit_leading_comments_of_pos(num.span().lo(), false)?;
 
-        // Handle infinity
-        if num.value.is_infinite() {
-            if num.value.is_sign_negative() {
-                self.wr.write_str_lit(num.span, "-")?;
-            }
-            self.wr.write_str_lit(num.span, "Infinity")?;
-        } else {
-            if num.value.is_sign_negative() && num.value == 0.0 {
-                self.wr.write_str_lit(num.span, "-0.0")?;
-            } else {
-                self.wr.write_str_lit(num.span, &format!("{}", num.value))?;
+        let text = self.num_lit_text(num);
+        self.wr.write_str_lit(num.span, &text)?;
+    }
+
+    /// Returns the source text of a numeric literal.
+    ///
+    /// The original spelling (e.g. `0xFF`) is preserved unless minifying, in
+    /// which case the shortest spelling of the value is used.
+    ///
+    /// Syntax not supported by the target is not preserved. Numeric
+    /// separators are removed below es2021, and binary or octal literals are
+    /// printed as decimal below es2015.
+    fn num_lit_text<'n>(&self, num: &'n Number) -> Cow<'n, str> {
+        if !self.cfg.minify {
+            if let Some(raw) = &num.raw {
+                let target = self.wr.target();
+                let is_bin_or_oct = raw.starts_with("0b")
+                    || raw.starts_with("0B")
+                    || raw.starts_with("0o")
+                    || raw.starts_with("0O");
+
+                if target < JscTarget::Es2015 && is_bin_or_oct {
+                    // Fall back to the value.
+                } else if target < JscTarget::Es2021 && raw.contains('_') {
+                    return Cow::Owned(raw.replace('_', ""));
+                } else {
+                    return Cow::Borrowed(raw);
+                }
             }
         }
+
+        Cow::Owned(number_to_str(num.value, self.cfg.minify))
     }
 
     #[emitter]
@@ -547,6 +603,10 @@ impl<'a> Emitter<'a> {
                 emit!(e.callee);
                 self.wr.write_operator("?.")?;
 
+                if let Some(type_args) = &e.type_args {
+                    emit!(type_args);
+                }
+
                 punct!("(");
                 self.emit_expr_or_spreads(n.span(), &e.args, ListFormat::CallExpressionArguments)?;
                 punct!(")");
@@ -568,6 +628,10 @@ impl<'a> Emitter<'a> {
 
         emit!(node.callee);
 
+        if let Some(type_args) = &node.type_args {
+            emit!(type_args);
+        }
+
         punct!("(");
         self.emit_expr_or_spreads(node.span(), &node.args, ListFormat::CallExpressionArguments)?;
         punct!(")");
@@ -620,26 +684,20 @@ impl<'a> Emitter<'a> {
     /// `1..toString` is a valid property access, emit a dot after the literal
     pub fn needs_2dots_for_property_access(&self, expr: &ExprOrSuper) -> bool {
         match *expr {
-            ExprOrSuper::Expr(ref expr) => {
-                match **expr {
-                    Expr::Lit(Lit::Num(Number { span, value })) => {
-                        if value.fract() == 0.0 {
-                            return true;
-                        }
-                        // check if numeric literal is a decimal literal that was originally written
-                        // with a dot
-                        if let Ok(text) = self.cm.span_to_snippet(span) {
-                            if text.contains('.') {
-                                return false;
-                            }
-                            text.starts_with('0') || text.ends_with(' ')
-                        } else {
-                            true
-                        }
-                    }
-                    _ => false,
+            ExprOrSuper::Expr(ref expr) => match **expr {
+                Expr::Lit(Lit::Num(ref num)) => {
+                    // A dot right after a decimal integer is a decimal point.
+                    let text = self.num_lit_text(num);
+                    let text = text.trim_start_matches('-');
+
+                    let is_legacy_octal = text.len() > 1
+                        && text.starts_with('0')
+                        && text.bytes().all(|c| b'0' <= c && c <= b'7');
+
+                    !is_legacy_octal && text.bytes().all(|c| c.is_ascii_digit() || c == b'_')
                 }
-            }
+                _ => false,
+            },
             _ => false,
         }
     }
@@ -667,8 +725,10 @@ impl<'a> Emitter<'a> {
         }
 
         let parens = !self.cfg.minify
+            || node.type_params.is_some()
+            || node.return_type.is_some()
             || match node.params.as_slice() {
-                [Pat::Ident(_)] => false,
+                [Pat::Ident(i)] => i.type_ann.is_some() || i.id.optional,
                 _ => true,
             };
 
@@ -683,6 +743,12 @@ impl<'a> Emitter<'a> {
             punct!(")");
         }
 
+        if let Some(ty) = &node.return_type {
+            punct!(":");
+            formatting_space!();
+            emit!(ty);
+        }
+
         punct!("=>");
         emit!(node.body);
     }
@@ -813,6 +879,11 @@ impl<'a> Emitter<'a> {
             emit!(dec);
         }
 
+        if node.class.is_abstract {
+            keyword!("abstract");
+            space!();
+        }
+
         keyword!("class");
 
         if let Some(ref i) = node.ident {
@@ -834,6 +905,18 @@ impl<'a> Emitter<'a> {
             keyword!("extends");
             space!();
             emit!(node.super_class);
+            emit!(node.super_type_params);
+            space!();
+        }
+
+        if !node.implements.is_empty() {
+            keyword!("implements");
+            space!();
+            self.emit_list(
+                node.span,
+                Some(&node.implements),
+                ListFormat::HeritageClauseTypes,
+            )?;
             space!();
         }
 
@@ -850,8 +933,12 @@ impl<'a> Emitter<'a> {
             ClassMember::Method(ref n) => emit!(n),
             ClassMember::PrivateMethod(ref n) => emit!(n),
             ClassMember::PrivateProp(ref n) => emit!(n),
-            ClassMember::TsIndexSignature(ref n) => emit!(n),
+            ClassMember::TsIndexSignature(ref n) => {
+                emit!(n);
+                formatting_semi!();
+            }
             ClassMember::Empty(ref n) => emit!(n),
+            ClassMember::StaticBlock(ref n) => emit!(n),
         }
     }
 
@@ -859,16 +946,31 @@ impl<'a> Emitter<'a> {
     fn emit_private_method(&mut self, n: &PrivateMethod) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo(), false)?;
 
+        self.emit_list(n.span, Some(&n.function.decorators), ListFormat::Decorators)?;
+
+        self.emit_accesibility(n.accessibility)?;
+
         if n.is_static {
             keyword!("static");
             space!();
         }
+
+        if n.is_abstract {
+            keyword!("abstract");
+            space!();
+        }
+
+        if n.is_override {
+            keyword!("override");
+            space!();
+        }
+
         match n.kind {
             MethodKind::Method => {
                 if n.function.is_async {
                     keyword!("async");
+                    space!();
                 }
-                space!();
                 if n.function.is_generator {
                     punct!("*");
                 }
@@ -889,6 +991,10 @@ impl<'a> Emitter<'a> {
             }
         }
 
+        if n.is_optional {
+            punct!("?");
+        }
+
         self.emit_fn_trailing(&n.function)?;
     }
 
@@ -907,12 +1013,25 @@ impl<'a> Emitter<'a> {
     fn emit_class_method(&mut self, n: &ClassMethod) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo(), false)?;
 
+        self.emit_list(n.span, Some(&n.function.decorators), ListFormat::Decorators)?;
+
         self.emit_accesibility(n.accessibility)?;
 
         if n.is_static {
             keyword!("static");
             space!();
         }
+
+        if n.is_abstract {
+            keyword!("abstract");
+            space!();
+        }
+
+        if n.is_override {
+            keyword!("override");
+            space!();
+        }
+
         match n.kind {
             MethodKind::Method => {
                 if n.function.is_async {
@@ -939,6 +1058,10 @@ impl<'a> Emitter<'a> {
             }
         }
 
+        if n.is_optional {
+            punct!("?");
+        }
+
         if let Some(type_params) = &n.function.type_params {
             emit!(type_params);
         }
@@ -973,12 +1096,36 @@ impl<'a> Emitter<'a> {
 
         self.emit_accesibility(n.accessibility)?;
 
+        if n.is_static {
+            keyword!("static");
+            space!();
+        }
+
+        if n.is_abstract {
+            keyword!("abstract");
+            space!();
+        }
+
+        if n.is_override {
+            keyword!("override");
+            space!();
+        }
+
         if n.readonly {
             keyword!("readonly");
             space!();
         }
 
         emit!(n.key);
+
+        if n.is_optional {
+            punct!("?");
+        }
+
+        if n.definite {
+            punct!("!");
+        }
+
         if let Some(type_ann) = &n.type_ann {
             punct!(":");
             space!();
@@ -1006,13 +1153,15 @@ impl<'a> Emitter<'a> {
     fn emit_class_prop(&mut self, n: &ClassProp) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo(), false)?;
 
-        if n.accessibility != Some(Accessibility::Public) {
-            self.emit_accesibility(n.accessibility)?;
+        self.emit_list(n.span, Some(&n.decorators), ListFormat::Decorators)?;
+
+        if n.declare {
+            keyword!("declare");
+            space!();
         }
 
-        if n.readonly {
-            keyword!("readonly");
-            space!()
+        if n.accessibility != Some(Accessibility::Public) {
+            self.emit_accesibility(n.accessibility)?;
         }
 
         if n.is_static {
@@ -1020,6 +1169,21 @@ impl<'a> Emitter<'a> {
             space!();
         }
 
+        if n.is_abstract {
+            keyword!("abstract");
+            space!();
+        }
+
+        if n.is_override {
+            keyword!("override");
+            space!();
+        }
+
+        if n.readonly {
+            keyword!("readonly");
+            space!()
+        }
+
         if n.computed {
             punct!("[");
             emit!(n.key);
@@ -1028,6 +1192,14 @@ impl<'a> Emitter<'a> {
             emit!(n.key);
         }
 
+        if n.is_optional {
+            punct!("?");
+        }
+
+        if n.definite {
+            punct!("!");
+        }
+
         if let Some(ty) = &n.type_ann {
             punct!(":");
             space!();
@@ -1071,6 +1243,9 @@ impl<'a> Emitter<'a> {
         self.emit_accesibility(n.accessibility)?;
 
         keyword!("constructor");
+        if n.is_optional {
+            punct!("?");
+        }
         punct!("(");
         self.emit_list(n.span(), Some(&n.params), ListFormat::Parameters)?;
         punct!(")");
@@ -1082,6 +1257,15 @@ impl<'a> Emitter<'a> {
         }
     }
 
+    #[emitter]
+    fn emit_static_block(&mut self, n: &StaticBlock) -> Result {
+        self.emit_leading_comments_of_pos(n.span().lo(), false)?;
+
+        keyword!("static");
+        formatting_space!();
+        emit!(n.body);
+    }
+
     #[emitter]
     fn emit_prop_name(&mut self, node: &PropName) -> Result {
         match *node {
@@ -1216,8 +1400,12 @@ impl<'a> Emitter<'a> {
 
     #[emitter]
     fn emit_quasi(&mut self, node: &TplElement) -> Result {
-        self.wr
-            .write_str_lit(node.span, &unescape(&node.raw.value))?;
+        let raw = unescape(&node.raw.value);
+        if self.cfg.ascii_only {
+            self.wr.write_str_lit(node.span, &to_ascii(&raw, false))?;
+        } else {
+            self.wr.write_str_lit(node.span, &raw)?;
+        }
         return Ok(());
     }
 
@@ -1382,6 +1570,11 @@ impl<'a> Emitter<'a> {
         space!();
         punct!("(");
         punct!(")");
+        if let Some(ty) = &node.type_ann {
+            punct!(":");
+            formatting_space!();
+            emit!(ty);
+        }
         formatting_space!();
         emit!(node.body);
     }
@@ -1460,7 +1653,12 @@ impl<'a> Emitter<'a> {
         self.emit_leading_comments_of_pos(ident.span.lo(), false)?;
 
         // TODO: span
-        self.wr.write_symbol(ident.span, &ident.sym)?;
+        if self.cfg.ascii_only {
+            self.wr
+                .write_symbol(ident.span, &to_ascii(&ident.sym, false))?;
+        } else {
+            self.wr.write_symbol(ident.span, &ident.sym)?;
+        }
         if ident.optional {
             punct!("?");
         }
@@ -1486,6 +1684,60 @@ impl<'a> Emitter<'a> {
         )
     }
 
+    /// Returns a multi-line format if `children` don't fit in
+    /// [Config::line_width] when printed on a single line.
+    fn fit_line_width<N: Node>(
+        &mut self,
+        parent_node: Span,
+        children: &[N],
+        format: ListFormat,
+        start: usize,
+        count: usize,
+    ) -> io::Result<ListFormat> {
+        let line_width = match self.cfg.line_width {
+            Some(v) if !self.cfg.minify => v,
+            _ => return Ok(format),
+        };
+
+        if !format.contains(ListFormat::CommaDelimited)
+            || !format.intersects(ListFormat::BracketsMask | ListFormat::SpaceBetweenBraces)
+            || self
+                .cm
+                .should_write_leading_line_terminator(parent_node, children, format)
+        {
+            return Ok(format);
+        }
+
+        let mut buf = vec![];
+        {
+            let mut emitter = Emitter {
+                cfg: Config {
+                    line_width: None,
+                    ..self.cfg.clone()
+                },
+                cm: self.cm.clone(),
+                comments: None,
+                wr: Box::new(text_writer::JsWriter::with_target(
+                    self.cm.clone(),
+                    "\n",
+                    &mut buf,
+                    None,
+                    self.wr.target(),
+                )),
+            };
+            emitter.emit_list5(parent_node, Some(children), format, start, count)?;
+        }
+
+        // Lists containing a block (e.g. a callback) are checked up to the first
+        // line break.
+        let width = buf.iter().position(|&c| c == b'\n').unwrap_or(buf.len());
+        if self.wr.column() + width <= line_width {
+            return Ok(format);
+        }
+
+        Ok((format - ListFormat::LinesMask) | ListFormat::MultiLine | ListFormat::Indented)
+    }
+
     #[allow(clippy::cognitive_complexity)]
     fn emit_list5<N: Node>(
         &mut self,
@@ -1504,6 +1756,13 @@ impl<'a> Emitter<'a> {
             return Ok(());
         }
 
+        let format = match children {
+            Some(children) if !is_empty => {
+                self.fit_line_width(parent_node, children, format, start, count)?
+            }
+            _ => format,
+        };
+
         if format.contains(ListFormat::BracketsMask) {
             self.wr.write_punct(format.opening_bracket())?;
 
@@ -1644,9 +1903,21 @@ impl<'a> Emitter<'a> {
                 }
             };
 
-            if has_trailing_comma && format.contains(ListFormat::CommaDelimited) {
-                self.wr.write_punct(",")?;
-                formatting_space!(self);
+            let should_write_closing_line_terminator = self
+                .cm
+                .should_write_closing_line_terminator(parent_node, children, format);
+
+            if format.contains(ListFormat::CommaDelimited) {
+                if has_trailing_comma {
+                    self.wr.write_punct(",")?;
+                    formatting_space!(self);
+                } else if self.cfg.trailing_comma
+                    && !self.cfg.minify
+                    && format.contains(ListFormat::MultiLineTrailingComma)
+                    && should_write_closing_line_terminator
+                {
+                    self.wr.write_punct(",")?;
+                }
             }
 
             {
@@ -1681,10 +1952,7 @@ impl<'a> Emitter<'a> {
             }
 
             // Write the closing line terminator or closing whitespace.
-            if self
-                .cm
-                .should_write_closing_line_terminator(parent_node, children, format)
-            {
+            if should_write_closing_line_terminator {
                 if !self.cfg.minify {
                     self.wr.write_line()?;
                 }
@@ -2267,6 +2535,58 @@ impl<'a> Emitter<'a> {
     }
 }
 
+/// Formats a number for printing.
+///
+/// If `minify` is true, the shortest of the decimal, exponential (`1e3`) and
+/// hexadecimal (`0xfffffffffff`) spellings is returned. Leading zeros of
+/// fractions are omitted (`.5`).
+fn number_to_str(value: f64, minify: bool) -> String {
+    if value.is_infinite() {
+        return if value.is_sign_negative() {
+            "-Infinity".into()
+        } else {
+            "Infinity".into()
+        };
+    }
+
+    // `-0.0` is printed as `-0`.
+    if !minify {
+        return format!("{}", value);
+    }
+
+    let sign = if value.is_sign_negative() { "-" } else { "" };
+    let value = value.abs();
+
+    let mut best = format!("{}", value);
+    if best.starts_with("0.") {
+        best.remove(0);
+    }
+
+    let mut consider = |s: String| {
+        if s.len() < best.len() {
+            best = s;
+        }
+    };
+
+    if value != 0.0 {
+        consider(format!("{:e}", value));
+
+        // `123456789e3`
+        if value.fract() == 0.0 {
+            let digits = format!("{}", value);
+            let mantissa = digits.trim_end_matches('0');
+            consider(format!("{}e{}", mantissa, digits.len() - mantissa.len()));
+        }
+    }
+
+    // Integers above 2^53 are not lexed exactly from a hexadecimal literal.
+    if value.fract() == 0.0 && value <= 9007199254740992.0 {
+        consider(format!("{:#x}", value as u64));
+    }
+
+    format!("{}{}", sign, best)
+}
+
 #[allow(dead_code)]
 fn get_text_of_node<T: Spanned>(
     cm: &Arc<SourceMap>,
@@ -2509,6 +2829,54 @@ fn escape_without_source(v: &str, target: JscTarget, single_quote: bool) -> Stri
     buf
 }
 
+/// Escapes characters outside of ASCII using `\u` escapes.
+///
+/// Characters outside of the BMP are escaped as surrogate pairs if
+/// `surrogate_pairs` is true, and as `\u{...}` otherwise. Identifiers only
+/// support the latter.
+fn to_ascii(s: &str, surrogate_pairs: bool) -> Cow<str> {
+    if s.is_ascii() {
+        return Cow::Borrowed(s);
+    }
+
+    let mut buf = String::with_capacity(s.len() + 8);
+    for c in s.chars() {
+        if c.is_ascii() {
+            buf.push(c);
+        } else if (c as u32) <= 0xffff {
+            let _ = write!(buf, "\\u{:04x}", c as u32);
+        } else if surrogate_pairs {
+            let mut units = [0; 2];
+            for unit in c.encode_utf16(&mut units) {
+                let _ = write!(buf, "\\u{:04x}", unit);
+            }
+        } else {
+            let _ = write!(buf, "\\u{{{:x}}}", c as u32);
+        }
+    }
+
+    Cow::Owned(buf)
+}
+
+/// Escapes characters outside of ASCII using HTML entities, as JSX strings
+/// do not support `\u` escapes.
+fn to_html_entities(s: &str) -> Cow<str> {
+    if s.is_ascii() {
+        return Cow::Borrowed(s);
+    }
+
+    let mut buf = String::with_capacity(s.len() + 8);
+    for c in s.chars() {
+        if c.is_ascii() {
+            buf.push(c);
+        } else {
+            let _ = write!(buf, "&#x{:x};", c as u32);
+        }
+    }
+
+    Cow::Owned(buf)
+}
+
 fn escape_with_source<'s>(
     cm: &SourceMap,
     target: JscTarget,
@@ -78,6 +78,9 @@ add_bitflags!(
         /// If the literal is empty, do not add spaces between braces.
         NoSpaceIfEmpty: 1 << 18,
         SingleElement: 1 << 19,
+        /// Write a trailing comma if the list spans multiple lines and
+        /// `Config::trailing_comma` is enabled.
+        MultiLineTrailingComma: 1 << 20,
     },
     /// Precomputed Formats
     Values {
@@ -115,13 +118,15 @@ add_bitflags!(
             | SpaceBetweenBraces
             | Indented
             | Braces
-            | NoSpaceIfEmpty,
+            | NoSpaceIfEmpty
+            | MultiLineTrailingComma,
         ArrayLiteralExpressionElements: PreserveLines
             | CommaDelimited
             | SpaceBetweenSiblings
             | AllowTrailingComma
             | Indented
-            | SquareBrackets,
+            | SquareBrackets
+            | MultiLineTrailingComma,
         CommaListElements: CommaDelimited | SpaceBetweenSiblings | SingleLine,
         CallExpressionArguments: CommaDelimited | SpaceBetweenSiblings | SingleLine | Parenthesis,
         NewExpressionArguments: CommaDelimited
@@ -144,7 +149,8 @@ add_bitflags!(
             | SpaceBetweenSiblings
             | AllowTrailingComma
             | SingleLine
-            | SpaceBetweenBraces,
+            | SpaceBetweenBraces
+            | MultiLineTrailingComma,
         JsxElementOrFragmentChildren: SingleLine | NoInterveningComments,
         JsxElementAttributes: SingleLine | SpaceBetweenSiblings | NoInterveningComments,
         CaseOrDefaultClauseStatements: Indented | MultiLine | NoTrailingNewLine | OptionalIfEmpty,
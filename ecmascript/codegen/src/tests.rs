@@ -12,6 +12,7 @@ use swc_ecma_parser;
 
 struct Builder {
     cfg: Config,
+    target: JscTarget,
     cm: Lrc<SourceMap>,
     comments: SingleThreadedComments,
 }
@@ -21,7 +22,9 @@ impl Builder {
     where
         F: FnOnce(&mut Emitter<'_>) -> Ret,
     {
-        let writer = text_writer::JsWriter::new(self.cm.clone(), "\n", s, None);
+        let writer =
+            text_writer::JsWriter::with_target(self.cm.clone(), "\n", s, None, self.target)
+                .with_config(&self.cfg);
         let writer: Box<dyn WriteJs> = if self.cfg.minify {
             Box::new(omit_trailing_semi(writer))
         } else {
@@ -53,6 +56,15 @@ impl Builder {
 }
 
 fn parse_then_emit(from: &str, cfg: Config, syntax: Syntax) -> String {
+    parse_then_emit_with_target(from, cfg, syntax, JscTarget::Es2020)
+}
+
+fn parse_then_emit_with_target(
+    from: &str,
+    cfg: Config,
+    syntax: Syntax,
+    target: JscTarget,
+) -> String {
     ::testing::run_test(false, |cm, handler| {
         let src = cm.new_source_file(FileName::Real("custom.js".into()), from.to_string());
         println!(
@@ -74,14 +86,27 @@ fn parse_then_emit(from: &str, cfg: Config, syntax: Syntax) -> String {
             res?
         };
 
-        let out = Builder { cfg, cm, comments }.text(from, |e| e.emit_module(&res).unwrap());
+        let out = Builder {
+            cfg,
+            target,
+            cm,
+            comments,
+        }
+        .text(from, |e| e.emit_module(&res).unwrap());
         Ok(out)
     })
     .unwrap()
 }
 
 pub(crate) fn assert_min(from: &str, to: &str) {
-    let out = parse_then_emit(from, Config { minify: true }, Syntax::default());
+    let out = parse_then_emit(
+        from,
+        Config {
+            minify: true,
+            ..Default::default()
+        },
+        Syntax::default(),
+    );
 
     assert_eq!(DebugUsingDisplay(out.trim()), DebugUsingDisplay(to),);
 }
@@ -90,7 +115,10 @@ pub(crate) fn assert_min(from: &str, to: &str) {
 pub(crate) fn assert_min_typescript(from: &str, to: &str) {
     let out = parse_then_emit(
         from,
-        Config { minify: true },
+        Config {
+            minify: true,
+            ..Default::default()
+        },
         Syntax::Typescript(Default::default()),
     );
 
@@ -98,7 +126,14 @@ pub(crate) fn assert_min_typescript(from: &str, to: &str) {
 }
 
 pub(crate) fn assert_pretty(from: &str, to: &str) {
-    let out = parse_then_emit(from, Config { minify: false }, Syntax::default());
+    let out = parse_then_emit(
+        from,
+        Config {
+            minify: false,
+            ..Default::default()
+        },
+        Syntax::default(),
+    );
 
     println!("Expected: {:?}", to);
     println!("Actaul:   {:?}", out);
@@ -205,7 +240,10 @@ fn empty_named_export_min() {
     test_from_to_custom_config(
         "export { }",
         "export{};",
-        Config { minify: true },
+        Config {
+            minify: true,
+            ..Default::default()
+        },
         Default::default(),
     );
 }
@@ -220,7 +258,10 @@ fn empty_named_export_from_min() {
     test_from_to_custom_config(
         "export { } from 'foo';",
         "export{}from'foo';",
-        Config { minify: true },
+        Config {
+            minify: true,
+            ..Default::default()
+        },
         Default::default(),
     );
 }
@@ -235,7 +276,10 @@ fn named_export_from_min() {
     test_from_to_custom_config(
         "export { bar } from 'foo';",
         "export{bar}from'foo';",
-        Config { minify: true },
+        Config {
+            minify: true,
+            ..Default::default()
+        },
         Default::default(),
     );
 }
@@ -258,7 +302,10 @@ fn export_namespace_from_min() {
     test_from_to_custom_config(
         "export * as Foo from 'foo';",
         "export*as Foo from'foo';",
-        Config { minify: true },
+        Config {
+            minify: true,
+            ..Default::default()
+        },
         Syntax::Es(EsConfig {
             export_namespace_from: true,
             ..EsConfig::default()
@@ -284,7 +331,10 @@ fn named_and_namespace_export_from_min() {
     test_from_to_custom_config(
         "export * as Foo, { bar } from 'foo';",
         "export*as Foo,{bar}from'foo';",
-        Config { minify: true },
+        Config {
+            minify: true,
+            ..Default::default()
+        },
         Syntax::Es(EsConfig {
             export_namespace_from: true,
             ..EsConfig::default()
@@ -561,6 +611,349 @@ fn issue_1452_1() {
     assert_min("async foo => 0", "async foo=>0");
 }
 
+fn emit_comments(policy: CommentPolicy) -> String {
+    parse_then_emit(
+        "/*! banner */\n/* @license MIT */\n/* normal */\n// @preserve\nfoo();",
+        Config {
+            comments: policy,
+            ..Default::default()
+        },
+        Syntax::default(),
+    )
+}
+
+#[test]
+fn comment_policy_none() {
+    let out = emit_comments(CommentPolicy::None);
+    assert!(!out.contains("/*"), "{}", out);
+    assert!(!out.contains("//"), "{}", out);
+}
+
+#[test]
+fn comment_policy_some() {
+    let out = emit_comments(CommentPolicy::Some);
+    assert!(out.contains("banner"), "{}", out);
+    assert!(out.contains("@license"), "{}", out);
+    assert!(out.contains("@preserve"), "{}", out);
+    assert!(!out.contains("normal"), "{}", out);
+}
+
+#[test]
+fn comment_policy_all() {
+    let out = emit_comments(CommentPolicy::All);
+    assert!(out.contains("banner"), "{}", out);
+    assert!(out.contains("normal"), "{}", out);
+}
+
+#[test]
+fn comment_policy_regex() {
+    let out = emit_comments(CommentPolicy::Regex(regex::Regex::new("normal").unwrap()));
+    assert!(!out.contains("banner"), "{}", out);
+    assert!(out.contains("normal"), "{}", out);
+}
+
+#[test]
+fn license_comment() {
+    assert!(is_license_comment("! banner"));
+    assert!(is_license_comment("*! banner"));
+    assert!(is_license_comment("* @license MIT"));
+    assert!(is_license_comment("@cc_on"));
+    assert!(!is_license_comment("* normal"));
+}
+
+fn emit_pretty(src: &str, cfg: Config) -> String {
+    parse_then_emit(src, cfg, Syntax::default())
+}
+
+#[test]
+fn indent_tab() {
+    let out = emit_pretty(
+        "if (a) { b(); }",
+        Config {
+            indent: Indent::Tab,
+            ..Default::default()
+        },
+    );
+    assert!(out.contains("\n\tb();\n"), "{}", out);
+}
+
+#[test]
+fn indent_spaces() {
+    let out = emit_pretty(
+        "if (a) { if (b) { c(); } }",
+        Config {
+            indent: Indent::Spaces(2),
+            ..Default::default()
+        },
+    );
+    assert!(out.contains("\n    c();\n"), "{}", out);
+}
+
+#[test]
+fn quote_style_single() {
+    let out = emit_pretty(
+        "a(\"b\"); c(\"it's\");",
+        Config {
+            quote_style: QuoteStyle::Single,
+            ..Default::default()
+        },
+    );
+    assert!(out.contains("a('b');"), "{}", out);
+    assert!(out.contains("c(\"it's\");"), "{}", out);
+}
+
+#[test]
+fn quote_style_double() {
+    let out = emit_pretty(
+        "a('b');",
+        Config {
+            quote_style: QuoteStyle::Double,
+            ..Default::default()
+        },
+    );
+    assert!(out.contains("a(\"b\");"), "{}", out);
+}
+
+#[test]
+fn trailing_comma() {
+    let out = emit_pretty(
+        "const a = { b: 1 }; foo(a, b);",
+        Config {
+            indent: Indent::Spaces(2),
+            trailing_comma: true,
+            ..Default::default()
+        },
+    );
+    assert!(out.contains("{\n  b: 1,\n}"), "{}", out);
+    assert!(out.contains("foo(a, b);"), "{}", out);
+}
+
+#[test]
+fn omit_semi() {
+    let out = emit_pretty(
+        "a();\n[1, 2].forEach(b);\nfor (;;) {}\nc();",
+        Config {
+            omit_semi: true,
+            ..Default::default()
+        },
+    );
+    assert!(out.contains("a()\n;[1, 2].forEach(b)\n"), "{}", out);
+    assert!(out.contains(";;"), "{}", out);
+    assert!(out.trim_end().ends_with("c()"), "{}", out);
+}
+
+#[test]
+fn ascii_only() {
+    let out = emit_pretty(
+        "const café = \"é😀\"; /é/; /😀/u; `😀`;",
+        Config {
+            ascii_only: true,
+            ..Default::default()
+        },
+    );
+    assert!(out.is_ascii(), "{}", out);
+    assert!(out.contains("caf\\u00e9"), "{}", out);
+    assert!(out.contains("\"\\u00e9\\u{1f600}\""), "{}", out);
+    assert!(out.contains("/\\u00e9/;"), "{}", out);
+    assert!(out.contains("/\\u{1f600}/u;"), "{}", out);
+    assert!(out.contains("`\\u{1f600}`"), "{}", out);
+}
+
+#[test]
+fn ascii_only_jsx() {
+    let out = parse_then_emit(
+        "<Café data-é=\"é😀\" title={\"é\"}>café</Café>;",
+        Config {
+            ascii_only: true,
+            ..Default::default()
+        },
+        Syntax::Es(EsConfig {
+            jsx: true,
+            ..Default::default()
+        }),
+    );
+    assert!(out.contains("<Café data-é=\"&#xe9;&#x1f600;\""), "{}", out);
+    assert!(out.contains("title={\"\\u00e9\"}"), "{}", out);
+    assert!(out.contains(">caf&#xe9;</Café>"), "{}", out);
+}
+
+#[test]
+fn to_ascii_surrogate_pairs() {
+    assert_eq!(super::to_ascii("a😀", true), "a\\ud83d\\ude00");
+    assert_eq!(super::to_ascii("a😀", false), "a\\u{1f600}");
+}
+
+#[test]
+fn max_line_len() {
+    let out = parse_then_emit(
+        "foo(a, b, c); bar(d, e, f);",
+        Config {
+            minify: true,
+            max_line_len: Some(6),
+            ..Default::default()
+        },
+        Syntax::default(),
+    );
+    assert_eq!(
+        DebugUsingDisplay(out.trim()),
+        DebugUsingDisplay("foo(a,\nb,c);bar(d,\ne,f)")
+    );
+}
+
+#[test]
+fn line_width() {
+    let src = "foo(aaaaaaaaaa, bbbbbbbbbb, cccccccccc);";
+
+    let out = emit_pretty(
+        src,
+        Config {
+            line_width: Some(20),
+            ..Default::default()
+        },
+    );
+    assert!(
+        out.contains("foo(\n    aaaaaaaaaa,\n    bbbbbbbbbb,\n    cccccccccc\n);"),
+        "{}",
+        out
+    );
+
+    let out = emit_pretty(
+        src,
+        Config {
+            line_width: Some(80),
+            ..Default::default()
+        },
+    );
+    assert!(out.contains(src), "{}", out);
+}
+
+#[test]
+fn brand_check() {
+    assert_min(
+        "class A { #m() {} static is(obj) { return #m in obj; } }",
+        "class A{#m(){}static is(obj){return #m in obj}}",
+    );
+}
+
+fn emit_num_lits(target: JscTarget) -> String {
+    let out = parse_then_emit_with_target(
+        "x = 0xFF_FF + 0o755 + 0b11 + 1e21 + .5 + 1_000 + 1.0;",
+        Default::default(),
+        Syntax::Es(EsConfig {
+            num_sep: true,
+            ..Default::default()
+        }),
+        target,
+    );
+
+    out.trim().to_string()
+}
+
+#[test]
+fn num_lit_raw() {
+    assert_eq!(
+        DebugUsingDisplay(&emit_num_lits(JscTarget::Es2021)),
+        DebugUsingDisplay("x = 0xFF_FF + 0o755 + 0b11 + 1e21 + .5 + 1_000 + 1.0;")
+    );
+}
+
+#[test]
+fn num_lit_raw_es2020() {
+    assert_eq!(
+        DebugUsingDisplay(&emit_num_lits(JscTarget::Es2020)),
+        DebugUsingDisplay("x = 0xFFFF + 0o755 + 0b11 + 1e21 + .5 + 1000 + 1.0;")
+    );
+}
+
+#[test]
+fn num_lit_raw_es5() {
+    assert_eq!(
+        DebugUsingDisplay(&emit_num_lits(JscTarget::Es5)),
+        DebugUsingDisplay("x = 0xFFFF + 493 + 3 + 1e21 + .5 + 1000 + 1.0;")
+    );
+}
+
+#[test]
+fn num_lit_min() {
+    assert_min("0xFF", "255");
+    assert_min("1000", "1e3");
+    assert_min("100", "100");
+    assert_min("0.5", ".5");
+    assert_min("0.000001", "1e-6");
+    assert_min("1e21", "1e21");
+    assert_min("123456789000", "123456789e3");
+    assert_min("17592186044415", "0xfffffffffff");
+
+    assert_eq!(number_to_str(-0.0, true), "-0");
+    assert_eq!(number_to_str(-0.0, false), "-0");
+}
+
+#[test]
+fn num_lit_member() {
+    assert_min("1..toString()", "1..toString()");
+    assert_min("1.5.toString()", "1.5.toString()");
+    assert_min("1000..toString()", "1e3.toString()");
+    assert_min("0.5.toString()", ".5.toString()");
+    test_identical("0x10.toString();");
+    test_identical("1e3.toString();");
+    test_identical("1..toString();");
+    test_from_to("1 .toString();", "1..toString();");
+}
+
+/// Numbers printed while minifying should be lexed as exactly the same value.
+#[test]
+fn num_lit_min_roundtrip() {
+    let mut values = vec![
+        0.0,
+        0.1 + 0.2,
+        1e21,
+        1e-7,
+        255.0,
+        9007199254740992.0,
+        f64::MAX,
+        f64::MIN_POSITIVE,
+        5e-324,
+    ];
+    // xorshift64
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    for i in 0..5000 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+
+        let value = f64::from_bits(state).abs();
+        if value.is_finite() {
+            values.push(value);
+        }
+        values.push((state % 1_000_000) as f64 * 10f64.powi(i % 40 - 20));
+        values.push((state >> 11) as f64);
+    }
+
+    for value in values {
+        let text = number_to_str(value, true);
+        let lexed = {
+            let mut parser = Parser::new(
+                Syntax::default(),
+                StringInput::new(&text, BytePos(0), BytePos(text.len() as u32)),
+                None,
+            );
+            match parser.parse_expr().map(|e| *e) {
+                Ok(Expr::Lit(Lit::Num(num))) => num.value,
+                res => panic!("failed to parse {:?} as a number: {:?}", text, res),
+            }
+        };
+
+        assert_eq!(
+            value.to_bits(),
+            lexed.to_bits(),
+            "{} was printed as {}, which is {}",
+            value,
+            text,
+            lexed
+        );
+    }
+}
+
 #[derive(Debug, Clone)]
 struct Buf(Arc<RwLock<Vec<u8>>>);
 impl Write for Buf {
@@ -24,6 +24,14 @@ pub trait WriteJs {
         JscTarget::Es2020
     }
 
+    /// Returns the column where the next character will be written.
+    ///
+    /// Writers which don't track columns return `0`, which means
+    /// [Config::line_width](crate::Config::line_width) has no effect.
+    fn column(&self) -> usize {
+        0
+    }
+
     fn increase_indent(&mut self) -> Result;
     fn decrease_indent(&mut self) -> Result;
 
@@ -109,4 +117,8 @@ where
     fn target(&self) -> JscTarget {
         (**self).target()
     }
+
+    fn column(&self) -> usize {
+        (**self).column()
+    }
 }
@@ -1,4 +1,5 @@
 use super::{Result, WriteJs};
+use crate::config::{Config, Indent};
 use std::io::{self, Write};
 use swc_common::{sync::Lrc, BytePos, LineCol, SourceMap, Span};
 use swc_ecma_parser::JscTarget;
@@ -13,6 +14,7 @@ pub struct JsWriter<'a, W: Write> {
     /// We may use this in future...
     _cm: Lrc<SourceMap>,
     indent: usize,
+    indent_str: String,
     line_start: bool,
     line_count: usize,
     line_pos: usize,
@@ -21,6 +23,15 @@ pub struct JsWriter<'a, W: Write> {
     wr: W,
     written_bytes: usize,
     target: JscTarget,
+
+    /// See [Config::max_line_len].
+    max_line_len: Option<usize>,
+    /// See [Config::omit_semi].
+    omit_semi: bool,
+    /// A semicolon which is written unless a line break follows.
+    pending_semi: bool,
+    /// True if a semicolon was omitted at the end of the previous line.
+    omitted_semi: bool,
 }
 
 impl<'a, W: Write> JsWriter<'a, W> {
@@ -43,6 +54,7 @@ impl<'a, W: Write> JsWriter<'a, W> {
         JsWriter {
             _cm: cm,
             indent: Default::default(),
+            indent_str: Indent::default().to_str(),
             line_start: true,
             line_count: 0,
             line_pos: Default::default(),
@@ -51,15 +63,43 @@ impl<'a, W: Write> JsWriter<'a, W> {
             wr,
             written_bytes: 0,
             target,
+            max_line_len: None,
+            omit_semi: false,
+            pending_semi: false,
+            omitted_semi: false,
         }
     }
 
-    fn write_indent_string(&mut self) -> io::Result<usize> {
-        const INDENT: &[u8] = b"    ";
+    /// Applies [Config::indent], [Config::max_line_len] and
+    /// [Config::omit_semi].
+    pub fn with_config(mut self, cfg: &Config) -> Self {
+        self.indent_str = cfg.indent.to_str();
+        self.max_line_len = cfg.max_line_len;
+        self.omit_semi = cfg.omit_semi;
+        self
+    }
 
+    /// Called after writing `s`.
+    fn break_long_line(&mut self, s: &str) -> Result {
+        if let Some(max) = self.max_line_len {
+            if self.line_pos >= max {
+                match s {
+                    ";" | "," | "{" => self.write_line()?,
+                    _ => {}
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_indent_string(&mut self) -> io::Result<usize> {
         let mut cnt = 0;
         for _ in 0..self.indent {
-            cnt += self.raw_write(INDENT)?;
+            let written = self.wr.write(self.indent_str.as_bytes())?;
+            self.written_bytes += written;
+            self.line_pos += written;
+            cnt += written;
         }
 
         Ok(cnt)
@@ -81,6 +121,17 @@ impl<'a, W: Write> JsWriter<'a, W> {
                 self.line_start = false;
             }
 
+            if self.pending_semi {
+                self.pending_semi = false;
+                cnt += self.raw_write(b";")?;
+            } else if self.omitted_semi {
+                self.omitted_semi = false;
+                // These would continue the statement of the previous line.
+                if data.starts_with(&['(', '[', '`', '+', '-', '/', '*', '<', '.'][..]) {
+                    cnt += self.raw_write(b";")?;
+                }
+            }
+
             if let Some(span) = span {
                 if !span.is_dummy() {
                     self.srcmap(span.lo())
@@ -123,7 +174,16 @@ impl<'a, W: Write> WriteJs for JsWriter<'a, W> {
     }
 
     fn write_semi(&mut self) -> Result {
-        self.write(None, ";")?;
+        if self.omit_semi {
+            if self.pending_semi {
+                self.pending_semi = false;
+                self.write(None, ";")?;
+            }
+            self.pending_semi = true;
+        } else {
+            self.write(None, ";")?;
+            self.break_long_line(";")?;
+        }
         Ok(())
     }
     fn write_space(&mut self) -> Result {
@@ -152,6 +212,11 @@ impl<'a, W: Write> WriteJs for JsWriter<'a, W> {
     }
 
     fn write_line(&mut self) -> Result {
+        if self.pending_semi {
+            self.pending_semi = false;
+            self.omitted_semi = true;
+        }
+
         if !self.line_start {
             self.raw_write(self.new_line.as_bytes())?;
             self.line_count += 1;
@@ -185,7 +250,11 @@ impl<'a, W: Write> WriteJs for JsWriter<'a, W> {
     }
 
     fn write_comment(&mut self, span: Span, s: &str) -> Result {
+        // Comments don't terminate statements.
+        let omitted_semi = self.omitted_semi;
+        self.omitted_semi = false;
         self.write(Some(span), s)?;
+        self.omitted_semi = omitted_semi;
         Ok(())
     }
 
@@ -206,12 +275,21 @@ impl<'a, W: Write> WriteJs for JsWriter<'a, W> {
 
     fn write_punct(&mut self, s: &'static str) -> Result {
         self.write(None, s)?;
+        self.break_long_line(s)?;
         Ok(())
     }
 
     fn target(&self) -> JscTarget {
         self.target
     }
+
+    fn column(&self) -> usize {
+        if self.line_start {
+            self.indent * self.indent_str.len()
+        } else {
+            self.line_pos
+        }
+    }
 }
 
 fn compute_line_starts(s: &str) -> Vec<usize> {
@@ -58,6 +58,10 @@ impl<W: WriteJs> WriteJs for OmitTrailingSemi<W> {
         self.pending_semi = false;
         Ok(self.inner.write_punct(s)?)
     }
+
+    fn column(&self) -> usize {
+        self.inner.column()
+    }
 }
 
 impl<W: WriteJs> OmitTrailingSemi<W> {
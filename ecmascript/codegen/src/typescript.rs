@@ -82,11 +82,21 @@ impl<'a> Emitter<'a> {
     fn emit_ts_constructor_signature_decl(&mut self, n: &TsConstructSignatureDecl) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo(), false)?;
 
-        keyword!("constructor");
+        keyword!("new");
+        if let Some(type_params) = &n.type_params {
+            space!();
+            emit!(type_params);
+        }
 
         punct!("(");
         self.emit_list(n.span, Some(&n.params), ListFormat::Parameters)?;
         punct!(")");
+
+        if let Some(type_ann) = &n.type_ann {
+            punct!(":");
+            formatting_space!();
+            emit!(type_ann);
+        }
     }
 
     #[emitter]
@@ -185,6 +195,7 @@ impl<'a> Emitter<'a> {
         punct!("=");
         formatting_space!();
         emit!(n.expr);
+        formatting_semi!();
     }
 
     #[emitter]
@@ -200,7 +211,10 @@ impl<'a> Emitter<'a> {
     fn emit_ts_external_module_ref(&mut self, n: &TsExternalModuleRef) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo(), false)?;
 
-        unimplemented!("emit_ts_external_module_ref")
+        keyword!("require");
+        punct!("(");
+        emit!(n.expr);
+        punct!(")");
     }
 
     #[emitter]
@@ -250,17 +264,30 @@ impl<'a> Emitter<'a> {
         }
 
         keyword!("import");
+        space!();
+        emit!(n.id);
         formatting_space!();
         punct!("=");
         formatting_space!();
 
         emit!(n.module_ref);
+        formatting_semi!();
     }
 
     #[emitter]
     fn emit_ts_index_signature(&mut self, n: &TsIndexSignature) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo(), false)?;
 
+        if n.is_static {
+            keyword!("static");
+            space!();
+        }
+
+        if n.readonly {
+            keyword!("readonly");
+            space!();
+        }
+
         punct!("[");
         self.emit_list(n.span, Some(&n.params), ListFormat::Parameters)?;
         punct!("]");
@@ -413,7 +440,9 @@ impl<'a> Emitter<'a> {
         self.emit_leading_comments_of_pos(n.span().lo(), false)?;
 
         punct!("{");
-        self.wr.write_line()?;
+        if !self.cfg.minify {
+            self.wr.write_line()?;
+        }
         self.wr.increase_indent()?;
 
         match n.readonly {
@@ -439,21 +468,20 @@ impl<'a> Emitter<'a> {
         punct!("[");
         emit!(n.type_param.name);
 
-        if let Some(constraints) = &n.type_param.constraint {
+        if let Some(constraint) = &n.type_param.constraint {
             space!();
             keyword!("in");
             space!();
+            emit!(constraint);
         }
 
-        if let Some(default) = &n.type_param.default {
-            formatting_space!();
-            punct!("=");
-            formatting_space!();
-            emit!(default);
+        if let Some(name_type) = &n.name_type {
+            space!();
+            keyword!("as");
+            space!();
+            emit!(name_type);
         }
 
-        emit!(n.type_param.constraint);
-
         punct!("]");
 
         match n.optional {
@@ -464,7 +492,7 @@ impl<'a> Emitter<'a> {
                 }
                 TruePlusMinus::Plus => {
                     punct!("+");
-                    punct!("/");
+                    punct!("?");
                 }
                 TruePlusMinus::Minus => {
                     punct!("-");
@@ -473,13 +501,17 @@ impl<'a> Emitter<'a> {
             },
         }
 
-        punct!(":");
-        space!();
-        emit!(n.type_ann);
+        if let Some(type_ann) = &n.type_ann {
+            punct!(":");
+            formatting_space!();
+            emit!(type_ann);
+        }
         formatting_semi!();
 
-        self.wr.write_line()?;
         self.wr.decrease_indent()?;
+        if !self.cfg.minify {
+            self.wr.write_line()?;
+        }
         punct!("}");
     }
 
@@ -489,6 +521,7 @@ impl<'a> Emitter<'a> {
 
         if n.readonly {
             keyword!("readonly");
+            space!();
         }
 
         if n.computed {
@@ -520,8 +553,12 @@ impl<'a> Emitter<'a> {
 
     #[emitter]
     fn emit_ts_module_block(&mut self, n: &TsModuleBlock) -> Result {
-        self.emit_list(n.span, Some(&n.body), ListFormat::SourceFileStatements)?;
         self.emit_leading_comments_of_pos(n.span().lo(), false)?;
+
+        punct!("{");
+        self.emit_list(n.span, Some(&n.body), ListFormat::MultiLineBlockStatements)?;
+        self.emit_leading_comments_of_pos(n.span().hi(), true)?;
+        punct!("}");
     }
 
     #[emitter]
@@ -533,13 +570,20 @@ impl<'a> Emitter<'a> {
             space!();
         }
 
-        keyword!("module");
-        space!();
-        emit!(n.id);
-        formatting_space!();
+        if n.global {
+            keyword!("global");
+        } else {
+            match &n.id {
+                TsModuleName::Ident(..) => keyword!("namespace"),
+                TsModuleName::Str(..) => keyword!("module"),
+            }
+            space!();
+            emit!(n.id);
+        }
 
-        if let Some(body) = &n.body {
-            emit!(body);
+        match &n.body {
+            Some(body) => emit!(body),
+            None => formatting_semi!(),
         }
     }
 
@@ -555,28 +599,29 @@ impl<'a> Emitter<'a> {
     fn emit_ts_module_ref(&mut self, n: &TsModuleRef) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo(), false)?;
 
-        unimplemented!("emit_ts_module_ref")
+        match n {
+            TsModuleRef::TsEntityName(n) => emit!(n),
+            TsModuleRef::TsExternalModuleRef(n) => emit!(n),
+        }
     }
 
     #[emitter]
     fn emit_ts_ns_body(&mut self, n: &TsNamespaceBody) -> Result {
-        self.emit_leading_comments_of_pos(n.span().lo(), false)?;
-
-        punct!("{");
-        self.wr.increase_indent()?;
         match n {
-            TsNamespaceBody::TsModuleBlock(n) => emit!(n),
+            TsNamespaceBody::TsModuleBlock(n) => {
+                formatting_space!();
+                emit!(n)
+            }
             TsNamespaceBody::TsNamespaceDecl(n) => emit!(n),
         }
-        self.wr.decrease_indent()?;
-        punct!("}");
     }
 
+    /// Emits `.B {}` of `namespace A.B {}`.
     #[emitter]
     fn emit_ts_ns_decl(&mut self, n: &TsNamespaceDecl) -> Result {
-        self.emit_leading_comments_of_pos(n.span().lo(), false)?;
-
-        unimplemented!("emit_ts_ns_decl")
+        punct!(".");
+        emit!(n.id);
+        emit!(n.body);
     }
 
     #[emitter]
@@ -585,9 +630,12 @@ impl<'a> Emitter<'a> {
 
         keyword!("export");
         space!();
-        punct!("=");
+        keyword!("as");
+        space!();
+        keyword!("namespace");
         space!();
         emit!(n.id);
+        formatting_semi!();
     }
 
     #[emitter]
@@ -776,11 +824,7 @@ impl<'a> Emitter<'a> {
             emit!(n);
         }
 
-        if let Some(type_args) = &n.type_args {
-            punct!("<");
-            emit!(type_args);
-            punct!(">");
-        }
+        emit!(n.type_args);
     }
 
     #[emitter]
@@ -860,10 +904,27 @@ impl<'a> Emitter<'a> {
 
     #[emitter]
     fn emit_ts_getter_signature(&mut self, n: &TsGetterSignature) -> Result {
+        self.emit_leading_comments_of_pos(n.span().lo(), false)?;
+
+        if n.readonly {
+            keyword!("readonly");
+            space!();
+        }
+
         keyword!("get");
         space!();
 
-        emit!(n.key);
+        if n.computed {
+            punct!("[");
+            emit!(n.key);
+            punct!("]");
+        } else {
+            emit!(n.key);
+        }
+
+        if n.optional {
+            punct!("?");
+        }
 
         punct!("(");
         punct!(")");
@@ -878,10 +939,27 @@ impl<'a> Emitter<'a> {
 
     #[emitter]
     fn emit_ts_setter_signature(&mut self, n: &TsSetterSignature) -> Result {
+        self.emit_leading_comments_of_pos(n.span().lo(), false)?;
+
+        if n.readonly {
+            keyword!("readonly");
+            space!();
+        }
+
         keyword!("set");
         space!();
 
-        emit!(n.key);
+        if n.computed {
+            punct!("[");
+            emit!(n.key);
+            punct!("]");
+        } else {
+            emit!(n.key);
+        }
+
+        if n.optional {
+            punct!("?");
+        }
 
         punct!("(");
         emit!(n.param);
@@ -1032,4 +1110,27 @@ mod tests {
             "var memory:WebAssembly.Memory",
         );
     }
+
+    #[test]
+    fn namespace_decl() {
+        assert_min_typescript(
+            "namespace A.B { export const c = 1; }",
+            "namespace A.B{export const c=1}",
+        );
+    }
+
+    #[test]
+    fn declare_module() {
+        assert_min_typescript("declare module 'foo';", "declare module \"foo\"");
+    }
+
+    #[test]
+    fn import_equals_require() {
+        assert_min_typescript("import x = require('y');", "import x=require(\"y\")");
+    }
+
+    #[test]
+    fn import_equals_entity_name() {
+        assert_min_typescript("import x = A.B;", "import x=A.B");
+    }
 }
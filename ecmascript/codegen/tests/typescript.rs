@@ -0,0 +1,103 @@
+use std::path::{Path, PathBuf};
+use swc_common::{sync::Lrc, SourceMap, Span, DUMMY_SP};
+use swc_ecma_ast::Module;
+use swc_ecma_codegen::{text_writer::JsWriter, Config, Emitter};
+use swc_ecma_parser::{lexer::Lexer, Parser, StringInput, Syntax, TsConfig};
+use swc_ecma_visit::{VisitMut, VisitMutWith};
+
+/// Parses every typescript fixture of the parser, prints it, parses the
+/// output again and ensures that the reparsed module is identical to the
+/// original one, so the emitter does not drop nodes or modifiers.
+#[testing::fixture("../../parser/tests/typescript/**/*.ts")]
+#[testing::fixture("../../parser/tests/typescript/**/*.tsx")]
+fn roundtrip(file: PathBuf) {
+    testing::run_test(false, |cm, _| {
+        let fm = cm.load_file(&file).expect("failed to load fixture");
+
+        // Fixtures which are expected to fail are covered by the parser.
+        let module = match parse(&file, StringInput::from(&*fm)) {
+            Some(module) => module,
+            None => return Ok(()),
+        };
+        let first = print(cm.clone(), &module);
+
+        let fm = cm.new_source_file(file.clone().into(), first.clone());
+        let reparsed = match parse(&file, StringInput::from(&*fm)) {
+            Some(module) => module,
+            None => panic!(
+                "failed to reparse the output of {}:\n{}",
+                file.display(),
+                first
+            ),
+        };
+        let second = print(cm.clone(), &reparsed);
+
+        assert_eq!(first, second, "printing {} is not stable", file.display());
+        assert_eq!(
+            drop_span(module),
+            drop_span(reparsed),
+            "reparsing the output of {} gives a different module:\n{}",
+            file.display(),
+            first
+        );
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+fn parse(file: &Path, input: StringInput) -> Option<Module> {
+    let fname = file.display().to_string();
+    let lexer = Lexer::new(
+        Syntax::Typescript(TsConfig {
+            dts: fname.ends_with(".d.ts"),
+            tsx: fname.contains("tsx"),
+            dynamic_import: true,
+            decorators: true,
+            import_assertions: true,
+            no_early_errors: true,
+            ..Default::default()
+        }),
+        Default::default(),
+        input,
+        None,
+    );
+    let mut parser = Parser::new_from(lexer);
+
+    let module = parser.parse_module().ok()?;
+    if !parser.take_errors().is_empty() {
+        return None;
+    }
+
+    Some(module)
+}
+
+fn print(cm: Lrc<SourceMap>, module: &Module) -> String {
+    let mut buf = vec![];
+
+    {
+        let mut emitter = Emitter {
+            cfg: Config::default(),
+            cm: cm.clone(),
+            comments: None,
+            wr: Box::new(JsWriter::new(cm, "\n", &mut buf, None)),
+        };
+
+        emitter.emit_module(module).unwrap();
+    }
+
+    String::from_utf8(buf).unwrap()
+}
+
+fn drop_span(mut module: Module) -> Module {
+    struct DropSpan;
+
+    impl VisitMut for DropSpan {
+        fn visit_mut_span(&mut self, span: &mut Span) {
+            *span = DUMMY_SP;
+        }
+    }
+
+    module.visit_mut_with(&mut DropSpan);
+    module
+}
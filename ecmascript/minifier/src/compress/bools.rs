@@ -0,0 +1,16 @@
+use swc_ecma_ast::*;
+
+/// `true` => `!0`, `false` => `!1`
+pub(super) fn compress_bool(e: &mut Expr) {
+    if let Expr::Lit(Lit::Bool(Bool { span, value })) = *e {
+        *e = Expr::Unary(UnaryExpr {
+            span,
+            op: op!("!"),
+            arg: Box::new(Expr::Lit(Lit::Num(Number {
+                span,
+                value: if value { 0.0 } else { 1.0 },
+                raw: None,
+            }))),
+        });
+    }
+}
@@ -0,0 +1,124 @@
+use swc_common::Spanned;
+use swc_ecma_ast::*;
+use swc_ecma_transforms_base::ext::MapWithMut;
+
+#[derive(Clone, Copy)]
+enum Kind {
+    Empty,
+    Expr,
+}
+
+fn kind_of(s: &Stmt) -> Option<Kind> {
+    match s {
+        Stmt::Empty(..) => Some(Kind::Empty),
+        Stmt::Expr(..) => Some(Kind::Expr),
+        Stmt::Block(BlockStmt { stmts, .. }) => match &**stmts {
+            [] => Some(Kind::Empty),
+            [Stmt::Expr(..)] => Some(Kind::Expr),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn into_expr(s: Stmt) -> Box<Expr> {
+    match s {
+        Stmt::Expr(s) => s.expr,
+        Stmt::Block(BlockStmt { mut stmts, .. }) => match stmts.pop() {
+            Some(Stmt::Expr(s)) => s.expr,
+            _ => unreachable!("into_expr: block should contain only an expression statement"),
+        },
+        _ => unreachable!("into_expr: invalid statement"),
+    }
+}
+
+fn is_str_lit(e: &Expr) -> bool {
+    match e {
+        Expr::Lit(Lit::Str(..)) => true,
+        Expr::Paren(ParenExpr { expr, .. }) => is_str_lit(expr),
+        _ => false,
+    }
+}
+
+/// Creates `test && right` or `test || right`.
+///
+/// `!a && b` is converted to `a || b`.
+fn logical(op: BinaryOp, test: Box<Expr>, right: Box<Expr>) -> Box<Expr> {
+    let (op, left) = match *test {
+        Expr::Unary(UnaryExpr {
+            op: op!("!"), arg, ..
+        }) => (
+            if op == op!("&&") {
+                op!("||")
+            } else {
+                op!("&&")
+            },
+            arg,
+        ),
+        test => (op, Box::new(test)),
+    };
+
+    Box::new(Expr::Bin(BinExpr {
+        span: left.span(),
+        op,
+        left,
+        right,
+    }))
+}
+
+/// Converts `if` statements with expression statements as bodies into an
+/// expression statement.
+///
+/// As children are visited first, `if` chains become nested ternaries.
+///
+///  - `if (a) b();` => `a && b();`
+///  - `if (a) b(); else c();` => `a ? b() : c();`
+///  - `if (a); else c();` => `a || c();`
+///
+/// `if` statements with a string literal as the test and without bodies are
+/// preserved, as they would become directives.
+pub(super) fn compress_if(s: &mut Stmt) {
+    let (test, cons, alt) = match s {
+        Stmt::If(IfStmt {
+            test, cons, alt, ..
+        }) => (
+            test,
+            kind_of(cons),
+            match alt {
+                Some(alt) => kind_of(alt),
+                None => Some(Kind::Empty),
+            },
+        ),
+        _ => return,
+    };
+    let (cons, alt) = match (cons, alt) {
+        // `if ("use strict");` should not become a directive.
+        (Some(Kind::Empty), Some(Kind::Empty)) if is_str_lit(test) => return,
+        (Some(cons), Some(alt)) => (cons, alt),
+        _ => return,
+    };
+
+    let IfStmt {
+        span,
+        test,
+        cons: cons_stmt,
+        alt: alt_stmt,
+    } = match s.take() {
+        Stmt::If(s) => s,
+        _ => unreachable!(),
+    };
+
+    let expr = match (cons, alt) {
+        (Kind::Empty, Kind::Empty) => test,
+        (Kind::Expr, Kind::Empty) => logical(op!("&&"), test, into_expr(*cons_stmt)),
+        (Kind::Empty, Kind::Expr) => logical(op!("||"), test, into_expr(*alt_stmt.unwrap())),
+        (Kind::Expr, Kind::Expr) => Box::new(Expr::Cond(CondExpr {
+            span,
+            test,
+            cons: into_expr(*cons_stmt),
+            alt: into_expr(*alt_stmt.unwrap()),
+        })),
+    };
+
+    *s = Stmt::Expr(ExprStmt { span, expr });
+}
@@ -0,0 +1,172 @@
+use fxhash::FxHashSet;
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_transforms_base::ext::MapWithMut;
+use swc_ecma_utils::{ident::IdentLike, prepend, Id};
+use swc_ecma_visit::{noop_visit_mut_type, VisitMut, VisitMutWith};
+
+/// Moves all `var` declarations in a function body to the top of the function.
+///
+/// Declarations with a destructuring pattern are not hoisted.
+pub(super) fn hoist_vars(stmts: &mut Vec<Stmt>) {
+    let mut v = Hoister::default();
+    stmts.visit_mut_with(&mut v);
+
+    if v.vars.is_empty() {
+        return;
+    }
+
+    prepend(
+        stmts,
+        Stmt::Decl(Decl::Var(VarDecl {
+            span: DUMMY_SP,
+            kind: VarDeclKind::Var,
+            declare: false,
+            decls: v
+                .vars
+                .into_iter()
+                .map(|id| VarDeclarator {
+                    span: DUMMY_SP,
+                    name: Pat::Ident(id.into()),
+                    init: None,
+                    definite: false,
+                })
+                .collect(),
+        })),
+    );
+}
+
+#[derive(Default)]
+struct Hoister {
+    vars: Vec<Ident>,
+    declared: FxHashSet<Id>,
+}
+
+impl Hoister {
+    fn declare(&mut self, i: &Ident) {
+        if self.declared.insert(i.to_id()) {
+            self.vars.push(i.clone());
+        }
+    }
+
+    /// Returns [None] if `var` cannot be hoisted. Otherwise, returns the
+    /// assignments which should replace the declaration.
+    fn hoist(&mut self, var: &mut VarDecl) -> Option<Option<Box<Expr>>> {
+        if var.kind != VarDeclKind::Var {
+            return None;
+        }
+        let is_simple = var.decls.iter().all(|decl| match decl.name {
+            Pat::Ident(..) => true,
+            _ => false,
+        });
+        if !is_simple {
+            return None;
+        }
+
+        let mut exprs = vec![];
+        for decl in var.decls.take() {
+            let id = match decl.name {
+                Pat::Ident(i) => i.id,
+                _ => unreachable!(),
+            };
+            self.declare(&id);
+
+            if let Some(init) = decl.init {
+                exprs.push(Box::new(Expr::Assign(AssignExpr {
+                    span: decl.span,
+                    op: op!("="),
+                    left: PatOrExpr::Pat(Box::new(Pat::Ident(id.into()))),
+                    right: init,
+                })));
+            }
+        }
+
+        Some(match exprs.len() {
+            0 => None,
+            1 => exprs.pop(),
+            _ => Some(Box::new(Expr::Seq(SeqExpr {
+                span: var.span,
+                exprs,
+            }))),
+        })
+    }
+}
+
+impl Hoister {
+    /// `for (var a in b)` => `for (a in b)`
+    fn hoist_for_head(&mut self, left: &mut VarDeclOrPat) {
+        let name = match left {
+            VarDeclOrPat::VarDecl(var) if var.kind == VarDeclKind::Var => match &mut *var.decls {
+                [VarDeclarator {
+                    name: name @ Pat::Ident(..),
+                    init: None,
+                    ..
+                }] => name.take(),
+                _ => return,
+            },
+            _ => return,
+        };
+
+        if let Pat::Ident(i) = &name {
+            self.declare(&i.id);
+        }
+        *left = VarDeclOrPat::Pat(name);
+    }
+}
+
+impl VisitMut for Hoister {
+    noop_visit_mut_type!();
+
+    /// `var` cannot appear in an expression, except in nested functions.
+    fn visit_mut_expr(&mut self, _: &mut Expr) {}
+
+    /// Nested functions have their own scope.
+    fn visit_mut_function(&mut self, _: &mut Function) {}
+
+    fn visit_mut_class(&mut self, _: &mut Class) {}
+
+    fn visit_mut_for_in_stmt(&mut self, s: &mut ForInStmt) {
+        s.body.visit_mut_with(self);
+
+        self.hoist_for_head(&mut s.left);
+    }
+
+    fn visit_mut_for_of_stmt(&mut self, s: &mut ForOfStmt) {
+        s.body.visit_mut_with(self);
+
+        self.hoist_for_head(&mut s.left);
+    }
+
+    fn visit_mut_for_stmt(&mut self, s: &mut ForStmt) {
+        s.body.visit_mut_with(self);
+
+        if let Some(VarDeclOrExpr::VarDecl(var)) = &mut s.init {
+            if let Some(expr) = self.hoist(var) {
+                s.init = expr.map(VarDeclOrExpr::Expr);
+            }
+        }
+    }
+
+    fn visit_mut_stmt(&mut self, s: &mut Stmt) {
+        s.visit_mut_children_with(self);
+
+        if let Stmt::Decl(Decl::Var(var)) = s {
+            let span = var.span;
+
+            match self.hoist(var) {
+                Some(Some(expr)) => *s = Stmt::Expr(ExprStmt { span, expr }),
+                Some(None) => *s = Stmt::Empty(EmptyStmt { span }),
+                None => {}
+            }
+        }
+    }
+
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        stmts.visit_mut_children_with(self);
+
+        stmts.retain(|s| match s {
+            Stmt::Empty(..) => false,
+            _ => true,
+        });
+    }
+}
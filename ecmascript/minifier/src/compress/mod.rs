@@ -0,0 +1,204 @@
+use self::{
+    bools::compress_bool,
+    conds::compress_if,
+    hoist_vars::hoist_vars,
+    pure::{drop_console, drop_pure_call},
+    sequences::{join_vars, merge_sequences},
+    strings::merge_strings,
+};
+use crate::option::CompressOptions;
+use swc_atoms::js_word;
+use swc_common::SyntaxContext;
+use swc_ecma_ast::*;
+use swc_ecma_utils::StmtLike;
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
+
+mod bools;
+mod conds;
+mod hoist_vars;
+mod pure;
+mod sequences;
+mod strings;
+
+/// Applies size-reducing rewrites which `simplifier` does not do.
+///
+/// Rules can be toggled individually using [CompressOptions].
+///
+/// # When to run
+///
+/// This pass expects the output of the resolver, and should be run before the
+/// `hygiene` pass.
+pub fn compressor(options: CompressOptions) -> impl 'static + Fold {
+    as_folder(Compressor { options })
+}
+
+struct Compressor {
+    options: CompressOptions,
+}
+
+impl Compressor {
+    fn optimize_fn_body(&mut self, stmts: &mut Vec<Stmt>) {
+        if self.options.hoist_vars {
+            hoist_vars(stmts);
+        }
+
+        if self.options.merge_strings {
+            merge_strings(stmts);
+        }
+    }
+
+    fn optimize_stmts<T>(&mut self, stmts: &mut Vec<T>)
+    where
+        T: StmtLike,
+    {
+        if self.options.join_vars {
+            join_vars(stmts);
+        }
+
+        if self.options.sequences {
+            merge_sequences(stmts);
+        }
+    }
+}
+
+impl VisitMut for Compressor {
+    noop_visit_mut_type!();
+
+    fn visit_mut_arrow_expr(&mut self, n: &mut ArrowExpr) {
+        match &mut n.body {
+            BlockStmtOrExpr::BlockStmt(body) => self.optimize_fn_body(&mut body.stmts),
+            BlockStmtOrExpr::Expr(_) => {}
+        }
+
+        n.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_constructor(&mut self, n: &mut Constructor) {
+        if let Some(body) = &mut n.body {
+            self.optimize_fn_body(&mut body.stmts);
+        }
+
+        n.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        e.visit_mut_children_with(self);
+
+        if self.options.drop_console {
+            drop_console(e);
+        }
+
+        if self.options.booleans {
+            compress_bool(e);
+        }
+    }
+
+    fn visit_mut_function(&mut self, n: &mut Function) {
+        if let Some(body) = &mut n.body {
+            self.optimize_fn_body(&mut body.stmts);
+        }
+
+        n.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_getter_prop(&mut self, n: &mut GetterProp) {
+        if let Some(body) = &mut n.body {
+            self.optimize_fn_body(&mut body.stmts);
+        }
+
+        n.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_module(&mut self, n: &mut Module) {
+        for _ in 0..self.options.passes.max(1) {
+            n.visit_mut_children_with(self);
+        }
+    }
+
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        items.visit_mut_children_with(self);
+
+        items.retain(|item| match item {
+            ModuleItem::Stmt(Stmt::Empty(..)) => false,
+            _ => true,
+        });
+
+        self.optimize_stmts(items);
+    }
+
+    fn visit_mut_return_stmt(&mut self, s: &mut ReturnStmt) {
+        s.visit_mut_children_with(self);
+
+        if self.options.drop_return_undefined {
+            match s.arg.as_deref() {
+                Some(arg) if is_undefined(arg) => s.arg = None,
+                _ => {}
+            }
+        }
+    }
+
+    fn visit_mut_script(&mut self, n: &mut Script) {
+        for _ in 0..self.options.passes.max(1) {
+            n.visit_mut_children_with(self);
+        }
+    }
+
+    fn visit_mut_setter_prop(&mut self, n: &mut SetterProp) {
+        if let Some(body) = &mut n.body {
+            self.optimize_fn_body(&mut body.stmts);
+        }
+
+        n.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_stmt(&mut self, s: &mut Stmt) {
+        s.visit_mut_children_with(self);
+
+        match s {
+            // Created by `drop_console`.
+            Stmt::Expr(ExprStmt { span, expr }) if is_undefined(expr) => {
+                *s = Stmt::Empty(EmptyStmt { span: *span });
+            }
+            _ => {}
+        }
+
+        if !self.options.pure_funcs.is_empty() {
+            drop_pure_call(s, &self.options.pure_funcs);
+        }
+
+        if self.options.conditionals {
+            compress_if(s);
+        }
+    }
+
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        stmts.visit_mut_children_with(self);
+
+        stmts.retain(|s| match s {
+            Stmt::Empty(..) => false,
+            _ => true,
+        });
+
+        self.optimize_stmts(stmts);
+    }
+}
+
+/// Returns true for `undefined` (if it's not shadowed) and `void 0`.
+fn is_undefined(e: &Expr) -> bool {
+    match e {
+        Expr::Ident(Ident {
+            sym: js_word!("undefined"),
+            span,
+            ..
+        }) => span.ctxt == SyntaxContext::empty(),
+        Expr::Unary(UnaryExpr {
+            op: op!("void"),
+            arg,
+            ..
+        }) => match &**arg {
+            Expr::Lit(..) => true,
+            _ => false,
+        },
+        _ => false,
+    }
+}
@@ -0,0 +1,105 @@
+use swc_common::{Spanned, SyntaxContext, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_transforms_base::ext::MapWithMut;
+use swc_ecma_utils::{undefined, ExprExt};
+
+/// `console.log(a)` => `void 0`
+pub(super) fn drop_console(e: &mut Expr) {
+    let is_console = match e {
+        Expr::Call(CallExpr {
+            callee: ExprOrSuper::Expr(callee),
+            ..
+        }) => match &**callee {
+            Expr::Member(MemberExpr {
+                obj: ExprOrSuper::Expr(obj),
+                ..
+            }) => match &**obj {
+                // `console` should not be shadowed.
+                Expr::Ident(Ident { sym, span, .. }) => {
+                    &**sym == "console" && span.ctxt == SyntaxContext::empty()
+                }
+                _ => false,
+            },
+            _ => false,
+        },
+        _ => false,
+    };
+
+    if is_console {
+        *e = *undefined(e.span());
+    }
+}
+
+/// Returns `foo.bar` for `foo.bar`, and [None] for computed member
+/// expressions.
+fn name_of(e: &Expr) -> Option<String> {
+    match e {
+        Expr::Ident(i) => Some(i.sym.to_string()),
+        Expr::Member(MemberExpr {
+            obj: ExprOrSuper::Expr(obj),
+            prop,
+            computed: false,
+            ..
+        }) => match &**prop {
+            Expr::Ident(prop) => {
+                let mut name = name_of(obj)?;
+                name.push('.');
+                name.push_str(&prop.sym);
+                Some(name)
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// `foo(a(), b);` => `a();` if `foo` is in `pure_funcs`.
+pub(super) fn drop_pure_call(s: &mut Stmt, pure_funcs: &[String]) {
+    let is_pure = match s {
+        Stmt::Expr(ExprStmt { expr, .. }) => match &**expr {
+            Expr::Call(CallExpr {
+                callee: ExprOrSuper::Expr(callee),
+                args,
+                ..
+            }) => {
+                // Spread arguments invoke the iterator.
+                args.iter().all(|arg| arg.spread.is_none())
+                    && name_of(callee).map_or(false, |name| pure_funcs.contains(&name))
+            }
+            _ => false,
+        },
+        _ => false,
+    };
+    if !is_pure {
+        return;
+    }
+
+    let (span, args) = match s.take() {
+        Stmt::Expr(ExprStmt { span, expr }) => match *expr {
+            Expr::Call(CallExpr { args, .. }) => (span, args),
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    };
+
+    let mut exprs = args
+        .into_iter()
+        .map(|arg| arg.expr)
+        .filter(|e| e.may_have_side_effects())
+        .collect::<Vec<_>>();
+
+    *s = match exprs.len() {
+        0 => Stmt::Empty(EmptyStmt { span }),
+        1 => Stmt::Expr(ExprStmt {
+            span,
+            expr: exprs.pop().unwrap(),
+        }),
+        _ => Stmt::Expr(ExprStmt {
+            span,
+            expr: Box::new(Expr::Seq(SeqExpr {
+                span: DUMMY_SP,
+                exprs,
+            })),
+        }),
+    };
+}
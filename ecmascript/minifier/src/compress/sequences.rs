@@ -0,0 +1,129 @@
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_transforms_base::ext::MapWithMut;
+use swc_ecma_utils::StmtLike;
+
+/// Merges adjacent statements using `op`.
+///
+/// `op` should return `Err` with the original statements if they cannot be
+/// merged.
+fn merge_adjacent<T, F>(stmts: &mut Vec<T>, mut op: F)
+where
+    T: StmtLike,
+    F: FnMut(Stmt, Stmt) -> Result<Stmt, (Stmt, Stmt)>,
+{
+    let mut buf = Vec::with_capacity(stmts.len());
+    let mut prev: Option<Stmt> = None;
+
+    for item in stmts.take() {
+        match item.try_into_stmt() {
+            Ok(stmt) => match prev.take() {
+                Some(p) => match op(p, stmt) {
+                    Ok(merged) => prev = Some(merged),
+                    Err((p, stmt)) => {
+                        buf.push(T::from_stmt(p));
+                        prev = Some(stmt);
+                    }
+                },
+                None => prev = Some(stmt),
+            },
+            Err(item) => {
+                if let Some(p) = prev.take() {
+                    buf.push(T::from_stmt(p));
+                }
+                buf.push(item);
+            }
+        }
+    }
+
+    if let Some(p) = prev {
+        buf.push(T::from_stmt(p));
+    }
+
+    *stmts = buf;
+}
+
+/// `var a = 1; var b = 2;` => `var a = 1, b = 2;`
+pub(super) fn join_vars<T>(stmts: &mut Vec<T>)
+where
+    T: StmtLike,
+{
+    merge_adjacent(stmts, |prev, stmt| match (prev, stmt) {
+        (Stmt::Decl(Decl::Var(mut prev)), Stmt::Decl(Decl::Var(var)))
+            if prev.kind == var.kind && prev.declare == var.declare =>
+        {
+            prev.decls.extend(var.decls);
+            Ok(Stmt::Decl(Decl::Var(prev)))
+        }
+        (prev, stmt) => Err((prev, stmt)),
+    })
+}
+
+fn is_directive_like(s: &ExprStmt) -> bool {
+    match &*s.expr {
+        Expr::Lit(Lit::Str(..)) => true,
+        _ => false,
+    }
+}
+
+/// Creates `a, b`, flattening nested sequence expressions.
+fn seq(a: Box<Expr>, b: Box<Expr>) -> Box<Expr> {
+    fn add(exprs: &mut Vec<Box<Expr>>, e: Box<Expr>) {
+        match *e {
+            Expr::Seq(SeqExpr { exprs: seq, .. }) => exprs.extend(seq),
+            e => exprs.push(Box::new(e)),
+        }
+    }
+
+    let mut exprs = vec![];
+    add(&mut exprs, a);
+    add(&mut exprs, b);
+
+    Box::new(Expr::Seq(SeqExpr {
+        span: DUMMY_SP,
+        exprs,
+    }))
+}
+
+/// Merges expression statements into the next statement.
+///
+///  - `a(); b();` => `a(), b();`
+///  - `a(); return b;` => `return a(), b;`
+///  - `a(); throw b;` => `throw a(), b;`
+///  - `a(); if (b) c();` => `if (a(), b) c();`
+pub(super) fn merge_sequences<T>(stmts: &mut Vec<T>)
+where
+    T: StmtLike,
+{
+    merge_adjacent(stmts, |prev, stmt| match (prev, stmt) {
+        (Stmt::Expr(prev), stmt) if is_directive_like(&prev) => Err((Stmt::Expr(prev), stmt)),
+
+        (Stmt::Expr(prev), Stmt::Expr(s)) if !is_directive_like(&s) => Ok(Stmt::Expr(ExprStmt {
+            span: prev.span,
+            expr: seq(prev.expr, s.expr),
+        })),
+
+        (
+            Stmt::Expr(prev),
+            Stmt::Return(ReturnStmt {
+                span,
+                arg: Some(arg),
+            }),
+        ) => Ok(Stmt::Return(ReturnStmt {
+            span,
+            arg: Some(seq(prev.expr, arg)),
+        })),
+
+        (Stmt::Expr(prev), Stmt::Throw(ThrowStmt { span, arg })) => Ok(Stmt::Throw(ThrowStmt {
+            span,
+            arg: seq(prev.expr, arg),
+        })),
+
+        (Stmt::Expr(prev), Stmt::If(s)) => Ok(Stmt::If(IfStmt {
+            test: seq(prev.expr, s.test),
+            ..s
+        })),
+
+        (prev, stmt) => Err((prev, stmt)),
+    })
+}
@@ -0,0 +1,135 @@
+use fxhash::FxHashMap;
+use swc_atoms::JsWord;
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_utils::{prepend, private_ident};
+use swc_ecma_visit::{
+    noop_visit_mut_type, noop_visit_type, Node, Visit, VisitMut, VisitMutWith, VisitWith,
+};
+
+/// Replaces string literals used multiple times in a function body with a
+/// variable declared at the top of the function.
+///
+/// Nested functions are not visited, as they are processed on their own.
+pub(super) fn merge_strings(stmts: &mut Vec<Stmt>) {
+    let start = stmts.iter().take_while(|s| is_directive(s)).count();
+
+    let mut counter = Counter::default();
+    for s in &stmts[start..] {
+        s.visit_with(&Invalid { span: DUMMY_SP } as _, &mut counter);
+    }
+
+    let mut decls = vec![];
+    let mut vars = FxHashMap::default();
+    for value in counter.order {
+        let (lit, count) = counter.strings.remove(&value).unwrap();
+        let len = value.len();
+        // `var a = "value";` should be shorter than the literals.
+        if count * (len + 1) <= len + 5 {
+            continue;
+        }
+
+        let ident = private_ident!("_str");
+        decls.push(VarDeclarator {
+            span: DUMMY_SP,
+            name: Pat::Ident(ident.clone().into()),
+            init: Some(Box::new(Expr::Lit(Lit::Str(lit)))),
+            definite: false,
+        });
+        vars.insert(value, ident);
+    }
+
+    if vars.is_empty() {
+        return;
+    }
+
+    let mut replacer = Replacer { vars: &vars };
+    for s in &mut stmts[start..] {
+        s.visit_mut_with(&mut replacer);
+    }
+
+    prepend(
+        stmts,
+        Stmt::Decl(Decl::Var(VarDecl {
+            span: DUMMY_SP,
+            kind: VarDeclKind::Var,
+            declare: false,
+            decls,
+        })),
+    );
+}
+
+fn is_directive(s: &Stmt) -> bool {
+    match s {
+        Stmt::Expr(ExprStmt { expr, .. }) => match &**expr {
+            Expr::Lit(Lit::Str(..)) => true,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+#[derive(Default)]
+struct Counter {
+    /// Values in the order of first appearance.
+    order: Vec<JsWord>,
+    strings: FxHashMap<JsWord, (Str, usize)>,
+}
+
+impl Visit for Counter {
+    noop_visit_type!();
+
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr, _: &dyn Node) {}
+
+    fn visit_constructor(&mut self, _: &Constructor, _: &dyn Node) {}
+
+    fn visit_expr(&mut self, e: &Expr, _: &dyn Node) {
+        match e {
+            Expr::Lit(Lit::Str(s)) => {
+                if let Some(v) = self.strings.get_mut(&s.value) {
+                    v.1 += 1;
+                    return;
+                }
+
+                self.order.push(s.value.clone());
+                self.strings.insert(s.value.clone(), (s.clone(), 1));
+            }
+            _ => e.visit_children_with(self),
+        }
+    }
+
+    fn visit_function(&mut self, _: &Function, _: &dyn Node) {}
+
+    fn visit_getter_prop(&mut self, _: &GetterProp, _: &dyn Node) {}
+
+    fn visit_setter_prop(&mut self, _: &SetterProp, _: &dyn Node) {}
+}
+
+struct Replacer<'a> {
+    vars: &'a FxHashMap<JsWord, Ident>,
+}
+
+impl VisitMut for Replacer<'_> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_arrow_expr(&mut self, _: &mut ArrowExpr) {}
+
+    fn visit_mut_constructor(&mut self, _: &mut Constructor) {}
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        match e {
+            Expr::Lit(Lit::Str(s)) => {
+                if let Some(i) = self.vars.get(&s.value) {
+                    *e = Expr::Ident(i.clone());
+                }
+            }
+            _ => e.visit_mut_children_with(self),
+        }
+    }
+
+    fn visit_mut_function(&mut self, _: &mut Function) {}
+
+    fn visit_mut_getter_prop(&mut self, _: &mut GetterProp) {}
+
+    fn visit_mut_setter_prop(&mut self, _: &mut SetterProp) {}
+}
@@ -0,0 +1,16 @@
+//! Minifier for ecmascript.
+//!
+//! This crate contains passes which are only useful for minification, like
+//! the identifier mangler, the property mangler and the compressor.
+
+#![deny(unused)]
+
+pub use crate::{
+    compress::compressor,
+    pass::{mangle_names::mangle_names, mangle_props::mangle_props},
+};
+
+mod compress;
+pub mod option;
+mod pass;
+mod util;
@@ -0,0 +1,108 @@
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use swc_atoms::JsWord;
+
+/// Options for [mangle_names](crate::mangle_names).
+#[derive(Debug, Clone, Default)]
+pub struct MangleOptions {
+    /// Mangle names of top-level bindings.
+    ///
+    /// Exported bindings are renamed too, but their exported names are
+    /// preserved.
+    pub top_level: bool,
+
+    /// If true, names of classes are preserved.
+    pub keep_class_names: bool,
+
+    /// If true, names of functions are preserved.
+    pub keep_fn_names: bool,
+
+    /// Bindings with these names are not renamed, and these names are never
+    /// used as a mangled name.
+    pub reserved: Vec<JsWord>,
+}
+
+/// Options for [mangle_props](crate::mangle_props).
+#[derive(Debug, Clone)]
+pub struct PropMangleOptions {
+    /// Only properties matching this regex are mangled, e.g. `^_`.
+    pub regex: Regex,
+
+    /// Properties with these names are not mangled, and these names are never
+    /// used as a mangled name.
+    pub reserved: Vec<JsWord>,
+}
+
+/// Names generated by [mangle_props](crate::mangle_props).
+///
+/// Pass the cache of the previous build to keep mangled names stable across
+/// builds.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NameCache {
+    /// Original property name to mangled name.
+    #[serde(default)]
+    pub props: BTreeMap<String, String>,
+}
+
+/// Options for [compressor](crate::compressor).
+///
+/// Each field toggles a compression rule, so a miscompilation can be bisected
+/// by disabling rules one by one.
+#[derive(Debug, Clone)]
+pub struct CompressOptions {
+    /// `true` => `!0`, `false` => `!1`
+    pub booleans: bool,
+
+    /// `if (a) b(); else c();` => `a ? b() : c();`
+    pub conditionals: bool,
+
+    /// Removes calls to methods of the global `console` object.
+    pub drop_console: bool,
+
+    /// `return undefined;` => `return;`
+    pub drop_return_undefined: bool,
+
+    /// Moves all `var` declarations of a function to the top of the function.
+    pub hoist_vars: bool,
+
+    /// `var a = 1; var b = 2;` => `var a = 1, b = 2;`
+    pub join_vars: bool,
+
+    /// Replaces string literals used multiple times in a function with a
+    /// variable.
+    ///
+    /// Generated variables are renamed by the `hygiene` pass, so this should
+    /// only be enabled if the pass runs after the compressor.
+    pub merge_strings: bool,
+
+    /// The number of times the compressor runs. Values less than `1` are
+    /// treated as `1`.
+    pub passes: usize,
+
+    /// Functions without side effects, like `Math.floor` or `foo.bar`.
+    ///
+    /// Calls to these functions are removed if their return value is not
+    /// used. Arguments with side effects are preserved.
+    pub pure_funcs: Vec<String>,
+
+    /// `a(); b();` => `a(), b();`
+    pub sequences: bool,
+}
+
+impl Default for CompressOptions {
+    fn default() -> Self {
+        CompressOptions {
+            booleans: true,
+            conditionals: true,
+            drop_console: false,
+            drop_return_undefined: true,
+            hoist_vars: false,
+            join_vars: true,
+            merge_strings: true,
+            passes: 1,
+            pure_funcs: vec![],
+            sequences: true,
+        }
+    }
+}
@@ -0,0 +1,463 @@
+use fxhash::{FxHashMap, FxHashSet};
+use swc_atoms::{js_word, JsWord};
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_transforms_base::scope::ScopeKind;
+use swc_ecma_utils::{find_ids, ident::IdentLike, DestructuringFinder, Id};
+use swc_ecma_visit::{noop_visit_type, Node, Visit, VisitWith};
+
+pub(super) type ScopeId = usize;
+
+#[derive(Debug)]
+pub(super) struct ScopeData {
+    pub parent: Option<ScopeId>,
+    pub kind: ScopeKind,
+    depth: usize,
+
+    /// `true` if `eval` is called or `with` is used in this scope or in one of
+    /// its descendants.
+    pub has_eval: bool,
+
+    /// Bindings owned by this scope, in declaration order.
+    pub bindings: Vec<Id>,
+
+    /// Bindings of ancestor scopes referenced from this scope or from its
+    /// descendants.
+    pub crossing: FxHashSet<Id>,
+
+    /// Names which cannot be used by bindings of this scope, like unresolved
+    /// references or bindings which are not renamed.
+    pub reserved: FxHashSet<JsWord>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum BindingKind {
+    Class,
+    Fn,
+    Other,
+}
+
+#[derive(Debug)]
+pub(super) struct VarInfo {
+    /// The scope which owns the binding.
+    ///
+    /// This is the lowest common ancestor of the declaring scope and all
+    /// scopes referencing the binding.
+    pub scope: ScopeId,
+    pub usage: usize,
+    pub is_class: bool,
+    pub is_fn: bool,
+    /// `true` if the binding should not be renamed.
+    pub fixed: bool,
+}
+
+/// Collects scopes, bindings and references of a program which is already
+/// processed by the resolver.
+pub(super) struct Analyzer {
+    pub scopes: Vec<ScopeData>,
+    pub vars: FxHashMap<Id, VarInfo>,
+    /// Bindings in declaration order.
+    order: Vec<Id>,
+    refs: Vec<(ScopeId, Id)>,
+    cur: ScopeId,
+}
+
+impl Default for Analyzer {
+    fn default() -> Self {
+        Analyzer {
+            scopes: vec![ScopeData {
+                parent: None,
+                kind: ScopeKind::Fn,
+                depth: 0,
+                has_eval: false,
+                bindings: vec![],
+                crossing: Default::default(),
+                reserved: Default::default(),
+            }],
+            vars: Default::default(),
+            order: vec![],
+            refs: vec![],
+            cur: 0,
+        }
+    }
+}
+
+impl Analyzer {
+    fn with_child<F>(&mut self, kind: ScopeKind, op: F)
+    where
+        F: FnOnce(&mut Analyzer),
+    {
+        let id = self.scopes.len();
+        self.scopes.push(ScopeData {
+            parent: Some(self.cur),
+            kind,
+            depth: self.scopes[self.cur].depth + 1,
+            has_eval: false,
+            bindings: vec![],
+            crossing: Default::default(),
+            reserved: Default::default(),
+        });
+
+        let old = self.cur;
+        self.cur = id;
+        op(self);
+        self.cur = old;
+    }
+
+    /// `var`s, parameters and function declarations belong to the nearest
+    /// function scope.
+    fn var_scope(&self) -> ScopeId {
+        let mut cur = self.cur;
+        while self.scopes[cur].kind != ScopeKind::Fn {
+            cur = match self.scopes[cur].parent {
+                Some(v) => v,
+                None => break,
+            };
+        }
+        cur
+    }
+
+    fn declare(&mut self, id: Id, is_var: bool, kind: BindingKind) {
+        let scope = if is_var { self.var_scope() } else { self.cur };
+        let is_class = kind == BindingKind::Class;
+        let is_fn = kind == BindingKind::Fn;
+
+        if let Some(info) = self.vars.get_mut(&id) {
+            info.scope = lca(&self.scopes, info.scope, scope);
+            info.is_class |= is_class;
+            info.is_fn |= is_fn;
+            return;
+        }
+
+        self.order.push(id.clone());
+        self.vars.insert(
+            id,
+            VarInfo {
+                scope,
+                usage: 0,
+                is_class,
+                is_fn,
+                fixed: false,
+            },
+        );
+    }
+
+    fn declare_pat<T>(&mut self, pat: &T, is_var: bool)
+    where
+        T: for<'any> VisitWith<DestructuringFinder<'any, Id>>,
+    {
+        let ids: Vec<Id> = find_ids(pat);
+        for id in ids {
+            self.declare(id, is_var, BindingKind::Other);
+        }
+    }
+
+    fn mark_eval(&mut self) {
+        let mut cur = Some(self.cur);
+        while let Some(id) = cur {
+            self.scopes[id].has_eval = true;
+            cur = self.scopes[id].parent;
+        }
+    }
+
+    /// Computes owner scopes of bindings and conflict information of scopes.
+    ///
+    /// `is_fixed` is called for bindings with their owner scope, and should
+    /// return `true` if the binding should not be renamed.
+    pub fn finish<F>(mut self, mut is_fixed: F) -> (Vec<ScopeData>, FxHashMap<Id, VarInfo>)
+    where
+        F: FnMut(&Id, &VarInfo, &ScopeData) -> bool,
+    {
+        let refs = std::mem::take(&mut self.refs);
+
+        for (scope, id) in &refs {
+            match self.vars.get_mut(id) {
+                Some(info) => {
+                    info.scope = lca(&self.scopes, info.scope, *scope);
+                    info.usage += 1;
+                }
+                None => {
+                    // Unresolved reference.
+                    let mut cur = Some(*scope);
+                    while let Some(s) = cur {
+                        if !self.scopes[s].reserved.insert(id.0.clone()) {
+                            break;
+                        }
+                        cur = self.scopes[s].parent;
+                    }
+                }
+            }
+        }
+
+        for id in &self.order {
+            let info = self.vars.get_mut(id).unwrap();
+            let owner = info.scope;
+            self.scopes[owner].bindings.push(id.clone());
+
+            info.fixed = is_fixed(id, info, &self.scopes[owner]);
+            if info.fixed {
+                let mut cur = Some(owner);
+                while let Some(s) = cur {
+                    self.scopes[s].reserved.insert(id.0.clone());
+                    cur = self.scopes[s].parent;
+                }
+            }
+        }
+
+        for (scope, id) in refs {
+            let owner = match self.vars.get(&id) {
+                Some(info) => info.scope,
+                None => continue,
+            };
+
+            let mut cur = scope;
+            while cur != owner {
+                if !self.scopes[cur].crossing.insert(id.clone()) {
+                    break;
+                }
+                cur = match self.scopes[cur].parent {
+                    Some(v) => v,
+                    None => break,
+                };
+            }
+        }
+
+        (self.scopes, self.vars)
+    }
+}
+
+fn lca(scopes: &[ScopeData], mut a: ScopeId, mut b: ScopeId) -> ScopeId {
+    while scopes[a].depth > scopes[b].depth {
+        a = scopes[a].parent.unwrap();
+    }
+    while scopes[b].depth > scopes[a].depth {
+        b = scopes[b].parent.unwrap();
+    }
+    while a != b {
+        a = scopes[a].parent.unwrap();
+        b = scopes[b].parent.unwrap();
+    }
+    a
+}
+
+impl Visit for Analyzer {
+    noop_visit_type!();
+
+    fn visit_arrow_expr(&mut self, n: &ArrowExpr, _: &dyn Node) {
+        self.with_child(ScopeKind::Fn, |a| {
+            a.declare_pat(&n.params, true);
+            n.params.visit_with(n, a);
+
+            match &n.body {
+                BlockStmtOrExpr::BlockStmt(body) => body.stmts.visit_with(n, a),
+                BlockStmtOrExpr::Expr(body) => body.visit_with(n, a),
+            }
+        })
+    }
+
+    fn visit_block_stmt(&mut self, n: &BlockStmt, _: &dyn Node) {
+        self.with_child(ScopeKind::Block, |a| n.visit_children_with(a))
+    }
+
+    fn visit_break_stmt(&mut self, _: &BreakStmt, _: &dyn Node) {}
+
+    fn visit_call_expr(&mut self, n: &CallExpr, _: &dyn Node) {
+        match &n.callee {
+            ExprOrSuper::Expr(callee) => match &**callee {
+                Expr::Ident(Ident {
+                    sym: js_word!("eval"),
+                    ..
+                }) => self.mark_eval(),
+                _ => {}
+            },
+            _ => {}
+        }
+
+        n.visit_children_with(self)
+    }
+
+    fn visit_catch_clause(&mut self, n: &CatchClause, _: &dyn Node) {
+        self.with_child(ScopeKind::Block, |a| {
+            a.declare_pat(&n.param, false);
+            n.param.visit_with(n, a);
+            n.body.stmts.visit_with(n, a);
+        })
+    }
+
+    fn visit_class_decl(&mut self, n: &ClassDecl, _: &dyn Node) {
+        self.declare(n.ident.to_id(), false, BindingKind::Class);
+        n.ident.visit_with(n, self);
+        n.class.visit_with(n, self);
+    }
+
+    fn visit_class_expr(&mut self, n: &ClassExpr, _: &dyn Node) {
+        if let Some(ident) = &n.ident {
+            self.declare(ident.to_id(), false, BindingKind::Class);
+            ident.visit_with(n, self);
+        }
+        n.class.visit_with(n, self);
+    }
+
+    fn visit_class_prop(&mut self, n: &ClassProp, _: &dyn Node) {
+        n.decorators.visit_with(n, self);
+        if n.computed {
+            n.key.visit_with(n, self);
+        }
+        n.value.visit_with(n, self);
+    }
+
+    fn visit_constructor(&mut self, n: &Constructor, _: &dyn Node) {
+        self.with_child(ScopeKind::Fn, |a| {
+            a.declare_pat(&n.params, true);
+            n.params.visit_with(n, a);
+            if let Some(body) = &n.body {
+                body.stmts.visit_with(n, a);
+            }
+        })
+    }
+
+    fn visit_continue_stmt(&mut self, _: &ContinueStmt, _: &dyn Node) {}
+
+    fn visit_export_named_specifier(&mut self, n: &ExportNamedSpecifier, _: &dyn Node) {
+        n.orig.visit_with(n, self);
+    }
+
+    fn visit_fn_decl(&mut self, n: &FnDecl, _: &dyn Node) {
+        self.declare(n.ident.to_id(), true, BindingKind::Fn);
+        n.ident.visit_with(n, self);
+        n.function.visit_with(n, self);
+    }
+
+    fn visit_fn_expr(&mut self, n: &FnExpr, _: &dyn Node) {
+        if let Some(ident) = &n.ident {
+            self.declare(ident.to_id(), false, BindingKind::Fn);
+            ident.visit_with(n, self);
+        }
+        n.function.visit_with(n, self);
+    }
+
+    fn visit_for_in_stmt(&mut self, n: &ForInStmt, _: &dyn Node) {
+        self.with_child(ScopeKind::Block, |a| n.visit_children_with(a))
+    }
+
+    fn visit_for_of_stmt(&mut self, n: &ForOfStmt, _: &dyn Node) {
+        self.with_child(ScopeKind::Block, |a| n.visit_children_with(a))
+    }
+
+    fn visit_for_stmt(&mut self, n: &ForStmt, _: &dyn Node) {
+        self.with_child(ScopeKind::Block, |a| n.visit_children_with(a))
+    }
+
+    fn visit_function(&mut self, n: &Function, _: &dyn Node) {
+        n.decorators.visit_with(n, self);
+
+        self.with_child(ScopeKind::Fn, |a| {
+            a.declare_pat(&n.params, true);
+            n.params.visit_with(n, a);
+            if let Some(body) = &n.body {
+                body.stmts.visit_with(n, a);
+            }
+        })
+    }
+
+    fn visit_getter_prop(&mut self, n: &GetterProp, _: &dyn Node) {
+        n.key.visit_with(n, self);
+
+        self.with_child(ScopeKind::Fn, |a| {
+            if let Some(body) = &n.body {
+                body.stmts.visit_with(n, a);
+            }
+        })
+    }
+
+    fn visit_ident(&mut self, n: &Ident, _: &dyn Node) {
+        self.refs.push((self.cur, n.to_id()));
+    }
+
+    fn visit_import_specifier(&mut self, n: &ImportSpecifier, _: &dyn Node) {
+        let local = match n {
+            ImportSpecifier::Named(s) => &s.local,
+            ImportSpecifier::Default(s) => &s.local,
+            ImportSpecifier::Namespace(s) => &s.local,
+        };
+        self.declare(local.to_id(), false, BindingKind::Other);
+        local.visit_with(n, self);
+    }
+
+    fn visit_jsx_attr_name(&mut self, _: &JSXAttrName, _: &dyn Node) {}
+
+    fn visit_jsx_member_expr(&mut self, n: &JSXMemberExpr, _: &dyn Node) {
+        n.obj.visit_with(n, self);
+    }
+
+    fn visit_labeled_stmt(&mut self, n: &LabeledStmt, _: &dyn Node) {
+        n.body.visit_with(n, self);
+    }
+
+    fn visit_member_expr(&mut self, n: &MemberExpr, _: &dyn Node) {
+        n.obj.visit_with(n, self);
+        if n.computed {
+            n.prop.visit_with(n, self);
+        }
+    }
+
+    fn visit_meta_prop_expr(&mut self, _: &MetaPropExpr, _: &dyn Node) {}
+
+    fn visit_named_export(&mut self, n: &NamedExport, _: &dyn Node) {
+        // Re-exports do not reference local bindings.
+        if n.src.is_some() {
+            return;
+        }
+        n.specifiers.visit_with(n, self);
+    }
+
+    fn visit_private_name(&mut self, _: &PrivateName, _: &dyn Node) {}
+
+    fn visit_prop_name(&mut self, n: &PropName, _: &dyn Node) {
+        match n {
+            PropName::Computed(c) => c.visit_with(n, self),
+            _ => {}
+        }
+    }
+
+    fn visit_setter_prop(&mut self, n: &SetterProp, _: &dyn Node) {
+        n.key.visit_with(n, self);
+
+        self.with_child(ScopeKind::Fn, |a| {
+            a.declare_pat(&n.param, true);
+            n.param.visit_with(n, a);
+            if let Some(body) = &n.body {
+                body.stmts.visit_with(n, a);
+            }
+        })
+    }
+
+    fn visit_switch_stmt(&mut self, n: &SwitchStmt, _: &dyn Node) {
+        n.discriminant.visit_with(n, self);
+        self.with_child(ScopeKind::Block, |a| n.cases.visit_with(n, a))
+    }
+
+    fn visit_var_decl(&mut self, n: &VarDecl, _: &dyn Node) {
+        let is_var = n.kind == VarDeclKind::Var;
+        for decl in &n.decls {
+            self.declare_pat(&decl.name, is_var);
+        }
+
+        n.visit_children_with(self)
+    }
+
+    fn visit_with_stmt(&mut self, n: &WithStmt, _: &dyn Node) {
+        self.mark_eval();
+        n.visit_children_with(self)
+    }
+}
+
+/// Used to visit a program as a whole.
+pub(super) fn analyze<N>(n: &N) -> Analyzer
+where
+    N: VisitWith<Analyzer>,
+{
+    let mut v = Analyzer::default();
+    n.visit_with(&Invalid { span: DUMMY_SP } as _, &mut v);
+    v
+}
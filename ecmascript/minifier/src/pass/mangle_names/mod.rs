@@ -0,0 +1,147 @@
+use self::analyzer::{analyze, Analyzer, ScopeData, VarInfo};
+use crate::{option::MangleOptions, util::base54};
+use fxhash::{FxHashMap, FxHashSet};
+use swc_atoms::JsWord;
+use swc_common::{chain, Mark, SyntaxContext};
+use swc_ecma_ast::*;
+use swc_ecma_transforms_base::{hygiene::rename, resolver::resolver_with_mark};
+use swc_ecma_utils::Id;
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith, VisitWith};
+
+mod analyzer;
+
+/// Renames bindings to the shortest names available.
+///
+/// Bindings which are used more frequently get shorter names, and bindings of
+/// sibling scopes reuse names.
+///
+/// # When to run
+///
+/// The input should be free of span hygiene, e.g. the output of the `hygiene`
+/// pass. Bindings visible to `eval` or `with` are not renamed.
+pub fn mangle_names(options: MangleOptions) -> impl 'static + Fold {
+    chain!(
+        resolver_with_mark(Mark::fresh(Mark::root())),
+        as_folder(Mangler { options })
+    )
+}
+
+struct Mangler {
+    options: MangleOptions,
+}
+
+impl Mangler {
+    fn is_fixed(&self, id: &Id, info: &VarInfo, scope: &ScopeData) -> bool {
+        if scope.has_eval {
+            return true;
+        }
+
+        // Top-level bindings may be used by other scripts.
+        if scope.parent.is_none() && !self.options.top_level {
+            return true;
+        }
+
+        if self.options.keep_class_names && info.is_class {
+            return true;
+        }
+
+        if self.options.keep_fn_names && info.is_fn {
+            return true;
+        }
+
+        self.options.reserved.contains(&id.0)
+    }
+
+    fn is_available(&self, name: &JsWord, used: &FxHashSet<JsWord>) -> bool {
+        !used.contains(name) && !is_reserved_word(name) && !self.options.reserved.contains(name)
+    }
+
+    /// Scopes are stored in pre-order, so names of ancestor scopes are always
+    /// assigned first.
+    fn rename_map(
+        &self,
+        scopes: Vec<ScopeData>,
+        vars: &FxHashMap<Id, VarInfo>,
+    ) -> FxHashMap<Id, JsWord> {
+        let mut map = FxHashMap::default();
+
+        for scope in scopes {
+            let mut used = scope.reserved;
+            for id in &scope.crossing {
+                used.insert(map.get(id).cloned().unwrap_or_else(|| id.0.clone()));
+            }
+
+            let mut bindings = scope
+                .bindings
+                .into_iter()
+                .filter(|id| !vars[id].fixed)
+                .collect::<Vec<_>>();
+            // `sort_by` is stable, so ties are broken by declaration order.
+            bindings.sort_by(|a, b| vars[b].usage.cmp(&vars[a].usage));
+
+            let mut next = 0;
+            for id in bindings {
+                let name = loop {
+                    let name = base54(next);
+                    next += 1;
+                    if self.is_available(&name, &used) {
+                        break name;
+                    }
+                };
+
+                used.insert(name.clone());
+                if name != id.0 {
+                    map.insert(id, name);
+                }
+            }
+        }
+
+        map
+    }
+
+    fn compute_map<N>(&self, n: &N) -> FxHashMap<Id, JsWord>
+    where
+        N: VisitWith<Analyzer>,
+    {
+        let (scopes, vars) = analyze(n).finish(|id, info, scope| self.is_fixed(id, info, scope));
+
+        self.rename_map(scopes, &vars)
+    }
+}
+
+impl VisitMut for Mangler {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module(&mut self, n: &mut Module) {
+        let map = self.compute_map(&*n);
+
+        n.visit_mut_with(&mut rename(&map));
+        n.visit_mut_with(&mut MarkClearer);
+    }
+
+    fn visit_mut_script(&mut self, n: &mut Script) {
+        let map = self.compute_map(&*n);
+
+        n.visit_mut_with(&mut rename(&map));
+        n.visit_mut_with(&mut MarkClearer);
+    }
+}
+
+struct MarkClearer;
+
+impl VisitMut for MarkClearer {
+    noop_visit_mut_type!();
+
+    fn visit_mut_ident(&mut self, ident: &mut Ident) {
+        ident.span.ctxt = SyntaxContext::empty();
+    }
+}
+
+fn is_reserved_word(name: &JsWord) -> bool {
+    name.is_reserved_for_es3()
+        || match &**name {
+            "let" | "yield" | "await" | "static" | "arguments" | "eval" | "undefined" | "NaN"
+            | "Infinity" => true,
+            _ => false,
+        }
+}
@@ -0,0 +1,212 @@
+use crate::{
+    option::{NameCache, PropMangleOptions},
+    util::base54,
+};
+use fxhash::{FxHashMap, FxHashSet};
+use swc_atoms::JsWord;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{noop_visit_mut_type, VisitMut, VisitMutWith};
+
+/// Renames properties and class members matching
+/// [PropMangleOptions::regex] to the shortest names available.
+///
+/// All modules are renamed using a single mapping, so property accesses
+/// across modules of a bundle stay consistent. Names stored in `cache` are
+/// reused unless they conflict with a property which is not mangled, and
+/// newly generated names are added to it.
+///
+/// Properties accessed with a dynamic key (e.g. `obj[key]` or `key in obj`)
+/// cannot be tracked, so those properties should not match the regex.
+pub fn mangle_props(
+    modules: &mut [&mut Module],
+    options: &PropMangleOptions,
+    cache: &mut NameCache,
+) {
+    let mut order = vec![];
+    let mut usage = FxHashMap::<JsWord, usize>::default();
+    let mut used = FxHashSet::default();
+
+    for m in modules.iter_mut() {
+        m.visit_mut_with(&mut PropVisitor {
+            op: |name: &JsWord| {
+                if is_target(options, name) {
+                    let count = usage.entry(name.clone()).or_insert_with(|| {
+                        order.push(name.clone());
+                        0
+                    });
+                    *count += 1;
+                } else {
+                    used.insert(name.clone());
+                }
+                None
+            },
+        });
+    }
+
+    used.extend(options.reserved.iter().cloned());
+    let cached = cache
+        .props
+        .values()
+        .map(|v| JsWord::from(&**v))
+        .collect::<FxHashSet<_>>();
+
+    // `sort_by` is stable, so ties are broken by the order of appearance.
+    order.sort_by(|a, b| usage[b].cmp(&usage[a]));
+
+    let mut next = 0;
+    let mut map = FxHashMap::default();
+    for name in order {
+        let mangled = match cache.props.get(&*name).map(|v| JsWord::from(&**v)) {
+            Some(v) if !used.contains(&v) => v,
+            // Cached names are replaced if they are used by other properties.
+            _ => {
+                let mangled = loop {
+                    let mangled = base54(next);
+                    next += 1;
+                    if !used.contains(&mangled)
+                        && !cached.contains(&mangled)
+                        && !mangled.is_reserved_for_es3()
+                    {
+                        break mangled;
+                    }
+                };
+                cache.props.insert(name.to_string(), mangled.to_string());
+                mangled
+            }
+        };
+
+        map.insert(name, mangled);
+    }
+
+    for m in modules.iter_mut() {
+        m.visit_mut_with(&mut PropVisitor {
+            op: |name: &JsWord| map.get(name).cloned(),
+        });
+    }
+}
+
+fn is_target(options: &PropMangleOptions, name: &JsWord) -> bool {
+    options.regex.is_match(name) && !options.reserved.contains(name)
+}
+
+/// Calls `op` with each property name, and replaces the name if `op` returns
+/// a new name.
+struct PropVisitor<F>
+where
+    F: FnMut(&JsWord) -> Option<JsWord>,
+{
+    op: F,
+}
+
+impl<F> PropVisitor<F>
+where
+    F: FnMut(&JsWord) -> Option<JsWord>,
+{
+    /// Handles a non-computed key or a string literal.
+    fn visit_mut_key_expr(&mut self, e: &mut Expr) {
+        match e {
+            Expr::Ident(i) => {
+                if let Some(name) = (self.op)(&i.sym) {
+                    i.sym = name;
+                }
+            }
+            Expr::Lit(Lit::Str(s)) => {
+                if let Some(name) = (self.op)(&s.value) {
+                    s.value = name;
+                    s.has_escape = false;
+                    s.kind = Default::default();
+                }
+            }
+            _ => e.visit_mut_with(self),
+        }
+    }
+}
+
+impl<F> VisitMut for PropVisitor<F>
+where
+    F: FnMut(&JsWord) -> Option<JsWord>,
+{
+    noop_visit_mut_type!();
+
+    fn visit_mut_class_prop(&mut self, n: &mut ClassProp) {
+        n.decorators.visit_mut_with(self);
+        if n.computed {
+            match &*n.key {
+                Expr::Lit(Lit::Str(..)) => self.visit_mut_key_expr(&mut n.key),
+                _ => n.key.visit_mut_with(self),
+            }
+        } else {
+            self.visit_mut_key_expr(&mut n.key);
+        }
+        n.value.visit_mut_with(self);
+    }
+
+    fn visit_mut_member_expr(&mut self, n: &mut MemberExpr) {
+        n.obj.visit_mut_with(self);
+        if n.computed {
+            match &*n.prop {
+                Expr::Lit(Lit::Str(..)) => self.visit_mut_key_expr(&mut n.prop),
+                _ => n.prop.visit_mut_with(self),
+            }
+        } else {
+            self.visit_mut_key_expr(&mut n.prop);
+        }
+    }
+
+    /// `{ _a }` => `{ b: _a }`
+    fn visit_mut_object_pat_prop(&mut self, n: &mut ObjectPatProp) {
+        n.visit_mut_children_with(self);
+
+        if let ObjectPatProp::Assign(AssignPatProp { span, key, value }) = n {
+            if let Some(name) = (self.op)(&key.sym) {
+                let binding = Box::new(Pat::Ident(key.clone().into()));
+                *n = ObjectPatProp::KeyValue(KeyValuePatProp {
+                    key: PropName::Ident(Ident::new(name, key.span)),
+                    value: match value.take() {
+                        Some(right) => Box::new(Pat::Assign(AssignPat {
+                            span: *span,
+                            left: binding,
+                            right,
+                            type_ann: None,
+                        })),
+                        None => binding,
+                    },
+                });
+            }
+        }
+    }
+
+    /// `{ _a }` => `{ b: _a }`
+    fn visit_mut_prop(&mut self, n: &mut Prop) {
+        n.visit_mut_children_with(self);
+
+        if let Prop::Shorthand(i) = n {
+            if let Some(name) = (self.op)(&i.sym) {
+                *n = Prop::KeyValue(KeyValueProp {
+                    key: PropName::Ident(Ident::new(name, i.span)),
+                    value: Box::new(Expr::Ident(i.clone())),
+                });
+            }
+        }
+    }
+
+    fn visit_mut_prop_name(&mut self, n: &mut PropName) {
+        match n {
+            PropName::Ident(i) => {
+                if let Some(name) = (self.op)(&i.sym) {
+                    i.sym = name;
+                }
+            }
+            PropName::Str(s) => {
+                if let Some(name) = (self.op)(&s.value) {
+                    *n = PropName::Ident(Ident::new(name, s.span));
+                }
+            }
+            PropName::Computed(c) => match &*c.expr {
+                Expr::Lit(Lit::Str(..)) => self.visit_mut_key_expr(&mut c.expr),
+                _ => c.visit_mut_with(self),
+            },
+            PropName::Num(..) | PropName::BigInt(..) => {}
+        }
+    }
+}
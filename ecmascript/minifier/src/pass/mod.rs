@@ -0,0 +1,2 @@
+pub mod mangle_names;
+pub mod mangle_props;
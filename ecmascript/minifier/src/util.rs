@@ -0,0 +1,38 @@
+use swc_atoms::JsWord;
+
+const CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ$_0123456789";
+
+/// Creates `n`th shortest identifier. Identifiers cannot start with a digit, so
+/// only the first 54 characters are used for the first character.
+pub(crate) fn base54(mut n: usize) -> JsWord {
+    let mut s = String::new();
+    let mut base = 54;
+
+    n += 1;
+    loop {
+        n -= 1;
+        s.push(CHARS[n % base] as char);
+        n /= base;
+        base = 64;
+
+        if n == 0 {
+            break;
+        }
+    }
+
+    s.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::base54;
+
+    #[test]
+    fn base54_names() {
+        assert_eq!(&*base54(0), "a");
+        assert_eq!(&*base54(53), "_");
+        assert_eq!(&*base54(54), "aa");
+        assert_eq!(&*base54(55), "ba");
+        assert_eq!(&*base54(54 + 64 * 54), "aaa");
+    }
+}
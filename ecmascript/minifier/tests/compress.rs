@@ -0,0 +1,211 @@
+use swc_common::chain;
+use swc_ecma_minifier::{compressor, option::CompressOptions};
+use swc_ecma_parser::Syntax;
+use swc_ecma_transforms_base::resolver::resolver;
+use swc_ecma_transforms_testing::test;
+
+fn syntax() -> Syntax {
+    Default::default()
+}
+
+test!(
+    syntax(),
+    |_| compressor(Default::default()),
+    booleans,
+    "foo(true, false);",
+    "foo(!0, !1);"
+);
+
+test!(
+    syntax(),
+    |_| compressor(Default::default()),
+    conditionals_ternary,
+    "if (a) b(); else c();",
+    "a ? b() : c();"
+);
+
+test!(
+    syntax(),
+    |_| compressor(Default::default()),
+    conditionals_and,
+    "if (a) { b(); }",
+    "a && b();"
+);
+
+test!(
+    syntax(),
+    |_| compressor(Default::default()),
+    conditionals_negated_test,
+    "if (!a) b();",
+    "a || b();"
+);
+
+test!(
+    syntax(),
+    |_| compressor(Default::default()),
+    conditionals_chain,
+    "if (a) b(); else if (c) d(); else e();",
+    "a ? b() : c ? d() : e();"
+);
+
+test!(
+    syntax(),
+    |_| compressor(Default::default()),
+    conditionals_non_expr_body,
+    "function foo() { if (a) { b(); return; } }",
+    "function foo() { if (a) { b(); return; } }"
+);
+
+test!(
+    syntax(),
+    |_| compressor(Default::default()),
+    conditionals_str_test,
+    "function foo() { if ('use strict'); bar(); }",
+    "function foo() { if ('use strict'); bar(); }"
+);
+
+test!(
+    syntax(),
+    |_| compressor(Default::default()),
+    drop_return_undefined,
+    "function foo() { return undefined; }
+    function bar() { return void 0; }",
+    "function foo() { return; }
+    function bar() { return; }"
+);
+
+test!(
+    syntax(),
+    |_| chain!(resolver(), compressor(Default::default())),
+    drop_return_undefined_shadowed,
+    "function foo(undefined) { return undefined; }",
+    "function foo(undefined) { return undefined; }"
+);
+
+test!(
+    syntax(),
+    |_| compressor(Default::default()),
+    join_vars,
+    "var a = 1; var b = 2; let c = 3;",
+    "var a = 1, b = 2; let c = 3;"
+);
+
+test!(
+    syntax(),
+    |_| compressor(Default::default()),
+    sequences,
+    "function foo() { a(); b(); return c; }",
+    "function foo() { return a(), b(), c; }"
+);
+
+test!(
+    syntax(),
+    |_| compressor(Default::default()),
+    sequences_if,
+    "function foo() { a(); if (b) return c; }",
+    "function foo() { if (a(), b) return c; }"
+);
+
+test!(
+    syntax(),
+    |_| compressor(Default::default()),
+    sequences_directive,
+    "'use strict'; a(); b();",
+    "'use strict'; a(), b();"
+);
+
+test!(
+    syntax(),
+    |_| compressor(CompressOptions {
+        hoist_vars: true,
+        ..Default::default()
+    }),
+    hoist_vars,
+    "function foo() { a(); var b = 1; for (var c in d) {} }",
+    "function foo() { var b, c; a(), b = 1; for (c in d) {} }"
+);
+
+test!(
+    syntax(),
+    |_| compressor(Default::default()),
+    hoist_vars_disabled,
+    "function foo() { a(); var b = 1; }",
+    "function foo() { a(); var b = 1; }"
+);
+
+test!(
+    syntax(),
+    |_| compressor(Default::default()),
+    merge_strings,
+    "function foo() { a('value'); b('value'); c('value'); }",
+    "function foo() { var _str = 'value'; a(_str), b(_str), c(_str); }"
+);
+
+test!(
+    syntax(),
+    |_| compressor(Default::default()),
+    merge_strings_short,
+    "function foo() { a('x'); b('x'); }",
+    "function foo() { a('x'), b('x'); }"
+);
+
+test!(
+    syntax(),
+    |_| compressor(CompressOptions {
+        merge_strings: false,
+        ..Default::default()
+    }),
+    merge_strings_disabled,
+    "function foo() { a('value'); b('value'); c('value'); }",
+    "function foo() { a('value'), b('value'), c('value'); }"
+);
+
+test!(
+    syntax(),
+    |_| chain!(
+        resolver(),
+        compressor(CompressOptions {
+            drop_console: true,
+            ..Default::default()
+        })
+    ),
+    drop_console,
+    "console.log(a); foo(console.warn(b)); if (c) console.error(d);",
+    "foo(void 0), c;"
+);
+
+test!(
+    syntax(),
+    |_| chain!(
+        resolver(),
+        compressor(CompressOptions {
+            drop_console: true,
+            ..Default::default()
+        })
+    ),
+    drop_console_shadowed,
+    "function foo(console) { console.log(a); }",
+    "function foo(console) { console.log(a); }"
+);
+
+test!(
+    syntax(),
+    |_| compressor(CompressOptions {
+        pure_funcs: vec!["Math.floor".into(), "foo".into()],
+        ..Default::default()
+    }),
+    pure_funcs,
+    "Math.floor(a); foo(b(), 1); Math.ceil(c);",
+    "b(), Math.ceil(c);"
+);
+
+test!(
+    syntax(),
+    |_| compressor(CompressOptions {
+        passes: 2,
+        ..Default::default()
+    }),
+    passes,
+    "if (a) { b(); } else { c(); }",
+    "a ? b() : c();"
+);
@@ -0,0 +1,125 @@
+use swc_ecma_minifier::{mangle_names, option::MangleOptions};
+use swc_ecma_parser::Syntax;
+use swc_ecma_transforms_testing::test;
+
+fn syntax() -> Syntax {
+    Default::default()
+}
+
+test!(
+    syntax(),
+    |_| mangle_names(Default::default()),
+    params_and_vars,
+    "function foo(first, second) { var result = first + second; return result; }",
+    "function foo(a, b) { var c = a + b; return c; }"
+);
+
+test!(
+    syntax(),
+    |_| mangle_names(Default::default()),
+    frequency,
+    "function foo(rare, common) { common(); common(); return rare; }",
+    "function foo(b, a) { a(); a(); return b; }"
+);
+
+test!(
+    syntax(),
+    |_| mangle_names(Default::default()),
+    sibling_scopes,
+    "function foo() { let x = 1; }
+    function bar() { let y = 2; }",
+    "function foo() { let a = 1; }
+    function bar() { let a = 2; }"
+);
+
+test!(
+    syntax(),
+    |_| mangle_names(Default::default()),
+    unresolved_reference,
+    "function foo(value) { return a(value); }",
+    "function foo(b) { return a(b); }"
+);
+
+test!(
+    syntax(),
+    |_| mangle_names(Default::default()),
+    closure,
+    "function outer(alpha) { return (beta) => alpha + beta; }",
+    "function outer(a) { return (b) => a + b; }"
+);
+
+test!(
+    syntax(),
+    |_| mangle_names(Default::default()),
+    shorthand_prop,
+    "function foo(value) { return { value }; }",
+    "function foo(a) { return { value: a }; }"
+);
+
+test!(
+    syntax(),
+    |_| mangle_names(Default::default()),
+    direct_eval,
+    "function foo(value) { eval('value'); }",
+    "function foo(value) { eval('value'); }"
+);
+
+test!(
+    syntax(),
+    |_| mangle_names(Default::default()),
+    top_level_is_preserved,
+    "var value = 1; export { value };",
+    "var value = 1; export { value };"
+);
+
+test!(
+    syntax(),
+    |_| mangle_names(MangleOptions {
+        top_level: true,
+        ..Default::default()
+    }),
+    top_level_export,
+    "var value = 1; export { value };",
+    "var a = 1; export { a as value };"
+);
+
+test!(
+    syntax(),
+    |_| mangle_names(Default::default()),
+    class_name,
+    "function foo() { class Foo {} return Foo; }",
+    "function foo() { class a {} return a; }"
+);
+
+test!(
+    syntax(),
+    |_| mangle_names(MangleOptions {
+        keep_class_names: true,
+        ..Default::default()
+    }),
+    keep_class_names,
+    "function foo() { class Foo {} return Foo; }",
+    "function foo() { class Foo {} return Foo; }"
+);
+
+test!(
+    syntax(),
+    |_| mangle_names(MangleOptions {
+        reserved: vec!["a".into()],
+        ..Default::default()
+    }),
+    reserved,
+    "function foo(first) { return first; }",
+    "function foo(b) { return b; }"
+);
+
+test!(
+    syntax(),
+    |_| mangle_names(MangleOptions {
+        keep_fn_names: true,
+        ..Default::default()
+    }),
+    keep_fn_names,
+    "function foo() { function bar(value) { return value; } return bar; }",
+    "function foo() { function bar(a) { return a; } return bar; }"
+);
@@ -0,0 +1,185 @@
+use regex::Regex;
+use swc_atoms::JsWord;
+use swc_common::{sync::Lrc, FileName, SourceMap};
+use swc_ecma_ast::*;
+use swc_ecma_minifier::{
+    mangle_props,
+    option::{NameCache, PropMangleOptions},
+};
+use swc_ecma_parser::{Parser, StringInput, Syntax};
+use swc_ecma_transforms_testing::test;
+use swc_ecma_visit::Fold;
+
+fn syntax() -> Syntax {
+    Default::default()
+}
+
+fn options() -> PropMangleOptions {
+    PropMangleOptions {
+        regex: Regex::new("^_").unwrap(),
+        reserved: vec![],
+    }
+}
+
+struct PropMangler {
+    options: PropMangleOptions,
+    cache: NameCache,
+}
+
+impl Fold for PropMangler {
+    fn fold_module(&mut self, mut m: Module) -> Module {
+        mangle_props(&mut [&mut m], &self.options, &mut self.cache);
+        m
+    }
+}
+
+fn tr(options: PropMangleOptions) -> PropMangler {
+    PropMangler {
+        options,
+        cache: Default::default(),
+    }
+}
+
+test!(
+    syntax(),
+    |_| tr(options()),
+    member,
+    "foo._bar = 1; foo._bar(); foo.baz;",
+    "foo.a = 1; foo.a(); foo.baz;"
+);
+
+test!(
+    syntax(),
+    |_| tr(options()),
+    frequency,
+    "foo._rare; foo._common; foo._common;",
+    "foo.b; foo.a; foo.a;"
+);
+
+test!(
+    syntax(),
+    |_| tr(options()),
+    object_and_class,
+    "var obj = { _foo: 1, '_bar': 2, ['_baz']: 3, _qux() {} };
+    class Foo { _foo = 1; _bar() {} }",
+    "var obj = { a: 1, b: 2, [\"c\"]: 3, d() {} };
+    class Foo { a = 1; b() {} }"
+);
+
+test!(
+    syntax(),
+    |_| tr(options()),
+    shorthand,
+    "var { _foo, _bar = 1 } = obj; var obj2 = { _foo };",
+    "var { a: _foo, b: _bar = 1 } = obj; var obj2 = { a: _foo };"
+);
+
+test!(
+    syntax(),
+    |_| tr(options()),
+    avoid_existing_names,
+    "foo._bar; foo.a;",
+    "foo.b; foo.a;"
+);
+
+test!(
+    syntax(),
+    |_| tr(PropMangleOptions {
+        reserved: vec!["_bar".into(), "a".into()],
+        ..options()
+    }),
+    reserved,
+    "foo._bar; foo._baz;",
+    "foo._bar; foo.b;"
+);
+
+test!(
+    syntax(),
+    |_| tr(options()),
+    private_name,
+    "class Foo { #_foo = 1; bar() { return this.#_foo; } }",
+    "class Foo { #_foo = 1; bar() { return this.#_foo; } }"
+);
+
+test!(
+    syntax(),
+    |_| PropMangler {
+        options: options(),
+        cache: serde_json::from_str(r#"{ "props": { "_foo": "z" } }"#).unwrap(),
+    },
+    name_cache,
+    "foo._foo; foo._bar;",
+    "foo.z; foo.a;"
+);
+
+test!(
+    syntax(),
+    |_| PropMangler {
+        options: PropMangleOptions {
+            reserved: vec!["y".into()],
+            ..options()
+        },
+        cache: serde_json::from_str(r#"{ "props": { "_foo": "z", "_bar": "y", "_baz": "b" } }"#)
+            .unwrap(),
+    },
+    name_cache_conflict,
+    "foo._foo; foo.z; foo._bar; foo._baz;",
+    "foo.a; foo.z; foo.c; foo.b;"
+);
+
+fn parse(cm: &Lrc<SourceMap>, src: &str) -> Module {
+    let fm = cm.new_source_file(FileName::Anon, src.into());
+    Parser::new(syntax(), StringInput::from(&*fm), None)
+        .parse_module()
+        .unwrap()
+}
+
+fn prop_of(m: &Module) -> JsWord {
+    match &m.body[0] {
+        ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) => match &**expr {
+            Expr::Member(MemberExpr { prop, .. }) => match &**prop {
+                Expr::Ident(i) => i.sym.clone(),
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn consistent_across_modules() {
+    let cm = Lrc::new(SourceMap::default());
+    let mut a = parse(&cm, "foo._bar;");
+    let mut b = parse(&cm, "foo._baz; foo._bar;");
+
+    let mut cache = NameCache::default();
+    mangle_props(&mut [&mut a, &mut b], &options(), &mut cache);
+
+    assert_eq!(&*prop_of(&a), &*cache.props["_bar"]);
+    assert_eq!(&*prop_of(&b), &*cache.props["_baz"]);
+    assert_ne!(prop_of(&a), prop_of(&b));
+
+    // Second build with the name cache of the first build.
+    let mut c = parse(&cm, "foo._qux; foo._qux; foo._bar;");
+    let json = serde_json::to_string(&cache).unwrap();
+    let mut cache: NameCache = serde_json::from_str(&json).unwrap();
+    mangle_props(&mut [&mut c], &options(), &mut cache);
+
+    assert_eq!(&*prop_of(&c), &*cache.props["_qux"]);
+    assert_eq!(cache.props["_bar"], "a");
+    assert_eq!(cache.props["_baz"], "b");
+    assert_eq!(cache.props["_qux"], "c");
+}
+
+#[test]
+fn name_cache_conflict_updates_cache() {
+    let cm = Lrc::new(SourceMap::default());
+    let mut m = parse(&cm, "foo._foo; foo.a;");
+
+    let mut cache: NameCache = serde_json::from_str(r#"{ "props": { "_foo": "a" } }"#).unwrap();
+    mangle_props(&mut [&mut m], &options(), &mut cache);
+
+    assert_eq!(&*prop_of(&m), "b");
+    assert_eq!(cache.props["_foo"], "b");
+}
@@ -63,6 +63,7 @@ pub enum SyntaxError {
         word: JsWord,
     },
     UnterminatedRegxp,
+    InvalidRegExp(swc_ecma_regexp::ErrorKind),
     UnterminatedTpl,
     IdentAfterNum,
     UnexpectedChar {
@@ -146,6 +147,9 @@ pub enum SyntaxError {
     },
     InvalidLeadingDecorator,
     DecoratorOnExport,
+    InvalidStaticBlock,
+    UndeclaredPrivateName(JsWord),
+    InvalidPrivateNameOperand,
 
     TsRequiredAfterOptional,
     TsInvalidParamPropPat,
@@ -262,6 +266,9 @@ impl SyntaxError {
                 format!("Unexpected escape sequence in reserved word: {}", word).into()
             }
             SyntaxError::UnterminatedRegxp => "Unterminated regexp literal".into(),
+            SyntaxError::InvalidRegExp(kind) => {
+                format!("Invalid regular expression: {}", kind).into()
+            }
             SyntaxError::UnterminatedTpl => "Unterminated template".into(),
             SyntaxError::IdentAfterNum => "Identifier cannot follow number".into(),
             SyntaxError::UnexpectedChar { c } => format!("Unexpected character {:?}", c).into(),
@@ -373,6 +380,15 @@ impl SyntaxError {
                                                class is not allowed. Please use `export @dec \
                                                class` instead."
                 .into(),
+            SyntaxError::InvalidStaticBlock => {
+                "Decorators and modifiers cannot appear on a static block".into()
+            }
+            SyntaxError::UndeclaredPrivateName(name) => {
+                format!("Private name #{} is not defined", name).into()
+            }
+            SyntaxError::InvalidPrivateNameOperand => {
+                "Private names are only allowed as the left operand of `in`".into()
+            }
             SyntaxError::TsRequiredAfterOptional => {
                 "A required element cannot follow an optional element.".into()
             }
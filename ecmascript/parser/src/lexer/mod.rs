@@ -3,6 +3,7 @@
 pub use self::{
     input::Input,
     state::{TokenContext, TokenContexts},
+    tokenizer::{Lexeme, LexemeKind, Tokenizer},
 };
 use self::{state::State, util::*};
 use crate::{
@@ -10,7 +11,6 @@ use crate::{
     token::*,
     Context, JscTarget, Syntax,
 };
-use either::Either::{Left, Right};
 use smallvec::{smallvec, SmallVec};
 use std::{cell::RefCell, char, iter::FusedIterator, mem::take, rc::Rc};
 use swc_atoms::{js_word, JsWord};
@@ -26,6 +26,7 @@ mod number;
 mod state;
 #[cfg(test)]
 mod tests;
+mod tokenizer;
 pub mod util;
 
 pub(crate) type LexResult<T> = Result<T, Error>;
@@ -184,13 +185,9 @@ impl<'a, I: Input> Lexer<'a, I> {
                     }
                 };
                 if '0' <= next && next <= '9' {
-                    return self
-                        .read_number(true)
-                        .map(|v| match v {
-                            Left(v) => Num(v),
-                            Right(v) => BigInt(v),
-                        })
-                        .map(Some);
+                    let start = self.cur_pos();
+                    let v = self.read_number(true)?;
+                    return Ok(Some(self.make_num_token(start, v)));
                 }
 
                 self.input.bump(); // 1st `.`
@@ -262,32 +259,20 @@ impl<'a, I: Input> Lexer<'a, I> {
                     Some('o') | Some('O') => 8,
                     Some('b') | Some('B') => 2,
                     _ => {
-                        return self
-                            .read_number(false)
-                            .map(|v| match v {
-                                Left(v) => Num(v),
-                                Right(v) => BigInt(v),
-                            })
-                            .map(Some)
+                        let start = self.cur_pos();
+                        let v = self.read_number(false)?;
+                        return Ok(Some(self.make_num_token(start, v)));
                     }
                 };
 
-                return self
-                    .read_radix_number(radix)
-                    .map(|v| match v {
-                        Left(v) => Num(v),
-                        Right(v) => BigInt(v),
-                    })
-                    .map(Some);
+                let start = self.cur_pos();
+                let v = self.read_radix_number(radix)?;
+                return Ok(Some(self.make_num_token(start, v)));
             }
             '1'..='9' => {
-                return self
-                    .read_number(false)
-                    .map(|v| match v {
-                        Left(v) => Num(v),
-                        Right(v) => BigInt(v),
-                    })
-                    .map(Some)
+                let start = self.cur_pos();
+                let v = self.read_number(false)?;
+                return Ok(Some(self.make_num_token(start, v)));
             }
 
             '"' | '\'' => return self.read_str_lit().map(Some),
@@ -954,6 +939,12 @@ impl<'a, I: Input> Lexer<'a, I> {
             .map(|(value, _)| value)
             .unwrap_or(js_word!(""));
 
+        if self.syntax.early_errors() {
+            if let Err(err) = swc_ecma_regexp::parse(start, &content, &flags) {
+                self.emit_error_span(err.span, SyntaxError::InvalidRegExp(err.kind));
+            }
+        }
+
         Ok(Regex(content, flags))
     }
 
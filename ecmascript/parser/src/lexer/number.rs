@@ -139,11 +139,40 @@ impl<'a, I: Input> Lexer<'a, I> {
                 .expect("failed to parse float literal");
         }
 
+        // `val` is rounded while reading each part of the literal, so the whole
+        // literal is parsed again to get the correctly rounded value.
+        let end = self.cur_pos();
+        if let Ok(exact) = self.input.slice(start, end).replace('_', "").parse() {
+            val = exact;
+        }
+
         self.ensure_not_ident()?;
 
         Ok(Either::Left(val))
     }
 
+    /// Converts the result of [Lexer::read_number] or
+    /// [Lexer::read_radix_number] into a token.
+    ///
+    /// `start` should be the position of the first character of the literal,
+    /// so the raw source text can be preserved.
+    pub(super) fn make_num_token(
+        &mut self,
+        start: BytePos,
+        value: Either<f64, BigIntValue>,
+    ) -> Token {
+        match value {
+            Either::Left(value) => {
+                let end = self.cur_pos();
+                Token::Num {
+                    value,
+                    raw: self.input.slice(start, end).into(),
+                }
+            }
+            Either::Right(value) => Token::BigInt(value),
+        }
+    }
+
     /// Returns `Left(value)` or `Right(BigInt)`
     pub(super) fn read_radix_number(&mut self, radix: u8) -> LexResult<Either<f64, BigIntValue>> {
         debug_assert!(
@@ -485,7 +514,6 @@ mod tests {
     }
 
     #[test]
-    #[ignore]
     fn num_big_many_zero() {
         assert_eq!(
             1_000_000_000_000_000_000_000_000_000_000f64,
@@ -586,9 +614,15 @@ mod tests {
                 };
                 assert_eq!(vec.len(), 1);
                 let token = vec.into_iter().next().unwrap();
-                assert_eq!(Num(expected), token);
+                match token {
+                    Num { value, .. } => assert_eq!(expected, value),
+                    _ => panic!("expected a numeric literal, got {:?}", token),
+                }
             } else if let Ok(vec) = vec {
-                assert_ne!(vec![Num(expected)], vec)
+                match &*vec {
+                    [Num { value, .. }] => assert_ne!(expected, *value),
+                    _ => {}
+                }
             }
         }
     }
@@ -76,12 +76,10 @@ impl WithSpan for Token {
 }
 impl WithSpan for usize {
     fn into_token(self) -> Token {
-        Num(self as f64)
-    }
-}
-impl WithSpan for f64 {
-    fn into_token(self) -> Token {
-        Num(self)
+        Num {
+            value: self as f64,
+            raw: self.to_string().into(),
+        }
     }
 }
 impl<'a> WithSpan for &'a str {
@@ -158,7 +156,12 @@ fn test262_lexer_error_0001() {
     assert_eq!(
         lex(Syntax::default(), "123..a(1)"),
         vec![
-            123f64.span(0..4).lb(),
+            Num {
+                value: 123.0,
+                raw: "123.".into(),
+            }
+            .span(0..4)
+            .lb(),
             Dot.span(4..5),
             "a".span(5..6),
             LParen.span(6..7),
@@ -1310,6 +1313,40 @@ fn issue_1272_2_js() {
     assert_eq!(errors, vec![]);
 }
 
+#[test]
+fn regexp_early_errors() {
+    let (tokens, errors) = lex_errors(Syntax::default(), "/(?<a>.)(?<a>.)/");
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(
+        errors,
+        vec![Error {
+            error: Box::new((
+                sp(11..13),
+                SyntaxError::InvalidRegExp(swc_ecma_regexp::ErrorKind::DuplicateCaptureGroupName)
+            )),
+        }]
+    );
+
+    let (_, errors) = lex_errors(Syntax::default(), "/a/gg");
+    assert_eq!(
+        errors,
+        vec![Error {
+            error: Box::new((
+                sp(4..5),
+                SyntaxError::InvalidRegExp(swc_ecma_regexp::ErrorKind::DuplicateFlag('g'))
+            )),
+        }]
+    );
+}
+
+#[test]
+fn regexp_annex_b() {
+    for &src in &["/{/", "/]/", "/\\1/", "/\\c/", "/[\\d-z]/"] {
+        let (_, errors) = lex_errors(Syntax::default(), src);
+        assert_eq!(errors, vec![], "{}", src);
+    }
+}
+
 #[test]
 #[ignore = "Raw token should be different. See https://github.com/denoland/deno/issues/9620"]
 fn normalize_tpl_carriage_return() {
@@ -1322,3 +1359,95 @@ fn normalize_tpl_carriage_return() {
         lex_tokens(Syntax::default(), "`\n`")
     );
 }
+
+fn tokenize(syntax: Syntax, s: &'static str) -> Vec<(LexemeKind, &'static str)> {
+    crate::with_test_sess(s, |_, input| {
+        Ok(Tokenizer::new(syntax, Default::default(), input)
+            .map(|l| (l.kind, &s[l.span.lo.0 as usize..l.span.hi.0 as usize]))
+            .collect())
+    })
+    .unwrap()
+}
+
+#[test]
+fn tokenizer_trivia() {
+    assert_eq!(
+        tokenize(Syntax::default(), "/* a */ x\r\n// b\n\ty;"),
+        vec![
+            (LexemeKind::BlockComment, "/* a */"),
+            (LexemeKind::Whitespace, " "),
+            (LexemeKind::Ident, "x"),
+            (LexemeKind::Newline, "\r\n"),
+            (LexemeKind::LineComment, "// b"),
+            (LexemeKind::Newline, "\n"),
+            (LexemeKind::Whitespace, "\t"),
+            (LexemeKind::Ident, "y"),
+            (LexemeKind::Punct, ";"),
+        ]
+    );
+}
+
+#[test]
+fn tokenizer_regex() {
+    assert_eq!(
+        tokenize(Syntax::default(), "a / b;\nif (x) /re/g;")
+            .into_iter()
+            .filter(|t| !t.0.is_trivia())
+            .collect::<Vec<_>>(),
+        vec![
+            (LexemeKind::Ident, "a"),
+            (LexemeKind::Punct, "/"),
+            (LexemeKind::Ident, "b"),
+            (LexemeKind::Punct, ";"),
+            (LexemeKind::Keyword, "if"),
+            (LexemeKind::Punct, "("),
+            (LexemeKind::Ident, "x"),
+            (LexemeKind::Punct, ")"),
+            (LexemeKind::Regex, "/re/g"),
+            (LexemeKind::Punct, ";"),
+        ]
+    );
+}
+
+#[test]
+fn tokenizer_tpl() {
+    assert_eq!(
+        tokenize(Syntax::default(), "`a ${ b } c`"),
+        vec![
+            (LexemeKind::Punct, "`"),
+            (LexemeKind::Template, "a "),
+            (LexemeKind::Punct, "${"),
+            (LexemeKind::Whitespace, " "),
+            (LexemeKind::Ident, "b"),
+            (LexemeKind::Whitespace, " "),
+            (LexemeKind::Punct, "}"),
+            (LexemeKind::Template, " c"),
+            (LexemeKind::Punct, "`"),
+        ]
+    );
+}
+
+#[test]
+fn tokenizer_jsx() {
+    assert_eq!(
+        tokenize(
+            crate::Syntax::Es(crate::EsConfig {
+                jsx: true,
+                ..Default::default()
+            }),
+            "<a b>foo </a>"
+        ),
+        vec![
+            (LexemeKind::Punct, "<"),
+            (LexemeKind::JSXName, "a"),
+            (LexemeKind::Whitespace, " "),
+            (LexemeKind::JSXName, "b"),
+            (LexemeKind::Punct, ">"),
+            (LexemeKind::JSXText, "foo "),
+            (LexemeKind::Punct, "<"),
+            (LexemeKind::Punct, "/"),
+            (LexemeKind::JSXName, "a"),
+            (LexemeKind::Punct, ">"),
+        ]
+    );
+}
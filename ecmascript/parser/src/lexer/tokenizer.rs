@@ -0,0 +1,288 @@
+//! Tokenizer for syntax highlighters and formatters.
+
+use super::{Input, Lexer};
+use crate::{
+    token::{Token, Word},
+    JscTarget, Syntax,
+};
+use std::collections::VecDeque;
+use swc_common::{BytePos, Span, Spanned, SyntaxContext};
+
+/// A token or trivia.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lexeme {
+    pub kind: LexemeKind,
+    pub span: Span,
+    /// [None] for trivia.
+    pub token: Option<Token>,
+}
+
+impl Spanned for Lexeme {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LexemeKind {
+    /// Spaces and tabs, except line terminators.
+    Whitespace,
+    /// A line terminator. `\r\n` is a single newline.
+    Newline,
+    /// `// ...`, without the line terminator.
+    LineComment,
+    /// `/* ... */`
+    BlockComment,
+    /// `#!...`
+    Shebang,
+
+    /// Reserved words, including `null`, `true` and `false`.
+    ///
+    /// Contextual keywords like `async` or `of` are [LexemeKind::Ident].
+    Keyword,
+    Ident,
+    /// Operators and punctuations, including `` ` `` and `${` of templates
+    /// and `<` and `>` of jsx tags.
+    Punct,
+    /// String literal, including the quotes.
+    Str,
+    Num,
+    BigInt,
+    /// Regular expression literal, including the slashes and the flags.
+    Regex,
+    /// Text between `` ` ``, `${` and `}` of a template literal. It may be
+    /// empty.
+    Template,
+    /// Names of jsx elements and attributes.
+    JSXName,
+    JSXText,
+
+    /// The lexer failed to read a token. [Lexeme::token] is the error, if
+    /// any.
+    Error,
+}
+
+impl LexemeKind {
+    /// Returns true for whitespaces, newlines, comments and shebangs.
+    pub fn is_trivia(self) -> bool {
+        match self {
+            LexemeKind::Whitespace
+            | LexemeKind::Newline
+            | LexemeKind::LineComment
+            | LexemeKind::BlockComment
+            | LexemeKind::Shebang => true,
+            _ => false,
+        }
+    }
+}
+
+impl<'a> From<&'a Token> for LexemeKind {
+    fn from(token: &Token) -> Self {
+        match *token {
+            Token::Word(Word::Ident(..)) => LexemeKind::Ident,
+            Token::Word(..) => LexemeKind::Keyword,
+            Token::Str { .. } => LexemeKind::Str,
+            Token::Num { .. } => LexemeKind::Num,
+            Token::BigInt(..) => LexemeKind::BigInt,
+            Token::Regex(..) => LexemeKind::Regex,
+            Token::Template { .. } => LexemeKind::Template,
+            Token::JSXName { .. } => LexemeKind::JSXName,
+            Token::JSXText { .. } => LexemeKind::JSXText,
+            Token::Shebang(..) => LexemeKind::Shebang,
+            Token::Error(..) => LexemeKind::Error,
+            _ => LexemeKind::Punct,
+        }
+    }
+}
+
+/// Yields every token and trivia of the input, in order.
+///
+/// Unlike [Lexer], this does not need a parser. Regular expressions,
+/// templates and jsx are distinguished by the lexer state, like the
+/// tokenizer of babel. Spans of lexemes don't overlap and cover the whole
+/// input, except html-like comments (`<!--` and `-->`), which are a part of
+/// the next token.
+///
+/// The tokenizer does not stop at errors. The lexer skips the invalid
+/// characters and continues.
+///
+/// # Example
+///
+/// ```
+/// use swc_common::{input::StringInput, BytePos};
+/// use swc_ecma_parser::lexer::{LexemeKind, Tokenizer};
+///
+/// let input = StringInput::new("a / b // c", BytePos(0), BytePos(10));
+/// let kinds = Tokenizer::new(Default::default(), Default::default(), input)
+///     .map(|lexeme| lexeme.kind)
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(
+///     kinds,
+///     vec![
+///         LexemeKind::Ident,
+///         LexemeKind::Whitespace,
+///         LexemeKind::Punct,
+///         LexemeKind::Whitespace,
+///         LexemeKind::Ident,
+///         LexemeKind::Whitespace,
+///         LexemeKind::LineComment,
+///     ]
+/// );
+/// ```
+pub struct Tokenizer<'a, I: Input> {
+    lexer: Lexer<'a, I>,
+    /// Used to read trivia.
+    input: I,
+    /// End of the last token.
+    last_pos: BytePos,
+    queue: VecDeque<Lexeme>,
+    done: bool,
+}
+
+impl<'a, I: Input> Tokenizer<'a, I> {
+    pub fn new(syntax: Syntax, target: JscTarget, mut input: I) -> Self {
+        let last_pos = input.cur_pos();
+
+        Tokenizer {
+            lexer: Lexer::new(syntax, target, input.clone(), None),
+            input,
+            last_pos,
+            queue: Default::default(),
+            done: false,
+        }
+    }
+
+    /// Splits `self.last_pos..end` into trivia.
+    fn read_trivia(&mut self, end: BytePos) {
+        if end <= self.last_pos {
+            return;
+        }
+        self.input.reset_to(self.last_pos);
+
+        while self.input.cur_pos() < end {
+            let start = self.input.cur_pos();
+            let c = match self.input.cur() {
+                Some(c) => c,
+                None => break,
+            };
+            self.input.bump();
+
+            let kind = match c {
+                '\r' => {
+                    self.input.eat_byte(b'\n');
+                    LexemeKind::Newline
+                }
+                '\n' | '\u{2028}' | '\u{2029}' => LexemeKind::Newline,
+
+                '/' if self.input.eat_byte(b'/') => {
+                    while let Some(c) = self.input.cur() {
+                        if is_newline(c) {
+                            break;
+                        }
+                        self.input.bump();
+                    }
+                    LexemeKind::LineComment
+                }
+                '/' if self.input.eat_byte(b'*') => {
+                    while let Some(c) = self.input.cur() {
+                        self.input.bump();
+                        if c == '*' && self.input.eat_byte(b'/') {
+                            break;
+                        }
+                    }
+                    LexemeKind::BlockComment
+                }
+
+                c if c.is_whitespace() || c == '\u{feff}' => {
+                    while let Some(c) = self.input.cur() {
+                        if is_newline(c) || !(c.is_whitespace() || c == '\u{feff}') {
+                            break;
+                        }
+                        self.input.bump();
+                    }
+                    LexemeKind::Whitespace
+                }
+
+                // Unreachable unless the lexer skips something else.
+                _ => {
+                    self.input.reset_to(end);
+                    LexemeKind::Error
+                }
+            };
+
+            self.queue.push_back(Lexeme {
+                kind,
+                span: Span::new(start, self.input.cur_pos(), SyntaxContext::empty()),
+                token: None,
+            });
+        }
+
+        self.last_pos = end;
+    }
+
+    /// Reports the rest of the input as an error.
+    fn skip_rest(&mut self) {
+        self.input.reset_to(self.last_pos);
+        self.input.uncons_while(|_| true);
+        let end = self.input.cur_pos();
+
+        if self.last_pos < end {
+            self.queue.push_back(Lexeme {
+                kind: LexemeKind::Error,
+                span: Span::new(self.last_pos, end, SyntaxContext::empty()),
+                token: None,
+            });
+        }
+        self.last_pos = end;
+        self.done = true;
+    }
+}
+
+impl<I: Input> Iterator for Tokenizer<'_, I> {
+    type Item = Lexeme;
+
+    fn next(&mut self) -> Option<Lexeme> {
+        while self.queue.is_empty() && !self.done {
+            let pos = self.lexer.input.cur_pos();
+            match self.lexer.next() {
+                Some(t) => {
+                    self.read_trivia(t.span.lo);
+                    if t.span.hi > self.last_pos {
+                        self.last_pos = t.span.hi;
+                    }
+
+                    // The lexer may fail without moving forward.
+                    let stuck = match t.token {
+                        Token::Error(..) => self.lexer.input.cur_pos() == pos,
+                        _ => false,
+                    };
+
+                    self.queue.push_back(Lexeme {
+                        kind: LexemeKind::from(&t.token),
+                        span: t.span,
+                        token: Some(t.token),
+                    });
+
+                    if stuck {
+                        self.skip_rest();
+                    }
+                }
+                None => {
+                    let end = self.lexer.input.cur_pos();
+                    self.read_trivia(end);
+                    self.done = true;
+                }
+            }
+        }
+
+        self.queue.pop_front()
+    }
+}
+
+fn is_newline(c: char) -> bool {
+    match c {
+        '\r' | '\n' | '\u{2028}' | '\u{2029}' => true,
+        _ => false,
+    }
+}
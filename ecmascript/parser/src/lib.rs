@@ -115,6 +115,10 @@ pub enum Syntax {
     Es(EsConfig),
     #[serde(rename = "typescript")]
     Typescript(TsConfig),
+    /// Flow is parsed using the typescript parser, and flow annotations are
+    /// represented with the typescript nodes of [swc_ecma_ast].
+    #[serde(rename = "flow")]
+    Flow(FlowConfig),
 }
 
 impl Default for Syntax {
@@ -132,6 +136,7 @@ impl Syntax {
             | Syntax::Typescript(TsConfig {
                 import_assertions, ..
             }) => import_assertions,
+            Syntax::Flow(..) => false,
         }
     }
 
@@ -139,7 +144,8 @@ impl Syntax {
     pub fn jsx(self) -> bool {
         match self {
             Syntax::Es(EsConfig { jsx: true, .. })
-            | Syntax::Typescript(TsConfig { tsx: true, .. }) => true,
+            | Syntax::Typescript(TsConfig { tsx: true, .. })
+            | Syntax::Flow(FlowConfig { jsx: true, .. }) => true,
             _ => false,
         }
     }
@@ -157,6 +163,39 @@ impl Syntax {
             | Syntax::Typescript(TsConfig {
                 dynamic_import: true,
                 ..
+            })
+            | Syntax::Flow(FlowConfig {
+                dynamic_import: true,
+                ..
+            }) => true,
+            _ => false,
+        }
+    }
+
+    /// If true, the parser recovers from syntax errors instead of failing on
+    /// the first one.
+    ///
+    /// Statements and class members which cannot be parsed are replaced with
+    /// placeholders containing [swc_ecma_ast::Expr::Invalid] or
+    /// [swc_ecma_ast::Pat::Invalid], and the errors are reported via
+    /// [Parser::take_errors]. The `parse_*` methods for programs always return
+    /// `Ok` in this mode.
+    ///
+    /// This is useful for editors, where the source code is incomplete most of
+    /// the time.
+    pub fn error_recovery(self) -> bool {
+        match self {
+            Syntax::Es(EsConfig {
+                error_recovery: true,
+                ..
+            })
+            | Syntax::Typescript(TsConfig {
+                error_recovery: true,
+                ..
+            })
+            | Syntax::Flow(FlowConfig {
+                error_recovery: true,
+                ..
             }) => true,
             _ => false,
         }
@@ -180,6 +219,9 @@ impl Syntax {
             })
             | Syntax::Typescript(TsConfig {
                 decorators: true, ..
+            })
+            | Syntax::Flow(FlowConfig {
+                decorators: true, ..
             }) => true,
             _ => false,
         }
@@ -195,7 +237,8 @@ impl Syntax {
                 class_private_props: true,
                 ..
             })
-            | Syntax::Typescript(..) => true,
+            | Syntax::Typescript(..)
+            | Syntax::Flow(..) => true,
             _ => false,
         }
     }
@@ -210,15 +253,27 @@ impl Syntax {
                 decorators_before_export: true,
                 ..
             })
-            | Syntax::Typescript(..) => true,
+            | Syntax::Typescript(..)
+            | Syntax::Flow(..) => true,
             _ => false,
         }
     }
 
     /// Should we pare typescript?
+    ///
+    /// This is also true for [Syntax::Flow], because flow annotations are
+    /// parsed by the typescript parser.
     pub fn typescript(self) -> bool {
         match self {
-            Syntax::Typescript(..) => true,
+            Syntax::Typescript(..) | Syntax::Flow(..) => true,
+            _ => false,
+        }
+    }
+
+    /// Should we parse flow?
+    pub fn flow(self) -> bool {
+        match self {
+            Syntax::Flow(..) => true,
             _ => false,
         }
     }
@@ -248,7 +303,8 @@ impl Syntax {
             Syntax::Es(EsConfig {
                 import_meta: true, ..
             })
-            | Syntax::Typescript(..) => true,
+            | Syntax::Typescript(..)
+            | Syntax::Flow(..) => true,
 
             _ => false,
         }
@@ -260,7 +316,8 @@ impl Syntax {
                 top_level_await: true,
                 ..
             })
-            | Syntax::Typescript(..) => true,
+            | Syntax::Typescript(..)
+            | Syntax::Flow(..) => true,
 
             _ => false,
         }
@@ -276,7 +333,7 @@ impl Syntax {
     pub(crate) fn early_errors(self) -> bool {
         match self {
             Syntax::Typescript(t) => !t.no_early_errors,
-            Syntax::Es(..) => true,
+            Syntax::Es(..) | Syntax::Flow(..) => true,
         }
     }
 }
@@ -303,6 +360,27 @@ pub struct TsConfig {
     /// Stage 3.
     #[serde(default)]
     pub import_assertions: bool,
+
+    /// See [Syntax::error_recovery].
+    #[serde(default)]
+    pub error_recovery: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct FlowConfig {
+    #[serde(default)]
+    pub jsx: bool,
+
+    #[serde(default)]
+    pub decorators: bool,
+
+    #[serde(default)]
+    pub dynamic_import: bool,
+
+    /// See [Syntax::error_recovery].
+    #[serde(default)]
+    pub error_recovery: bool,
 }
 
 #[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
@@ -371,6 +449,10 @@ pub struct EsConfig {
     /// Stage 3.
     #[serde(default)]
     pub import_assertions: bool,
+
+    /// See [Syntax::error_recovery].
+    #[serde(default)]
+    pub error_recovery: bool,
 }
 
 /// Syntactic context.
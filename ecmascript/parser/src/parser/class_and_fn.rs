@@ -160,12 +160,16 @@ impl<'a, I: Tokens> Parser<I> {
             }
 
             expect!(p, '{');
+            p.brand_checks.push(vec![]);
             let body = p
                 .with_ctx(Context {
                     has_super_class: super_class.is_some(),
                     ..p.ctx()
                 })
-                .parse_class_body()?;
+                .parse_class_body();
+            let brand_checks = p.brand_checks.pop().unwrap_or_default();
+            let body = body?;
+            p.verify_brand_checks(&body, brand_checks);
             expect!(p, '}');
             let end = last_pos!(p);
             Ok(T::finish_class(
@@ -286,11 +290,42 @@ impl<'a, I: Tokens> Parser<I> {
                 continue;
             }
 
-            elems.push(self.parse_class_member()?);
+            let start = cur_pos!(self);
+            let member = match self.parse_class_member() {
+                Ok(member) => member,
+                Err(err) if self.should_recover() => self.recover_class_member(start, err),
+                Err(err) => return Err(err),
+            };
+            elems.push(member);
         }
         Ok(elems)
     }
 
+    /// Checks if private names used by brand checks are declared by the class
+    /// or an enclosing class.
+    fn verify_brand_checks(&mut self, body: &[ClassMember], names: Vec<PrivateName>) {
+        for name in names {
+            let declared = body.iter().any(|member| match member {
+                ClassMember::PrivateProp(PrivateProp { key, .. })
+                | ClassMember::PrivateMethod(PrivateMethod { key, .. }) => {
+                    key.id.sym == name.id.sym
+                }
+                _ => false,
+            });
+            if declared {
+                continue;
+            }
+
+            match self.brand_checks.last_mut() {
+                Some(outer) => outer.push(name),
+                None => self.emit_err(
+                    name.span,
+                    SyntaxError::UndeclaredPrivateName(name.id.sym.clone()),
+                ),
+            }
+        }
+    }
+
     pub(super) fn parse_access_modifier(&mut self) -> PResult<Option<Accessibility>> {
         Ok(self
             .parse_ts_modifier(&["public", "protected", "private"])?
@@ -375,6 +410,13 @@ impl<'a, I: Tokens> Parser<I> {
         };
 
         if let Some(static_token) = static_token {
+            if is!(self, '{') {
+                if !decorators.is_empty() || declare || accessibility.is_some() {
+                    self.emit_err(span!(self, start), SyntaxError::InvalidStaticBlock);
+                }
+                return self.parse_static_block(start).map(ClassMember::StaticBlock);
+            }
+
             // Handle static(){}
             if self.is_class_method()? {
                 let key = Either::Right(PropName::Ident(Ident::new(
@@ -432,6 +474,32 @@ impl<'a, I: Tokens> Parser<I> {
         )
     }
 
+    /// `static { }`, after `static`.
+    fn parse_static_block(&mut self, start: BytePos) -> PResult<StaticBlock> {
+        // Labels, `break`, `continue` and `return` cannot cross a static block.
+        let ctx = Context {
+            in_async: false,
+            in_generator: false,
+            in_function: false,
+            is_break_allowed: false,
+            is_continue_allowed: false,
+            ..self.ctx()
+        };
+        let state = State {
+            labels: vec![],
+            ..Default::default()
+        };
+        let body = self
+            .with_ctx(ctx)
+            .with_state(state)
+            .parse_with(|p| p.parse_block(false))?;
+
+        Ok(StaticBlock {
+            span: span!(self, start),
+            body,
+        })
+    }
+
     #[allow(clippy::cognitive_complexity)]
     fn parse_class_member_with_is_static(
         &mut self,
@@ -1063,6 +1131,7 @@ impl<'a, I: Tokens> Parser<I> {
             } else {
                 None
             };
+            p.skip_flow_predicate()?;
 
             let body: Option<_> = p.parse_fn_body(is_async, is_generator)?;
 
@@ -1420,4 +1489,23 @@ mod tests {
             }))
         );
     }
+
+    #[test]
+    fn static_block() {
+        let class = match *expr("(class { static { this.a = 1; } static() {} static b; })") {
+            Expr::Paren(ParenExpr { expr, .. }) => match *expr {
+                Expr::Class(ClassExpr { class, .. }) => class,
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        };
+
+        assert_eq!(class.body.len(), 3);
+        match &class.body[0] {
+            ClassMember::StaticBlock(StaticBlock { body, .. }) => assert_eq!(body.stmts.len(), 1),
+            member => panic!("expected a static block, got {:?}", member),
+        }
+        assert!(class.body[1].is_method());
+        assert!(class.body[2].is_class_prop());
+    }
 }
@@ -284,7 +284,7 @@ impl<'a, I: Tokens> Parser<I> {
                 tok!("null")
                 | tok!("true")
                 | tok!("false")
-                | Token::Num(..)
+                | Token::Num { .. }
                 | Token::BigInt(..)
                 | Token::Str { .. } => {
                     return Ok(Box::new(Expr::Lit(self.parse_lit()?)));
@@ -604,7 +604,12 @@ impl<'a, I: Tokens> Parser<I> {
         });
 
         // This is slow path. We handle arrow in conditional expression.
-        if self.syntax().typescript() && self.ctx().in_cond_expr && is!(self, ':') {
+        //
+        // In flow, `:` may also start the type of a type cast, like `((a): T)`.
+        if self.syntax().typescript()
+            && (self.ctx().in_cond_expr || self.syntax().flow())
+            && is!(self, ':')
+        {
             // TODO: Remove clone
             let items_ref = &paren_items;
             if let Some(expr) = self.try_parse_ts(|p| {
@@ -635,6 +640,7 @@ impl<'a, I: Tokens> Parser<I> {
 
         let return_type = if !self.ctx().in_cond_expr
             && self.input.syntax().typescript()
+            && !self.input.syntax().flow()
             && is!(self, ':')
             && !self.ctx().in_case_cond
         {
@@ -1321,7 +1327,29 @@ impl<'a, I: Tokens> Parser<I> {
                 false
             };
 
-            if optional || (self.input.syntax().typescript() && is!(self, ':')) {
+            if self.input.syntax().flow() && !optional && arg.spread.is_none() && is!(self, ':') {
+                let expr = self.parse_flow_type_cast(arg.expr)?;
+
+                if has_modifier {
+                    self.emit_err(span!(self, modifier_start), SyntaxError::TS2369);
+                }
+
+                if eat!(self, '=') {
+                    let left = self.reparse_expr_as_pat(PatType::BindingPat, expr)?;
+                    let right = self.parse_assignment_expr()?;
+                    items.push(PatOrExprOrSpread::Pat(Pat::Assign(AssignPat {
+                        span: span!(self, pat_start),
+                        left: Box::new(left),
+                        right,
+                        type_ann: None,
+                    })));
+                } else {
+                    items.push(PatOrExprOrSpread::ExprOrSpread(ExprOrSpread {
+                        spread: None,
+                        expr,
+                    }));
+                }
+            } else if optional || (self.input.syntax().typescript() && is!(self, ':')) {
                 let start = cur_pos!(self);
 
                 // TODO: `async(...args?: any[]) : any => {}`
@@ -1552,10 +1580,11 @@ impl<'a, I: Tokens> Parser<I> {
                 }),
                 _ => unreachable!(),
             },
-            Token::Num(..) => match bump!(self) {
-                Token::Num(value) => Lit::Num(Number {
+            Token::Num { .. } => match bump!(self) {
+                Token::Num { value, raw } => Lit::Num(Number {
                     span: span!(self, start),
                     value,
+                    raw: Some(raw),
                 }),
                 _ => unreachable!(),
             },
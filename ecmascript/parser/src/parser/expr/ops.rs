@@ -11,7 +11,7 @@ impl<'a, I: Tokens> Parser<I> {
 
         let ctx = self.ctx();
 
-        let left = match self.parse_unary_expr() {
+        let left = match self.parse_bin_operand() {
             Ok(v) => v,
             Err(err) => {
                 trace_cur!(self, parse_bin_expr__recovery_unary_err);
@@ -36,6 +36,36 @@ impl<'a, I: Tokens> Parser<I> {
         self.parse_bin_op_recursively(left, 0)
     }
 
+    /// Parses an operand of a binary expression, which may be the private
+    /// name of a brand check (`#x in obj`).
+    fn parse_bin_operand(&mut self) -> PResult<Box<Expr>> {
+        if is!(self, '#') {
+            return self.parse_brand_check_name();
+        }
+
+        self.parse_unary_expr()
+    }
+
+    /// `#x` of `#x in obj`
+    fn parse_brand_check_name(&mut self) -> PResult<Box<Expr>> {
+        let name = self.parse_private_name()?;
+        if !self.ctx().include_in_expr || !is!(self, "in") {
+            unexpected!(self, "in")
+        }
+
+        // Private names are validated when the enclosing class is parsed,
+        // because they may be declared after the brand check.
+        match self.brand_checks.last_mut() {
+            Some(names) => names.push(name.clone()),
+            None => self.emit_err(
+                name.span,
+                SyntaxError::UndeclaredPrivateName(name.id.sym.clone()),
+            ),
+        }
+
+        Ok(Box::new(Expr::PrivateName(name)))
+    }
+
     /// Parse binary operators with the operator precedence parsing
     /// algorithm. `left` is the left-hand side of the operator.
     /// `minPrec` provides context that allows the function to stop and
@@ -175,7 +205,7 @@ impl<'a, I: Tokens> Parser<I> {
         }
 
         let right = {
-            let left_of_right = self.parse_unary_expr()?;
+            let left_of_right = self.parse_bin_operand()?;
             self.parse_bin_op_recursively(
                 left_of_right,
                 if op == op!("**") {
@@ -186,6 +216,10 @@ impl<'a, I: Tokens> Parser<I> {
                 },
             )?
         };
+        // `a + #x in obj` is `(a + #x) in obj`
+        if right.is_private_name() {
+            syntax_error!(self, right.span(), SyntaxError::InvalidPrivateNameOperand)
+        }
         /* this check is for all ?? operators
          * a ?? b && c for this example
          * b && c => This is considered as a logical expression in the ast tree
@@ -0,0 +1,617 @@
+//! Flow extensions.
+//!
+//! Flow annotations are parsed by the typescript parser, so this file only
+//! contains syntax which does not exist in typescript. Flow-only types are
+//! converted to the nearest typescript type.
+
+use super::*;
+use crate::token::BinOpToken;
+use swc_common::Spanned;
+
+impl<I: Tokens> Parser<I> {
+    /// Returns true if the current token is the contextual keyword `word`.
+    pub(super) fn is_flow_word(&mut self, word: &str) -> bool {
+        match self.input.cur() {
+            Some(Token::Word(Word::Ident(w))) => &**w == word,
+            _ => false,
+        }
+    }
+
+    /// Returns true if the current token is `{` of `{|`, which opens an exact
+    /// object type.
+    pub(super) fn is_flow_exact_object_start(&mut self) -> bool {
+        self.input.syntax().flow()
+            && is!(self, '{')
+            && (peeked_is!(self, '|') || self.input.peeked_is(&Token::BinOp(BinOpToken::LogicalOr)))
+    }
+
+    /// Returns true if the current token is `|` of `|}`, which closes an exact
+    /// object type.
+    pub(super) fn is_flow_exact_object_end(&mut self) -> bool {
+        self.input.syntax().flow() && is!(self, '|') && peeked_is!(self, '}')
+    }
+
+    /// `{ a: T }`, `{| a: T |}`, `{ ...A, b: T }` and `{ a: T, ... }`.
+    ///
+    /// Spreads are represented as an intersection, like `A & { b: T }`, and
+    /// `...` of explicitly inexact object types is ignored.
+    pub(super) fn parse_flow_object_type(&mut self) -> PResult<Box<TsType>> {
+        debug_assert!(self.input.syntax().flow());
+
+        let start = cur_pos!(self);
+        let exact = self.is_flow_exact_object_start();
+        expect!(self, '{');
+
+        let mut members = vec![];
+        let mut spreads = vec![];
+        // `{||}` is lexed as `{`, `||` and `}`.
+        if !exact || !self.input.eat(&Token::BinOp(BinOpToken::LogicalOr)) {
+            if exact {
+                expect!(self, '|');
+            }
+
+            while !eof!(self)
+                && !(exact && self.is_flow_exact_object_end() || !exact && is!(self, '}'))
+            {
+                if eat!(self, "...") {
+                    if !is_one_of!(self, '}', ',', ';') && !self.is_flow_exact_object_end() {
+                        spreads.push(self.parse_ts_type()?);
+                    }
+                    if !eat!(self, ',') {
+                        eat!(self, ';');
+                    }
+                    continue;
+                }
+
+                members.push(self.parse_ts_type_member()?);
+            }
+
+            if exact {
+                expect!(self, '|');
+            }
+        }
+        expect!(self, '}');
+
+        let lit = TsTypeLit {
+            span: span!(self, start),
+            members,
+        };
+        if spreads.is_empty() {
+            return Ok(Box::new(TsType::TsTypeLit(lit)));
+        }
+
+        if !lit.members.is_empty() {
+            spreads.push(Box::new(TsType::TsTypeLit(lit)));
+        }
+        if spreads.len() == 1 {
+            return Ok(spreads.pop().unwrap());
+        }
+
+        Ok(Box::new(TsType::TsUnionOrIntersectionType(
+            TsUnionOrIntersectionType::TsIntersectionType(TsIntersectionType {
+                span: span!(self, start),
+                types: spreads,
+            }),
+        )))
+    }
+
+    /// `[K]: V` and `[name: K]: V` in an object type, after the variance.
+    ///
+    /// The name of an indexer is optional in flow, and `key` is used if it's
+    /// omitted.
+    pub(super) fn parse_flow_indexer(
+        &mut self,
+        start: BytePos,
+        readonly: bool,
+    ) -> PResult<TsIndexSignature> {
+        debug_assert!(self.input.syntax().flow());
+
+        expect!(self, '[');
+        let id = if is!(self, IdentName) && peeked_is!(self, ':') {
+            let id = self.parse_ident_name()?;
+            bump!(self);
+            id
+        } else {
+            Ident::new("key".into(), self.input.cur_span())
+        };
+        let key_start = cur_pos!(self);
+        let key_type = self.parse_ts_type()?;
+        let key_type = TsTypeAnn {
+            span: span!(self, key_start),
+            type_ann: key_type,
+        };
+        expect!(self, ']');
+
+        let type_ann = self.parse_ts_type_ann(/* eat_colon */ true, cur_pos!(self))?;
+        self.parse_ts_type_member_semicolon()?;
+
+        Ok(TsIndexSignature {
+            span: span!(self, start),
+            params: vec![TsFnParam::Ident(BindingIdent {
+                id,
+                type_ann: Some(key_type),
+            })],
+            type_ann: Some(type_ann),
+            readonly,
+            is_static: false,
+        })
+    }
+
+    /// Returns true if the parenthesized type at the cursor is followed by
+    /// `=>`.
+    ///
+    /// Parameters of function types may be unnamed in flow, so `(A) => B` and
+    /// `(A)` can only be distinguished after `)`.
+    pub(super) fn is_flow_start_of_fn_type(&mut self) -> PResult<bool> {
+        debug_assert!(self.input.syntax().flow());
+
+        assert_and_bump!(self, '(');
+        let mut depth = 1;
+        while depth > 0 {
+            if eof!(self) {
+                return Ok(false);
+            }
+            if is!(self, '(') {
+                depth += 1;
+            } else if is!(self, ')') {
+                depth -= 1;
+            }
+            bump!(self);
+        }
+
+        Ok(is!(self, "=>"))
+    }
+
+    /// Parameters of a function type, after `(`. Eats `)`.
+    ///
+    /// `(string, b?: number, ...Array<T>)`. Unnamed parameters are named `_0`,
+    /// `_1` and so on, after their position.
+    pub(super) fn parse_flow_fn_type_params(&mut self) -> PResult<Vec<TsFnParam>> {
+        debug_assert!(self.input.syntax().flow());
+
+        let mut params = vec![];
+        while !eof!(self) && !is!(self, ')') {
+            let start = cur_pos!(self);
+            let dot3_token = if eat!(self, "...") {
+                Some(self.input.prev_span())
+            } else {
+                None
+            };
+
+            let id = if is!(self, IdentName) && (peeked_is!(self, ':') || peeked_is!(self, '?')) {
+                let mut id = self.parse_ident_name()?;
+                id.optional = eat!(self, '?');
+                expect!(self, ':');
+                id
+            } else {
+                Ident::new(format!("_{}", params.len()).into(), self.input.cur_span())
+            };
+
+            let ty_start = cur_pos!(self);
+            let ty = self.parse_ts_type()?;
+            let type_ann = Some(TsTypeAnn {
+                span: span!(self, ty_start),
+                type_ann: ty,
+            });
+
+            params.push(match dot3_token {
+                Some(dot3_token) => TsFnParam::Rest(RestPat {
+                    span: span!(self, start),
+                    dot3_token,
+                    arg: Box::new(Pat::Ident(id.into())),
+                    type_ann,
+                }),
+                None => TsFnParam::Ident(BindingIdent { id, type_ann }),
+            });
+
+            if !eat!(self, ',') {
+                break;
+            }
+        }
+        expect!(self, ')');
+
+        Ok(params)
+    }
+
+    /// `?T`, which is a shorthand for `T | null | void`.
+    pub(super) fn parse_flow_maybe_type(&mut self) -> PResult<Box<TsType>> {
+        debug_assert!(self.input.syntax().flow());
+
+        let start = cur_pos!(self);
+        assert_and_bump!(self, '?');
+        let question_span = self.input.prev_span();
+
+        let ty = self.parse_ts_type_operator_or_higher()?;
+        let keyword = |kind| {
+            Box::new(TsType::TsKeywordType(TsKeywordType {
+                span: question_span,
+                kind,
+            }))
+        };
+
+        Ok(Box::new(TsType::TsUnionOrIntersectionType(
+            TsUnionOrIntersectionType::TsUnionType(TsUnionType {
+                span: span!(self, start),
+                types: vec![
+                    ty,
+                    keyword(TsKeywordTypeKind::TsNullKeyword),
+                    keyword(TsKeywordTypeKind::TsUndefinedKeyword),
+                ],
+            }),
+        )))
+    }
+
+    /// `*`, the existential type.
+    ///
+    /// Flow infers it, but we don't check types so it's `any`.
+    pub(super) fn parse_flow_existential_type(&mut self) -> PResult<Box<TsType>> {
+        debug_assert!(self.input.syntax().flow());
+
+        let start = cur_pos!(self);
+        assert_and_bump!(self, '*');
+
+        Ok(Box::new(TsType::TsKeywordType(TsKeywordType {
+            span: span!(self, start),
+            kind: TsKeywordTypeKind::TsAnyKeyword,
+        })))
+    }
+
+    /// Eats a variance sigil, and returns true if it's `+`.
+    ///
+    /// Covariant properties are read-only, so they are marked as `readonly`.
+    pub(super) fn eat_flow_variance(&mut self) -> bool {
+        if !self.input.syntax().flow() {
+            return false;
+        }
+
+        if eat!(self, '+') {
+            return true;
+        }
+        eat!(self, '-');
+        false
+    }
+
+    /// Skips `%checks` and `%checks(expr)` after the return type of a
+    /// function.
+    pub(super) fn skip_flow_predicate(&mut self) -> PResult<()> {
+        if !self.input.syntax().flow() || !self.input.is(&Token::BinOp(BinOpToken::Mod)) {
+            return Ok(());
+        }
+        bump!(self);
+
+        if !self.is_flow_word("checks") {
+            unexpected!(self, "checks")
+        }
+        bump!(self);
+
+        if eat!(self, '(') {
+            self.include_in_expr(true).parse_expr()?;
+            expect!(self, ')');
+        }
+
+        Ok(())
+    }
+
+    /// `opaque type T: Super = Type;`, after `opaque`.
+    ///
+    /// The underlying type of an opaque type is hidden outside of the file
+    /// declaring it, and `declare opaque type` has no underlying type at all.
+    /// We don't check types, so it's parsed as an alias of the underlying
+    /// type, the supertype or `unknown`, in that order.
+    pub(super) fn parse_flow_opaque_type_alias_decl(
+        &mut self,
+        start: BytePos,
+    ) -> PResult<TsTypeAliasDecl> {
+        debug_assert!(self.input.syntax().flow());
+
+        expect!(self, "type");
+        let id = self.parse_ident_name()?;
+        let type_params = self.try_parse_ts_type_params()?;
+        let supertype = self.eat_then_parse_ts_type(&tok!(':'))?;
+        let underlying = if self.ctx().in_declare {
+            self.eat_then_parse_ts_type(&tok!('='))?
+        } else {
+            Some(self.expect_then_parse_ts_type(&tok!('='), "=")?)
+        };
+        expect!(self, ';');
+
+        let type_ann = underlying.or(supertype).unwrap_or_else(|| {
+            Box::new(TsType::TsKeywordType(TsKeywordType {
+                span: id.span(),
+                kind: TsKeywordTypeKind::TsUnknownKeyword,
+            }))
+        });
+
+        Ok(TsTypeAliasDecl {
+            span: span!(self, start),
+            declare: false,
+            id,
+            type_params,
+            type_ann,
+        })
+    }
+
+    /// `(expr: T)`, after `expr`.
+    ///
+    /// Type casts are represented as `expr as T`. If the parenthesized
+    /// expression turns out to be the parameters of an arrow function, they
+    /// are converted back to type annotations by
+    /// [Parser::reparse_expr_as_pat].
+    pub(super) fn parse_flow_type_cast(&mut self, expr: Box<Expr>) -> PResult<Box<Expr>> {
+        debug_assert!(self.input.syntax().flow());
+
+        let start = expr.span().lo();
+        let type_ann = self.parse_ts_type_ann(/* eat_colon */ true, cur_pos!(self))?;
+
+        Ok(Box::new(Expr::TsAs(TsAsExpr {
+            span: span!(self, start),
+            expr,
+            type_ann: type_ann.type_ann,
+        })))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_parser, FlowConfig, Syntax};
+    use swc_ecma_ast::*;
+
+    fn flow() -> Syntax {
+        Syntax::Flow(FlowConfig {
+            jsx: true,
+            ..Default::default()
+        })
+    }
+
+    fn parse(src: &'static str) -> Module {
+        test_parser(src, flow(), |p| p.parse_module())
+    }
+
+    #[test]
+    fn annotations() {
+        parse(
+            "function foo(a: number, b?: string, ...rest: Array<mixed>): void {}
+const bar = (x: number): number => x;
+let baz: { a: number, b: string } = { a: 1, b: '' };",
+        );
+    }
+
+    #[test]
+    fn generic_call() {
+        parse("const a = foo<string>(1); new Bar<number>();");
+    }
+
+    #[test]
+    fn type_alias() {
+        parse("type A = number; type B<T> = { value: T }; export type C = A | B<A>;");
+    }
+
+    #[test]
+    fn opaque_type() {
+        let m = parse(
+            "opaque type A = number;
+opaque type B: A = number;
+export opaque type C = string;
+declare opaque type D;
+declare opaque type E: A;",
+        );
+
+        assert_eq!(m.body.len(), 5);
+        for item in &m.body {
+            match item {
+                ModuleItem::Stmt(Stmt::Decl(Decl::TsTypeAlias(..)))
+                | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    decl: Decl::TsTypeAlias(..),
+                    ..
+                })) => {}
+                _ => panic!("expected a type alias, got {:?}", item),
+            }
+        }
+    }
+
+    #[test]
+    fn declare() {
+        parse(
+            "declare var a: number;
+declare function b(x: number): string;
+declare class C { m(): void }
+declare type D = number;
+declare module 'e' { declare export function f(): void; }",
+        );
+    }
+
+    #[test]
+    fn declare_export() {
+        let m = parse("declare export function f(): void; declare export class C {}");
+
+        for item in &m.body {
+            match item {
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    decl: Decl::Fn(FnDecl { declare: true, .. }),
+                    ..
+                }))
+                | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    decl: Decl::Class(ClassDecl { declare: true, .. }),
+                    ..
+                })) => {}
+                _ => panic!("expected a declared export, got {:?}", item),
+            }
+        }
+    }
+
+    #[test]
+    fn import_type() {
+        let m = parse(
+            "import type A from 'a';
+import typeof B from 'b';
+import { type C, typeof D } from 'c';
+import { type E, f } from 'e';",
+        );
+
+        let type_only = m
+            .body
+            .iter()
+            .map(|item| match item {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(i)) => (i.type_only, i.specifiers.len()),
+                _ => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(type_only, vec![(true, 1), (true, 1), (true, 0), (false, 1)]);
+    }
+
+    #[test]
+    fn maybe_type() {
+        parse("let a: ?string = null; function f(x: ?Array<?number>): ?string {}");
+    }
+
+    #[test]
+    fn exact_object_type() {
+        parse("type A = {| a: number, b: string |}; type B = {||}; type C = {| a: 1 | 2 |};");
+    }
+
+    #[test]
+    fn indexer() {
+        let m = parse(
+            "type A = { [string]: number };
+type B = { [key: string]: number, a: 1 };
+type C = {| +[string]: number |};
+interface D { [number]: string }",
+        );
+
+        match &m.body[0] {
+            ModuleItem::Stmt(Stmt::Decl(Decl::TsTypeAlias(a))) => match &*a.type_ann {
+                TsType::TsTypeLit(TsTypeLit { members, .. }) => match &members[0] {
+                    TsTypeElement::TsIndexSignature(TsIndexSignature { params, .. }) => {
+                        assert_eq!(params.len(), 1)
+                    }
+                    member => panic!("expected an indexer, got {:?}", member),
+                },
+                ty => panic!("expected an object type, got {:?}", ty),
+            },
+            item => panic!("expected a type alias, got {:?}", item),
+        }
+    }
+
+    #[test]
+    fn object_type_spread() {
+        let m = parse(
+            "type A = { ...B, c: T };
+type D = { ...B, ...C };
+type E = {| ...B |};
+type F = { a: T, ... };
+type G = { ... };",
+        );
+
+        match &m.body[0] {
+            ModuleItem::Stmt(Stmt::Decl(Decl::TsTypeAlias(a))) => match &*a.type_ann {
+                TsType::TsUnionOrIntersectionType(
+                    TsUnionOrIntersectionType::TsIntersectionType(i),
+                ) => assert_eq!(i.types.len(), 2),
+                ty => panic!("expected an intersection, got {:?}", ty),
+            },
+            item => panic!("expected a type alias, got {:?}", item),
+        }
+    }
+
+    #[test]
+    fn unnamed_fn_type_params() {
+        let m = parse(
+            "type A = (string, ?number) => void;
+type B = (a: string, b?: number, ...Array<T>) => void;
+type C = { m(string): void, (number): string };
+type D = (A | B)[];
+type E = (string) => (number) => void;",
+        );
+
+        match &m.body[0] {
+            ModuleItem::Stmt(Stmt::Decl(Decl::TsTypeAlias(a))) => match &*a.type_ann {
+                TsType::TsFnOrConstructorType(TsFnOrConstructorType::TsFnType(f)) => {
+                    assert_eq!(f.params.len(), 2);
+                    match &f.params[1] {
+                        TsFnParam::Ident(BindingIdent {
+                            type_ann: Some(..), ..
+                        }) => {}
+                        param => panic!("expected a typed parameter, got {:?}", param),
+                    }
+                }
+                ty => panic!("expected a function type, got {:?}", ty),
+            },
+            item => panic!("expected a type alias, got {:?}", item),
+        }
+    }
+
+    #[test]
+    fn variance() {
+        parse("type A<+T, -U> = { +a: T, -b: U };");
+    }
+
+    #[test]
+    fn existential_type() {
+        parse("const a: Array<*> = [];");
+    }
+
+    #[test]
+    fn predicate() {
+        parse(
+            "function isString(x: mixed): boolean %checks { return typeof x === 'string'; }
+declare function isNumber(x: mixed): boolean %checks(typeof x === 'number');",
+        );
+    }
+
+    #[test]
+    fn type_cast() {
+        let m = parse("const a = (b: any); const c = ((d: any): string);");
+
+        match &m.body[0] {
+            ModuleItem::Stmt(Stmt::Decl(Decl::Var(v))) => match v.decls[0].init.as_deref() {
+                Some(Expr::Paren(ParenExpr { expr, .. })) => match &**expr {
+                    Expr::TsAs(..) => {}
+                    _ => panic!("expected a type cast, got {:?}", expr),
+                },
+                init => panic!("expected a parenthesized expression, got {:?}", init),
+            },
+            item => panic!("expected a variable declaration, got {:?}", item),
+        }
+    }
+
+    #[test]
+    fn arrow_params_are_not_type_casts() {
+        let m = parse("const f = (a: number, { b }: Obj, c: string = '') => a;");
+
+        match &m.body[0] {
+            ModuleItem::Stmt(Stmt::Decl(Decl::Var(v))) => match v.decls[0].init.as_deref() {
+                Some(Expr::Arrow(ArrowExpr { params, .. })) => {
+                    assert_eq!(params.len(), 3);
+                    match &params[0] {
+                        Pat::Ident(BindingIdent {
+                            type_ann: Some(..), ..
+                        }) => {}
+                        _ => panic!("expected a typed parameter, got {:?}", params[0]),
+                    }
+                    match &params[1] {
+                        Pat::Object(ObjectPat {
+                            type_ann: Some(..), ..
+                        }) => {}
+                        _ => panic!("expected a typed parameter, got {:?}", params[1]),
+                    }
+                    match &params[2] {
+                        Pat::Assign(AssignPat { left, .. }) => match &**left {
+                            Pat::Ident(BindingIdent {
+                                type_ann: Some(..), ..
+                            }) => {}
+                            _ => panic!("expected a typed parameter, got {:?}", left),
+                        },
+                        _ => panic!("expected a parameter with a default, got {:?}", params[2]),
+                    }
+                }
+                init => panic!("expected an arrow function, got {:?}", init),
+            },
+            item => panic!("expected a variable declaration, got {:?}", item),
+        }
+    }
+
+    #[test]
+    fn jsx() {
+        parse("const a = <div>{(b: any)}</div>;");
+    }
+}
@@ -0,0 +1,637 @@
+//! Incremental reparsing, for editors.
+
+use super::{stmt::IsDirective, Context, PResult, Parser, Tokens};
+use crate::{error::Error, lexer::Lexer, JscTarget, StringInput, Syntax};
+use std::mem::replace;
+use swc_atoms::JsWord;
+use swc_common::{sync::Lrc, BytePos, SourceFile, Span, Spanned};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{noop_visit_mut_type, VisitMut, VisitMutWith};
+
+/// Replaces `span` of a source file with `text`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    /// The range of the old source file to replace.
+    pub span: Span,
+    pub text: String,
+}
+
+/// Returned by [reparse_module].
+#[derive(Debug)]
+pub struct Reparsed {
+    /// The edited source file.
+    ///
+    /// It has the same `start_pos` as the old one, so that nodes before the
+    /// edit keep their spans. It's not added to any
+    /// [SourceMap](swc_common::SourceMap).
+    pub fm: Lrc<SourceFile>,
+    pub module: PResult<Module>,
+    /// Recoverable errors. See [Parser::take_errors].
+    pub errors: Vec<Error>,
+    /// `false` if the whole file is parsed again.
+    pub incremental: bool,
+}
+
+/// Applies `edit` to `fm`, and parses the edited file by reusing `module`,
+/// which should be parsed from `fm` by [Parser::parse_module] (or
+/// [Parser::parse_typescript_module] for typescript and flow).
+///
+/// Only the statements touched by the edit are parsed again, in the innermost
+/// function body containing the edit or at the top level. Other nodes are
+/// moved to the new module, and the spans of nodes after the edit are
+/// shifted.
+///
+/// The whole file is parsed again if it's not possible. For example, if the
+/// edited statements have a syntax error, if they may be merged with the
+/// statements around them by the automatic semicolon insertion, or if they
+/// contain directives.
+///
+/// Comments are not collected. Recoverable errors of the unchanged statements
+/// are not reported again.
+pub fn reparse_module(
+    syntax: Syntax,
+    target: JscTarget,
+    mut module: Module,
+    fm: &SourceFile,
+    edit: &TextEdit,
+) -> Reparsed {
+    let (lo, hi) = (edit.span.lo(), edit.span.hi());
+    assert!(
+        fm.start_pos <= lo && lo <= hi && hi <= fm.end_pos,
+        "the edit {:?} is out of the source file",
+        edit.span
+    );
+
+    let mut src = String::clone(&fm.src);
+    src.replace_range(
+        (lo - fm.start_pos).0 as usize..(hi - fm.start_pos).0 as usize,
+        &edit.text,
+    );
+    let fm = Lrc::new(SourceFile::new(
+        fm.name.clone(),
+        fm.name_was_remapped,
+        fm.unmapped_path.clone().unwrap_or_else(|| fm.name.clone()),
+        src,
+        fm.start_pos,
+    ));
+
+    let mut reparser = Reparser {
+        syntax,
+        target,
+        fm: &fm,
+        edit: edit.span,
+        delta: edit.text.len() as i64 - i64::from(hi.0 - lo.0),
+        ctx: Context {
+            module: true,
+            // See `parse_typescript_module`.
+            strict: !syntax.typescript(),
+            ..Default::default()
+        },
+        blocked: false,
+        private_names: None,
+        shebang: module.shebang.is_some(),
+        region: None,
+    };
+    module.visit_mut_with(&mut reparser);
+
+    let region = match reparser.region.take() {
+        Some(region) => Some(region),
+        None => {
+            let bounds = Span::new(fm.start_pos, fm.end_pos, Default::default());
+            reparser
+                .reparse(&module.body, bounds)
+                .map(|(region, items)| Region {
+                    items: Items::ModuleItems(items),
+                    ..region
+                })
+        }
+    };
+
+    let region = match region {
+        Some(region) => region,
+        None => {
+            let lexer = Lexer::new(syntax, target, StringInput::from(&*fm), None);
+            let mut p = Parser::new_from(lexer);
+            let module = if syntax.typescript() {
+                p.parse_typescript_module()
+            } else {
+                p.parse_module()
+            };
+
+            return Reparsed {
+                errors: p.take_errors(),
+                fm,
+                module,
+                incremental: false,
+            };
+        }
+    };
+
+    let len = module.body.len();
+    module.visit_mut_with(&mut ShiftSpans {
+        from: region.span.hi(),
+        delta: reparser.delta,
+    });
+    match region.items {
+        Items::ModuleItems(items) => {
+            if region.start == 0 {
+                module.span = module.span.with_lo(region.tokens.lo());
+            }
+            if region.end == len {
+                module.span = module.span.with_hi(region.tokens.hi());
+            }
+            module.body.splice(region.start..region.end, items);
+        }
+        Items::Stmts(items) => {
+            let body = region.body.unwrap();
+            module.visit_mut_with(&mut Splice {
+                body: body.with_hi(reparser.shift(body.hi())),
+                start: region.start,
+                end: region.end,
+                items: Some(items),
+            });
+        }
+    }
+
+    Reparsed {
+        fm,
+        module: Ok(module),
+        errors: vec![],
+        incremental: true,
+    }
+}
+
+/// Statements parsed again.
+struct Region {
+    /// The function body containing the statements, or [None] for the top
+    /// level.
+    body: Option<Span>,
+    /// Indices of the replaced statements.
+    start: usize,
+    end: usize,
+    /// The replaced range of the old file, which includes the whitespaces and
+    /// comments around the statements.
+    span: Span,
+    /// The range of tokens in the new file.
+    tokens: Span,
+    items: Items,
+}
+
+enum Items {
+    Stmts(Vec<Stmt>),
+    ModuleItems(Vec<ModuleItem>),
+}
+
+struct Reparser<'a> {
+    syntax: Syntax,
+    target: JscTarget,
+    /// The new file.
+    fm: &'a SourceFile,
+    edit: Span,
+    /// Difference of the lengths.
+    delta: i64,
+    /// Context of the innermost function containing the edit.
+    ctx: Context,
+    /// If true, the parser uses a context we don't know, e.g. in
+    /// conditional expressions.
+    blocked: bool,
+    /// Private names declared by the classes containing the edit.
+    private_names: Option<Vec<JsWord>>,
+    /// True if the module has a shebang, which is a part of its span.
+    shebang: bool,
+    region: Option<Region>,
+}
+
+impl Reparser<'_> {
+    fn shift(&self, pos: BytePos) -> BytePos {
+        BytePos((i64::from(pos.0) + self.delta) as u32)
+    }
+
+    /// Returns true if `span` contains the edit and the edit does not touch
+    /// the first and last characters of `span`, like braces of a block.
+    fn is_inside(&self, span: Span) -> bool {
+        span.lo() < self.edit.lo() && self.edit.hi() < span.hi()
+    }
+
+    /// Source text of the new file.
+    fn text(&self, span: Span) -> &str {
+        let start = self.fm.start_pos;
+        &self.fm.src[(span.lo() - start).0 as usize..(span.hi() - start).0 as usize]
+    }
+
+    fn with_blocked<F>(&mut self, op: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        let blocked = replace(&mut self.blocked, true);
+        op(self);
+        self.blocked = blocked;
+    }
+
+    fn reparse_fn_body(&mut self, ctx: Context, body: &mut BlockStmt) {
+        let strict = self.ctx.strict || body.stmts.first().map_or(false, |s| s.is_use_strict());
+        let ctx = replace(&mut self.ctx, Context { strict, ..ctx });
+
+        body.visit_mut_children_with(self);
+        if self.region.is_none() {
+            // Inside of braces
+            let bounds = Span::new(
+                body.span.lo() + BytePos(1),
+                body.span.hi() - BytePos(1),
+                Default::default(),
+            );
+            self.region = self
+                .reparse(&body.stmts, bounds)
+                .map(|(region, items)| Region {
+                    body: Some(body.span),
+                    items: Items::Stmts(items),
+                    ..region
+                });
+        }
+
+        self.ctx = ctx;
+    }
+
+    /// Parses the items of `list` touching the edit again.
+    ///
+    /// `bounds` is the range of the old file containing the list.
+    fn reparse<T>(&self, list: &[T], bounds: Span) -> Option<(Region, Vec<T>)>
+    where
+        T: ListItem,
+    {
+        let edit = self.edit;
+        let start = list
+            .iter()
+            .position(|item| edit.lo() <= item.span().hi())
+            .unwrap_or_else(|| list.len());
+        let end = list
+            .iter()
+            .rposition(|item| item.span().lo() <= edit.hi())
+            .map_or(0, |i| i + 1)
+            .max(start);
+
+        let lo = match start {
+            0 => bounds.lo(),
+            _ => list[start - 1].span().hi(),
+        };
+        let hi = match list.get(end) {
+            Some(item) => item.span().lo(),
+            None => bounds.hi(),
+        };
+
+        if start > 0 && !list[start - 1].is_terminated(self.text(list[start - 1].span())) {
+            return None;
+        }
+        // Directives change the context.
+        if start == 0 && list.first().map_or(false, ListItem::is_str) {
+            return None;
+        }
+        if T::TOP_LEVEL && start == 0 && self.shebang {
+            return None;
+        }
+
+        let new_hi = self.shift(hi);
+        let lexer = Lexer::new(
+            self.syntax,
+            self.target,
+            StringInput::new(
+                self.text(Span::new(lo, new_hi, Default::default())),
+                lo,
+                new_hi,
+            ),
+            None,
+        );
+        let mut p = Parser::new_from(lexer);
+        p.set_ctx(self.ctx);
+        if self.private_names.is_some() {
+            p.brand_checks.push(vec![]);
+        }
+
+        let has_tokens = p.input.cur().is_some();
+        let tokens_lo = cur_pos!(p);
+        let items = T::parse_list(&mut p).ok()?;
+        let tokens_hi = last_pos!(p);
+
+        if !p.take_errors().is_empty() {
+            return None;
+        }
+        if let Some(declared) = &self.private_names {
+            let used = p.brand_checks.pop().unwrap_or_default();
+            if used.iter().any(|name| !declared.contains(&name.id.sym)) {
+                return None;
+            }
+        }
+
+        if start == 0 && items.first().map_or(false, ListItem::is_str) {
+            return None;
+        }
+        if end < list.len() {
+            if let Some(last) = items.last() {
+                if !last.is_terminated(self.text(last.span())) {
+                    return None;
+                }
+            }
+        }
+        // The span of a module starts at the first token and ends at the last
+        // one.
+        if T::TOP_LEVEL && !has_tokens && (start == 0 || end == list.len()) {
+            return None;
+        }
+
+        Some((
+            Region {
+                body: None,
+                start,
+                end,
+                span: Span::new(lo, hi, Default::default()),
+                tokens: Span::new(tokens_lo, tokens_hi, Default::default()),
+                items: Items::Stmts(vec![]),
+            },
+            items,
+        ))
+    }
+}
+
+impl VisitMut for Reparser<'_> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_arrow_expr(&mut self, f: &mut ArrowExpr) {
+        match &mut f.body {
+            BlockStmtOrExpr::BlockStmt(body) if self.is_inside(body.span) && !self.blocked => {
+                let ctx = Context {
+                    in_async: f.is_async,
+                    in_generator: f.is_generator,
+                    ..fn_body_ctx(self.ctx)
+                };
+                self.reparse_fn_body(ctx, body)
+            }
+            BlockStmtOrExpr::Expr(body) => body.visit_mut_with(self),
+            _ => {}
+        }
+    }
+
+    fn visit_mut_class(&mut self, class: &mut Class) {
+        if class.span.lo() > self.edit.lo() || self.edit.hi() > class.span.hi() {
+            return;
+        }
+
+        let mut names = self.private_names.clone().unwrap_or_default();
+        names.extend(class.body.iter().filter_map(|member| match member {
+            ClassMember::PrivateProp(PrivateProp { key, .. })
+            | ClassMember::PrivateMethod(PrivateMethod { key, .. }) => Some(key.id.sym.clone()),
+            _ => None,
+        }));
+        let private_names = replace(&mut self.private_names, Some(names));
+        // Classes are always strict.
+        let ctx = replace(
+            &mut self.ctx,
+            Context {
+                strict: true,
+                ..self.ctx
+            },
+        );
+
+        class.visit_mut_children_with(self);
+
+        self.ctx = ctx;
+        self.private_names = private_names;
+    }
+
+    fn visit_mut_cond_expr(&mut self, e: &mut CondExpr) {
+        self.with_blocked(|v| e.visit_mut_children_with(v));
+    }
+
+    fn visit_mut_constructor(&mut self, c: &mut Constructor) {
+        if let Some(body) = &mut c.body {
+            if self.is_inside(body.span) && !self.blocked {
+                let ctx = fn_body_ctx(self.ctx);
+                self.reparse_fn_body(ctx, body)
+            }
+        }
+    }
+
+    fn visit_mut_decorator(&mut self, d: &mut Decorator) {
+        self.with_blocked(|v| d.visit_mut_children_with(v));
+    }
+
+    fn visit_mut_for_in_stmt(&mut self, s: &mut ForInStmt) {
+        self.with_blocked(|v| s.left.visit_mut_with(v));
+        s.right.visit_mut_with(self);
+        s.body.visit_mut_with(self);
+    }
+
+    fn visit_mut_for_of_stmt(&mut self, s: &mut ForOfStmt) {
+        self.with_blocked(|v| s.left.visit_mut_with(v));
+        s.right.visit_mut_with(self);
+        s.body.visit_mut_with(self);
+    }
+
+    fn visit_mut_for_stmt(&mut self, s: &mut ForStmt) {
+        // `in` is not an operator in `init`.
+        self.with_blocked(|v| s.init.visit_mut_with(v));
+        s.test.visit_mut_with(self);
+        s.update.visit_mut_with(self);
+        s.body.visit_mut_with(self);
+    }
+
+    fn visit_mut_function(&mut self, f: &mut Function) {
+        if let Some(body) = &mut f.body {
+            if self.is_inside(body.span) && !self.blocked {
+                let ctx = Context {
+                    in_async: f.is_async,
+                    in_generator: f.is_generator,
+                    ..fn_body_ctx(self.ctx)
+                };
+                self.reparse_fn_body(ctx, body)
+            }
+        }
+    }
+
+    fn visit_mut_getter_prop(&mut self, f: &mut GetterProp) {
+        if let Some(body) = &mut f.body {
+            if self.is_inside(body.span) && !self.blocked {
+                let ctx = fn_body_ctx(self.ctx);
+                self.reparse_fn_body(ctx, body)
+            }
+        }
+    }
+
+    fn visit_mut_jsx_element(&mut self, e: &mut JSXElement) {
+        self.with_blocked(|v| e.visit_mut_children_with(v));
+    }
+
+    fn visit_mut_jsx_fragment(&mut self, e: &mut JSXFragment) {
+        self.with_blocked(|v| e.visit_mut_children_with(v));
+    }
+
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        let edit = self.edit;
+        for item in items {
+            let span = item.span();
+            if span.lo() <= edit.lo() && edit.hi() <= span.hi() {
+                item.visit_mut_with(self);
+            }
+        }
+    }
+
+    fn visit_mut_setter_prop(&mut self, f: &mut SetterProp) {
+        if let Some(body) = &mut f.body {
+            if self.is_inside(body.span) && !self.blocked {
+                let ctx = fn_body_ctx(self.ctx);
+                self.reparse_fn_body(ctx, body)
+            }
+        }
+    }
+
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        let edit = self.edit;
+        for stmt in stmts {
+            let span = stmt.span();
+            if span.lo() <= edit.lo() && edit.hi() <= span.hi() {
+                stmt.visit_mut_with(self);
+            }
+        }
+    }
+
+    fn visit_mut_switch_case(&mut self, c: &mut SwitchCase) {
+        self.with_blocked(|v| c.test.visit_mut_with(v));
+        c.cons.visit_mut_with(self);
+    }
+}
+
+/// See `parse_fn_body`.
+fn fn_body_ctx(ctx: Context) -> Context {
+    Context {
+        in_function: true,
+        is_break_allowed: false,
+        is_continue_allowed: false,
+        in_async: false,
+        in_generator: false,
+        ..ctx
+    }
+}
+
+trait ListItem: Spanned + IsDirective + Sized {
+    /// True for items of a module.
+    const TOP_LEVEL: bool;
+
+    fn parse_list<I: Tokens>(p: &mut Parser<I>) -> PResult<Vec<Self>>;
+
+    /// Returns true if the tokens after the item can't be a part of it.
+    ///
+    /// `text` is the source text of the item.
+    fn is_terminated(&self, text: &str) -> bool;
+
+    /// Returns true if the item may be a directive.
+    fn is_str(&self) -> bool {
+        match self.as_ref() {
+            Some(Stmt::Expr(ExprStmt { expr, .. })) => matches!(**expr, Expr::Lit(Lit::Str(..))),
+            _ => false,
+        }
+    }
+}
+
+impl ListItem for Stmt {
+    const TOP_LEVEL: bool = false;
+
+    fn parse_list<I: Tokens>(p: &mut Parser<I>) -> PResult<Vec<Self>> {
+        p.parse_block_body(false, Self::TOP_LEVEL, None)
+    }
+
+    fn is_terminated(&self, text: &str) -> bool {
+        if text.ends_with(';') {
+            return true;
+        }
+        if !text.ends_with('}') {
+            return false;
+        }
+
+        match self {
+            Stmt::Block(..) | Stmt::Try(..) | Stmt::Switch(..) => true,
+            Stmt::Decl(decl) => is_block_like_decl(decl),
+            Stmt::If(IfStmt { alt: Some(s), .. })
+            | Stmt::If(IfStmt { cons: s, .. })
+            | Stmt::Labeled(LabeledStmt { body: s, .. })
+            | Stmt::For(ForStmt { body: s, .. })
+            | Stmt::ForIn(ForInStmt { body: s, .. })
+            | Stmt::ForOf(ForOfStmt { body: s, .. })
+            | Stmt::While(WhileStmt { body: s, .. })
+            | Stmt::With(WithStmt { body: s, .. }) => s.is_terminated(text),
+            _ => false,
+        }
+    }
+}
+
+impl ListItem for ModuleItem {
+    const TOP_LEVEL: bool = true;
+
+    fn parse_list<I: Tokens>(p: &mut Parser<I>) -> PResult<Vec<Self>> {
+        p.parse_block_body(false, Self::TOP_LEVEL, None)
+    }
+
+    fn is_terminated(&self, text: &str) -> bool {
+        match self {
+            ModuleItem::Stmt(stmt) => stmt.is_terminated(text),
+            _ if text.ends_with(';') => true,
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. })) => {
+                text.ends_with('}') && is_block_like_decl(decl)
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(..)) => text.ends_with('}'),
+            _ => false,
+        }
+    }
+}
+
+/// Returns true if `decl` ends with a block.
+fn is_block_like_decl(decl: &Decl) -> bool {
+    matches!(
+        decl,
+        Decl::Fn(..)
+            | Decl::Class(..)
+            | Decl::TsInterface(..)
+            | Decl::TsEnum(..)
+            | Decl::TsModule(..)
+    )
+}
+
+struct ShiftSpans {
+    from: BytePos,
+    delta: i64,
+}
+
+impl VisitMut for ShiftSpans {
+    fn visit_mut_span(&mut self, span: &mut Span) {
+        let shift = |pos: BytePos| {
+            if pos >= self.from {
+                BytePos((i64::from(pos.0) + self.delta) as u32)
+            } else {
+                pos
+            }
+        };
+
+        *span = Span::new(shift(span.lo()), shift(span.hi()), span.ctxt());
+    }
+}
+
+/// Replaces statements of a function body.
+struct Splice {
+    body: Span,
+    start: usize,
+    end: usize,
+    items: Option<Vec<Stmt>>,
+}
+
+impl VisitMut for Splice {
+    noop_visit_mut_type!();
+
+    fn visit_mut_block_stmt(&mut self, block: &mut BlockStmt) {
+        if block.span != self.body {
+            block.visit_mut_children_with(self);
+            return;
+        }
+
+        if let Some(items) = self.items.take() {
+            block.stmts.splice(self.start..self.end, items);
+        }
+    }
+}
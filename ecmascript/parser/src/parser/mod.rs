@@ -1,6 +1,9 @@
 #![allow(dead_code, unused_variables)]
 #![deny(non_snake_case)]
-pub use self::input::{Capturing, Tokens, TokensInput};
+pub use self::{
+    incremental::{reparse_module, Reparsed, TextEdit},
+    input::{Capturing, Tokens, TokensInput},
+};
 use self::{input::Buffer, util::ParseObject};
 use crate::{
     error::SyntaxError,
@@ -22,11 +25,14 @@ use test::Bencher;
 mod macros;
 mod class_and_fn;
 mod expr;
+mod flow;
 mod ident;
+mod incremental;
 pub mod input;
 mod jsx;
 mod object;
 mod pat;
+mod recovery;
 mod stmt;
 #[cfg(test)]
 mod tests;
@@ -42,6 +48,9 @@ pub struct Parser<I: Tokens> {
     /// [false] while backtracking
     emit_err: bool,
     state: State,
+    /// Private names used by brand checks (`#x in obj`), for each enclosing
+    /// class. The innermost class is the last one.
+    brand_checks: Vec<Vec<PrivateName>>,
     input: Buffer<I>,
 }
 
@@ -63,6 +72,7 @@ impl<I: Tokens> Parser<I> {
         Parser {
             emit_err: true,
             state: Default::default(),
+            brand_checks: vec![],
             input: Buffer::new(input),
         }
     }
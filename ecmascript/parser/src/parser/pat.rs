@@ -511,6 +511,45 @@ impl<'a, I: Tokens> Parser<I> {
         // In dts, we do not reparse.
         debug_assert!(!self.input.syntax().dts());
 
+        // Flow type casts in parameters of arrow functions are type annotations.
+        let expr = match *expr {
+            Expr::TsAs(TsAsExpr {
+                span: cast_span,
+                expr,
+                type_ann: ty,
+            }) if self.input.syntax().flow() && pat_ty == PatType::BindingPat => {
+                let mut pat = self.reparse_expr_as_pat_inner(pat_ty, expr)?;
+
+                match pat {
+                    Pat::Array(ArrayPat {
+                        ref mut type_ann,
+                        ref mut span,
+                        ..
+                    })
+                    | Pat::Ident(BindingIdent {
+                        ref mut type_ann,
+                        id: Ident { ref mut span, .. },
+                        ..
+                    })
+                    | Pat::Object(ObjectPat {
+                        ref mut type_ann,
+                        ref mut span,
+                        ..
+                    }) => {
+                        *span = cast_span;
+                        *type_ann = Some(TsTypeAnn {
+                            span: ty.span(),
+                            type_ann: ty,
+                        });
+                    }
+                    _ => self.emit_err(cast_span, SyntaxError::InvalidPat),
+                }
+
+                return Ok(pat);
+            }
+            expr => Box::new(expr),
+        };
+
         let span = expr.span();
 
         if pat_ty == PatType::AssignPat {
@@ -956,7 +995,11 @@ mod tests {
                         type_ann: None,
                         span,
                         left: Box::new(Pat::Ident(ident("a").into())),
-                        right: Box::new(Expr::Lit(Lit::Num(Number { span, value: 1.0 })))
+                        right: Box::new(Expr::Lit(Lit::Num(Number {
+                            span,
+                            value: 1.0,
+                            raw: Some("1".into())
+                        })))
                     }))
                 ],
                 type_ann: None
@@ -1034,7 +1077,11 @@ mod tests {
                 props: vec![ObjectPatProp::Assign(AssignPatProp {
                     span,
                     key: ident("prop"),
-                    value: Some(Box::new(Expr::Lit(Lit::Num(Number { span, value: 10.0 }))))
+                    value: Some(Box::new(Expr::Lit(Lit::Num(Number {
+                        span,
+                        value: 10.0,
+                        raw: Some("10".into())
+                    }))))
                 })]
             })
         );
@@ -1071,7 +1118,11 @@ mod tests {
                             prop(
                                 PropName::Ident(ident("$")),
                                 "num",
-                                Expr::Lit(Lit::Num(Number { span, value: 10.0 }))
+                                Expr::Lit(Lit::Num(Number {
+                                    span,
+                                    value: 10.0,
+                                    raw: Some("10".into())
+                                }))
                             ),
                             prop(
                                 PropName::Str(Str {
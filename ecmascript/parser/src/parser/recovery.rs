@@ -0,0 +1,197 @@
+//! Error recovery. See [Syntax::error_recovery].
+
+use super::*;
+use crate::token::{AssignOpToken, BinOpToken, Keyword};
+use swc_common::SyntaxContext;
+
+impl<I: Tokens> Parser<I> {
+    /// Returns true if an error should be recorded and recovered from instead
+    /// of being returned.
+    ///
+    /// We never recover while backtracking, as the caller relies on the error.
+    pub(super) fn should_recover(&self) -> bool {
+        self.emit_err && self.syntax().error_recovery()
+    }
+
+    /// Records an error the parser recovered from.
+    ///
+    /// Unlike [Parser::emit_error], this does not depend on
+    /// [Syntax::early_errors], because the error is a hard syntax error.
+    pub(super) fn add_recovered_error(&self, err: Error) {
+        self.input_ref().add_error(err);
+    }
+
+    /// Bumps and records error tokens produced by the lexer.
+    pub(super) fn eat_error_tokens(&mut self) {
+        while let Some(&Token::Error(..)) = self.input.cur() {
+            if let Token::Error(err) = self.input.bump() {
+                self.add_recovered_error(err);
+            }
+        }
+    }
+
+    /// Records `err` and skips to the start of the next statement.
+    ///
+    /// Returns a placeholder statement which spans the skipped tokens.
+    pub(super) fn recover_stmt(&mut self, start: BytePos, err: Error) -> Stmt {
+        self.add_recovered_error(err);
+
+        let span = self.skip_tokens(start, true, |token, had_line_break| {
+            *token == Token::Semi || (had_line_break && starts_stmt(token))
+        });
+        self.input.eat(&Token::Semi);
+
+        Stmt::Expr(ExprStmt {
+            span,
+            expr: Box::new(Expr::Invalid(Invalid { span })),
+        })
+    }
+
+    /// Records `err` and skips to the start of the next class member.
+    ///
+    /// Returns a placeholder property with an invalid key which spans the
+    /// skipped tokens.
+    pub(super) fn recover_class_member(&mut self, start: BytePos, err: Error) -> ClassMember {
+        self.add_recovered_error(err);
+
+        let span = self.skip_tokens(start, true, |token, had_line_break| {
+            *token == Token::Semi || (had_line_break && starts_class_member(token))
+        });
+        self.input.eat(&Token::Semi);
+
+        ClassMember::ClassProp(ClassProp {
+            span,
+            key: Box::new(Expr::Invalid(Invalid { span })),
+            value: None,
+            type_ann: None,
+            is_static: false,
+            decorators: vec![],
+            computed: false,
+            accessibility: None,
+            is_abstract: false,
+            is_optional: false,
+            is_override: false,
+            readonly: false,
+            declare: false,
+            definite: false,
+        })
+    }
+
+    /// Records `err` and skips to the initializer or the end of a variable
+    /// declarator.
+    pub(super) fn recover_var_declarator_name(&mut self, start: BytePos, err: Error) -> Pat {
+        self.add_recovered_error(err);
+
+        let span = self.skip_tokens(start, false, |token, had_line_break| match token {
+            Token::AssignOp(AssignOpToken::Assign) | Token::Comma | Token::Semi => true,
+            _ => had_line_break && starts_stmt(token),
+        });
+
+        Pat::Invalid(Invalid { span })
+    }
+
+    /// Records `err` and skips to the end of a variable declarator.
+    pub(super) fn recover_var_declarator_init(&mut self, start: BytePos, err: Error) -> Box<Expr> {
+        self.add_recovered_error(err);
+
+        let span = self.skip_tokens(start, false, |token, had_line_break| match token {
+            Token::Comma | Token::Semi => true,
+            _ => had_line_break && starts_stmt(token),
+        });
+
+        Box::new(Expr::Invalid(Invalid { span }))
+    }
+
+    /// Skips tokens until `stop` returns true for a token which is not nested
+    /// in brackets, or until a closing bracket which was not opened by the
+    /// skipped tokens. The token is not consumed.
+    ///
+    /// If `force_progress` is true and no token was consumed since `start`, the
+    /// current token is consumed regardless of `stop`, so that a caller which
+    /// loops always makes progress.
+    fn skip_tokens<F>(&mut self, start: BytePos, force_progress: bool, stop: F) -> Span
+    where
+        F: Fn(&Token, bool) -> bool,
+    {
+        let mut depth = 0usize;
+
+        loop {
+            self.eat_error_tokens();
+
+            let had_line_break = self.input.had_line_break_before_cur();
+            let progress = !force_progress || self.input.cur_pos() > start;
+            let should_stop = match self.input.cur() {
+                None => true,
+                Some(Token::LParen)
+                | Some(Token::LBracket)
+                | Some(Token::LBrace)
+                | Some(Token::DollarLBrace) => {
+                    depth += 1;
+                    false
+                }
+                Some(Token::RParen) | Some(Token::RBracket) | Some(Token::RBrace) => {
+                    if depth == 0 {
+                        progress
+                    } else {
+                        depth -= 1;
+                        false
+                    }
+                }
+                Some(token) => depth == 0 && progress && stop(token, had_line_break),
+            };
+            if should_stop {
+                break;
+            }
+
+            self.input.bump();
+        }
+
+        let end = self.input.last_pos();
+        Span::new(start, end.max(start), SyntaxContext::empty())
+    }
+}
+
+/// Returns true if `token` starts a statement when it's the first token of a
+/// line.
+fn starts_stmt(token: &Token) -> bool {
+    match token {
+        Token::Word(Word::Keyword(k)) => match k {
+            Keyword::Break
+            | Keyword::Class
+            | Keyword::Const
+            | Keyword::Continue
+            | Keyword::Debugger
+            | Keyword::Do
+            | Keyword::Export
+            | Keyword::For
+            | Keyword::Function
+            | Keyword::If
+            | Keyword::Import
+            | Keyword::Let
+            | Keyword::Return
+            | Keyword::Switch
+            | Keyword::Throw
+            | Keyword::Try
+            | Keyword::Var
+            | Keyword::While
+            | Keyword::With => true,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Returns true if `token` starts a class member when it's the first token of
+/// a line.
+fn starts_class_member(token: &Token) -> bool {
+    match token {
+        Token::Word(..)
+        | Token::Str { .. }
+        | Token::Num { .. }
+        | Token::LBracket
+        | Token::Hash
+        | Token::At
+        | Token::BinOp(BinOpToken::Mul) => true,
+        _ => false,
+    }
+}
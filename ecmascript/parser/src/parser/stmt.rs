@@ -22,10 +22,25 @@ impl<'a, I: Tokens> Parser<I> {
 
         let mut stmts = vec![];
         while {
+            if self.should_recover() {
+                self.eat_error_tokens();
+            }
             let c = cur!(self, false).ok();
             c != end
         } {
-            let stmt = self.parse_stmt_like(true, top_level)?;
+            let start = cur_pos!(self);
+            let stmt = match self.parse_stmt_like(true, top_level) {
+                Ok(stmt) => stmt,
+                Err(err) if self.should_recover() => {
+                    // Unterminated block
+                    if self.input.cur().is_none() {
+                        self.add_recovered_error(err);
+                        break;
+                    }
+                    self.recover_stmt(start, err).into()
+                }
+                Err(err) => return Err(err),
+            };
             if allow_directives {
                 allow_directives = false;
                 if stmt.is_use_strict() {
@@ -48,7 +63,7 @@ impl<'a, I: Tokens> Parser<I> {
             stmts.push(stmt);
         }
 
-        if end.is_some() {
+        if end.is_some() && self.input.cur().is_some() {
             bump!(self);
         }
 
@@ -77,7 +92,9 @@ impl<'a, I: Tokens> Parser<I> {
         let start = cur_pos!(self);
         let decorators = self.parse_decorators(true)?;
 
-        if is_one_of!(self, "import", "export") {
+        if is_one_of!(self, "import", "export")
+            || (self.input.syntax().flow() && is!(self, "declare") && peeked_is!(self, "export"))
+        {
             return self.handle_import_export(top_level, decorators);
         }
 
@@ -728,7 +745,11 @@ impl<'a, I: Tokens> Parser<I> {
     fn parse_var_declarator(&mut self, for_loop: bool) -> PResult<VarDeclarator> {
         let start = cur_pos!(self);
 
-        let mut name = self.parse_binding_pat_or_ident()?;
+        let mut name = match self.parse_binding_pat_or_ident() {
+            Ok(name) => name,
+            Err(err) if self.should_recover() => self.recover_var_declarator_name(start, err),
+            Err(err) => return Err(err),
+        };
 
         let definite = if self.input.syntax().typescript() {
             match name {
@@ -760,6 +781,8 @@ impl<'a, I: Tokens> Parser<I> {
                 }) => {
                     *type_ann = type_annotation;
                 }
+                // Recovered from an error
+                Pat::Invalid(..) => {}
                 _ => unreachable!("invalid syntax: Pat: {:?}", name),
             }
         }
@@ -767,8 +790,17 @@ impl<'a, I: Tokens> Parser<I> {
         //FIXME: This is wrong. Should check in/of only on first loop.
         let init = if !for_loop || !is_one_of!(self, "in", "of") {
             if eat!(self, '=') {
-                let expr = self.parse_assignment_expr()?;
-                let expr = self.verify_expr(expr)?;
+                let expr_start = cur_pos!(self);
+                let expr = match self
+                    .parse_assignment_expr()
+                    .and_then(|expr| self.verify_expr(expr))
+                {
+                    Ok(expr) => expr,
+                    Err(err) if self.should_recover() => {
+                        self.recover_var_declarator_init(expr_start, err)
+                    }
+                    Err(err) => return Err(err),
+                };
 
                 Some(expr)
             } else {
@@ -778,7 +810,7 @@ impl<'a, I: Tokens> Parser<I> {
                     None
                 } else {
                     match name {
-                        Pat::Ident(..) => None,
+                        Pat::Ident(..) | Pat::Invalid(..) => None,
                         _ => {
                             syntax_error!(self, span!(self, start), SyntaxError::PatVarWithoutInit)
                         }
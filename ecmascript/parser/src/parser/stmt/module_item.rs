@@ -1,4 +1,5 @@
 use super::*;
+use crate::parser::typescript::make_decl_declare;
 
 impl<'a, I: Tokens> Parser<I> {
     #[allow(clippy::cognitive_complexity)]
@@ -75,13 +76,21 @@ impl<'a, I: Tokens> Parser<I> {
             .map(ModuleItem::from);
         }
 
-        let type_only = self.input.syntax().typescript()
+        let mut type_only = if self.input.syntax().typescript()
             && is!(self, "type")
-            && (peeked_is!(self, '{') || !peeked_is!(self, "from") && !peeked_is!(self, ','));
-
-        if type_only {
+            && (peeked_is!(self, '{') || !peeked_is!(self, "from") && !peeked_is!(self, ','))
+        {
             assert_and_bump!(self, "type");
-        }
+            true
+        } else if self.input.syntax().flow() && is!(self, "typeof") {
+            // `import typeof A from 'a'`
+            assert_and_bump!(self, "typeof");
+            true
+        } else {
+            false
+        };
+        // Flow allows `import { type A, typeof B } from 'a'`.
+        let mut has_type_specifier = false;
 
         let mut specifiers = vec![];
 
@@ -115,12 +124,29 @@ impl<'a, I: Tokens> Parser<I> {
                         break;
                     }
 
+                    // We don't have a node for type-only specifiers, so they are dropped.
+                    if self.input.syntax().flow()
+                        && is_one_of!(self, "type", "typeof")
+                        && peeked_is!(self, IdentName)
+                        && !peeked_is!(self, "as")
+                    {
+                        bump!(self);
+                        self.parse_import_specifier()?;
+                        has_type_specifier = true;
+                        continue;
+                    }
+
                     specifiers.push(self.parse_import_specifier()?);
                 }
                 expect!(self, '}');
             }
         }
 
+        // Nothing is imported at runtime.
+        if has_type_specifier && specifiers.is_empty() {
+            type_only = true;
+        }
+
         let src = {
             expect!(self, "from");
             let str_start = cur_pos!(self);
@@ -214,6 +240,28 @@ impl<'a, I: Tokens> Parser<I> {
         Ok(self.with_ctx(ctx).parse_binding_ident()?.id)
     }
 
+    /// `declare export`, which is equivalent to `export declare` in flow.
+    fn parse_flow_declare_export(&mut self, decorators: Vec<Decorator>) -> PResult<ModuleDecl> {
+        debug_assert!(self.input.syntax().flow());
+
+        let start = cur_pos!(self);
+        assert_and_bump!(self, "declare");
+
+        let ctx = Context {
+            in_declare: true,
+            ..self.ctx()
+        };
+        let decl = self.with_ctx(ctx).parse_export(decorators)?;
+
+        Ok(match decl {
+            ModuleDecl::ExportDecl(ExportDecl { decl, .. }) => ModuleDecl::ExportDecl(ExportDecl {
+                span: span!(self, start),
+                decl: make_decl_declare(decl),
+            }),
+            _ => decl,
+        })
+    }
+
     #[allow(clippy::cognitive_complexity)]
     fn parse_export(&mut self, decorators: Vec<Decorator>) -> PResult<ModuleDecl> {
         if !self.ctx().module {
@@ -622,6 +670,9 @@ impl<'a, I: Tokens> StmtLikeParser<'a, ModuleItem> for Parser<I> {
             self.parse_import()?
         } else if is!(self, "export") {
             self.parse_export(decorators).map(ModuleItem::from)?
+        } else if self.input.syntax().flow() && is!(self, "declare") {
+            self.parse_flow_declare_export(decorators)
+                .map(ModuleItem::from)?
         } else {
             unreachable!(
                 "handle_import_export should not be called if current token isn't import nor \
@@ -1,4 +1,4 @@
-use crate::test_parser;
+use crate::{test_parser, EsConfig, Parser, Syntax};
 use swc_ecma_ast::*;
 
 fn program(src: &'static str) -> Program {
@@ -68,3 +68,152 @@ fn parse_program_module_error_01() {
         ",
     );
 }
+
+/// Parses `src` with error recovery and returns the module and the number of
+/// errors.
+fn recover(src: &'static str) -> (Module, usize) {
+    crate::with_test_sess(src, |_, input| {
+        let mut p = Parser::new(
+            Syntax::Es(EsConfig {
+                error_recovery: true,
+                ..Default::default()
+            }),
+            input,
+            None,
+        );
+        let module = p
+            .parse_module()
+            .expect("parse_module() should not fail in recovery mode");
+
+        Ok((module, p.take_errors().len()))
+    })
+    .unwrap()
+}
+
+fn is_invalid_stmt(item: &ModuleItem) -> bool {
+    match item {
+        ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) => expr.is_invalid(),
+        _ => false,
+    }
+}
+
+#[test]
+fn recover_stmt() {
+    let (module, errors) = recover("let a = 1;\nfoo(;\nlet b = 2;");
+
+    assert_ne!(errors, 0);
+    assert_eq!(module.body.len(), 3);
+    assert!(is_invalid_stmt(&module.body[1]));
+    assert!(module.body[2].is_stmt());
+}
+
+#[test]
+fn recover_missing_semi_at_line_break() {
+    let (module, errors) = recover("foo(a b\nconst c = 1;");
+
+    assert_ne!(errors, 0);
+    assert_eq!(module.body.len(), 2);
+    assert!(is_invalid_stmt(&module.body[0]));
+}
+
+#[test]
+fn recover_stray_brace() {
+    let (module, errors) = recover("}\nlet a = 1;");
+
+    assert_ne!(errors, 0);
+    assert_eq!(module.body.len(), 2);
+}
+
+#[test]
+fn recover_var_init() {
+    let (module, errors) = recover("const a = , b = 1;");
+
+    assert_ne!(errors, 0);
+    match &module.body[0] {
+        ModuleItem::Stmt(Stmt::Decl(Decl::Var(v))) => {
+            assert_eq!(v.decls.len(), 2);
+            assert!(v.decls[0].init.as_ref().unwrap().is_invalid());
+        }
+        item => panic!("expected a variable declaration, got {:?}", item),
+    }
+}
+
+#[test]
+fn recover_var_name() {
+    let (module, errors) = recover("const 1 = 2;");
+
+    assert_ne!(errors, 0);
+    match &module.body[0] {
+        ModuleItem::Stmt(Stmt::Decl(Decl::Var(v))) => {
+            assert!(v.decls[0].name.is_invalid());
+            assert!(v.decls[0].init.is_some());
+        }
+        item => panic!("expected a variable declaration, got {:?}", item),
+    }
+}
+
+#[test]
+fn recover_class_member() {
+    let (module, errors) = recover("class A {\n  foo() {}\n  bar(;\n  baz() {}\n}");
+
+    assert_ne!(errors, 0);
+    match &module.body[0] {
+        ModuleItem::Stmt(Stmt::Decl(Decl::Class(c))) => {
+            assert_eq!(c.class.body.len(), 3);
+            match &c.class.body[1] {
+                ClassMember::ClassProp(p) => assert!(p.key.is_invalid()),
+                member => panic!("expected a placeholder, got {:?}", member),
+            }
+        }
+        item => panic!("expected a class declaration, got {:?}", item),
+    }
+}
+
+#[test]
+fn recover_unterminated_block() {
+    let (module, errors) = recover("function foo() {\n  let a = 1;");
+
+    assert_ne!(errors, 0);
+    assert_eq!(module.body.len(), 1);
+}
+
+#[test]
+fn brand_check() {
+    module("class A { #a; static is(obj) { return #a in obj; } }");
+    module("class A { static is(obj) { return #m in obj && #b in obj; } #m() {} #b = 1; }");
+    module("class A { #a; m() { return class { is(obj) { return #a in obj; } }; } }");
+    module("class A { #a; #b; is(a, o) { return a && #a in o || #b in o; } }");
+    module("class A { #a; is(a, o) { return a ? #a in o : !(#a in o); } }");
+}
+
+/// Returns true if `src` fails to parse as a module.
+fn is_module_invalid(src: &'static str) -> bool {
+    crate::with_test_sess(src, |_, input| {
+        let mut p = Parser::new(Default::default(), input, None);
+        let failed = p.parse_module().is_err();
+
+        Ok(failed || !p.take_errors().is_empty())
+    })
+    .unwrap()
+}
+
+#[test]
+fn brand_check_operand() {
+    assert!(is_module_invalid(
+        "class A { #a; is(a, o) { return a + #a in o; } }"
+    ));
+    assert!(is_module_invalid("class A { #a; is(o) { return #a; } }"));
+    assert!(is_module_invalid(
+        "class A { #a; is(o) { return #a + o; } }"
+    ));
+    assert!(!is_module_invalid(
+        "class A { #a; is(a, o) { return a || #a in o; } }"
+    ));
+}
+
+#[test]
+fn brand_check_undeclared() {
+    assert_module_error("class A { is(obj) { return #a in obj; } }");
+    assert_module_error("class A { #a; } class B { is(obj) { return #a in obj; } }");
+    assert_module_error("#a in obj;");
+}
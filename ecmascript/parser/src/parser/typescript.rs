@@ -363,6 +363,7 @@ impl<I: Tokens> Parser<I> {
 
         let start = cur_pos!(self);
 
+        self.eat_flow_variance();
         let name = self.parse_ident_name()?;
         let constraint = self.eat_then_parse_ts_type(&tok!("extends"))?;
         let default = self.eat_then_parse_ts_type(&tok!('='))?;
@@ -542,7 +543,7 @@ impl<I: Tokens> Parser<I> {
     }
 
     /// `tsEatThenParseType`
-    fn eat_then_parse_ts_type(
+    pub(super) fn eat_then_parse_ts_type(
         &mut self,
         token_to_eat: &'static Token,
     ) -> PResult<Option<Box<TsType>>> {
@@ -556,7 +557,7 @@ impl<I: Tokens> Parser<I> {
     }
 
     /// `tsExpectThenParseType`
-    fn expect_then_parse_ts_type(
+    pub(super) fn expect_then_parse_ts_type(
         &mut self,
         token: &'static Token,
         token_str: &'static str,
@@ -603,7 +604,7 @@ impl<I: Tokens> Parser<I> {
                 Lit::Str(s) => TsEnumMemberId::Str(s),
                 _ => unreachable!(),
             })?,
-            Token::Num(v) => {
+            Token::Num { value: v, .. } => {
                 bump!(self);
                 let span = span!(self, start);
 
@@ -841,6 +842,10 @@ impl<I: Tokens> Parser<I> {
             return Ok(true);
         }
 
+        if self.input.syntax().flow() {
+            return Ok(is!(self, '(') && self.ts_look_ahead(|p| p.is_flow_start_of_fn_type())?);
+        }
+
         Ok(is!(self, '(') && self.ts_look_ahead(|p| p.is_ts_unambiguously_start_of_fn_type())?)
     }
 
@@ -1118,7 +1123,7 @@ impl<I: Tokens> Parser<I> {
     fn parse_ts_type_member_semicolon(&mut self) -> PResult<()> {
         debug_assert!(self.input.syntax().typescript());
 
-        if !eat!(self, ',') {
+        if !eat!(self, ',') && !self.is_flow_exact_object_end() {
             expect!(self, ';');
         }
 
@@ -1241,7 +1246,7 @@ impl<I: Tokens> Parser<I> {
             self.with_ctx(ctx).parse_with(|p| {
                 // We check if it's valid for it to be a private name when we push it.
                 let key = match *cur!(p, true)? {
-                    Token::Num(..) | Token::Str { .. } => p.parse_new_expr(),
+                    Token::Num { .. } | Token::Str { .. } => p.parse_new_expr(),
                     _ => p.parse_maybe_private_name().map(|e| match e {
                         Either::Left(e) => {
                             p.emit_err(e.span(), SyntaxError::PrivateNameInInterface);
@@ -1313,7 +1318,7 @@ impl<I: Tokens> Parser<I> {
     }
 
     /// `tsParseTypeMember`
-    fn parse_ts_type_member(&mut self) -> PResult<TsTypeElement> {
+    pub(super) fn parse_ts_type_member(&mut self) -> PResult<TsTypeElement> {
         debug_assert!(self.input.syntax().typescript());
 
         fn into_type_elem(
@@ -1336,7 +1341,11 @@ impl<I: Tokens> Parser<I> {
         }
         // Instead of fullStart, we create a node here.
         let start = cur_pos!(self);
-        let readonly = self.parse_ts_modifier(&["readonly"])?.is_some();
+        let readonly = self.eat_flow_variance() || self.parse_ts_modifier(&["readonly"])?.is_some();
+
+        if self.input.syntax().flow() && is!(self, '[') {
+            return self.parse_flow_indexer(start, readonly).map(From::from);
+        }
 
         let idx = self.try_parse_ts_index_signature(start, readonly, false)?;
         if let Some(idx) = idx {
@@ -1776,6 +1785,10 @@ impl<I: Tokens> Parser<I> {
     fn parse_ts_binding_list_for_signature(&mut self) -> PResult<Vec<TsFnParam>> {
         debug_assert!(self.input.syntax().typescript());
 
+        if self.input.syntax().flow() {
+            return self.parse_flow_fn_type_params();
+        }
+
         let params = self.parse_formal_params()?;
         let mut list = vec![];
 
@@ -1916,7 +1929,7 @@ impl<I: Tokens> Parser<I> {
                 let start = cur_pos!(self);
                 bump!(self);
                 if match *cur!(self, true)? {
-                    Token::Num(..) => false,
+                    Token::Num { .. } => false,
                     _ => true,
                 } {
                     unexpected!(self, "a numeric literal")
@@ -1926,6 +1939,7 @@ impl<I: Tokens> Parser<I> {
                     Lit::Num(num) => TsLit::Number(Number {
                         span: num.span,
                         value: -num.value,
+                        raw: None,
                     }),
                     _ => unreachable!(),
                 };
@@ -1956,8 +1970,14 @@ impl<I: Tokens> Parser<I> {
                 return self.parse_ts_type_query().map(TsType::from).map(Box::new);
             }
 
+            tok!('*') if self.input.syntax().flow() => {
+                return self.parse_flow_existential_type();
+            }
+
             tok!('{') => {
-                return if self.ts_look_ahead(|p| p.is_ts_start_of_mapped_type())? {
+                return if self.input.syntax().flow() {
+                    self.parse_flow_object_type()
+                } else if self.ts_look_ahead(|p| p.is_ts_start_of_mapped_type())? {
                     self.parse_ts_mapped_type().map(TsType::from).map(Box::new)
                 } else {
                     self.parse_ts_type_lit().map(TsType::from).map(Box::new)
@@ -2051,7 +2071,7 @@ impl<I: Tokens> Parser<I> {
     }
 
     /// `tsParseTypeOperatorOrHigher`
-    fn parse_ts_type_operator_or_higher(&mut self) -> PResult<Box<TsType>> {
+    pub(super) fn parse_ts_type_operator_or_higher(&mut self) -> PResult<Box<TsType>> {
         trace_cur!(self, parse_ts_type_operator_or_higher);
         debug_assert!(self.input.syntax().typescript());
 
@@ -2073,7 +2093,9 @@ impl<I: Tokens> Parser<I> {
             None => {
                 trace_cur!(self, parse_ts_type_operator_or_higher__not_operator);
 
-                if is!(self, "infer") {
+                if self.input.syntax().flow() && is!(self, '?') {
+                    self.parse_flow_maybe_type()
+                } else if is!(self, "infer") {
                     self.parse_ts_infer_type().map(TsType::from).map(Box::new)
                 } else {
                     let readonly = self.parse_ts_modifier(&["readonly"])?.is_some();
@@ -2392,6 +2414,18 @@ impl<I: Tokens> Parser<I> {
                 }
             }
 
+            _ if self.input.syntax().flow() && &*value == "opaque" => {
+                if next || is!(self, "type") {
+                    if next {
+                        bump!(self);
+                    }
+                    return self
+                        .parse_flow_opaque_type_alias_decl(start)
+                        .map(From::from)
+                        .map(Some);
+                }
+            }
+
             _ => {}
         }
 
@@ -2525,10 +2559,10 @@ impl<I: Tokens> Parser<I> {
         let ty = parse_constituent_type(self)?;
         trace_cur!(self, parse_ts_union_or_intersection_type__after_first);
 
-        if self.input.is(&operator) {
+        if self.input.is(&operator) && !self.is_flow_exact_object_end() {
             let mut types = vec![ty];
 
-            while self.input.eat(operator) {
+            while !self.is_flow_exact_object_end() && self.input.eat(operator) {
                 trace_cur!(self, parse_ts_union_or_intersection_type__constituent);
 
                 types.push(parse_constituent_type(self)?);
@@ -2592,7 +2626,7 @@ enum SignatureParsingMode {
 }
 
 /// Mark as declare
-fn make_decl_declare(mut decl: Decl) -> Decl {
+pub(super) fn make_decl_declare(mut decl: Decl) -> Decl {
     match decl {
         Decl::Class(ref mut c) => c.declare = true,
         Decl::Fn(ref mut f) => f.declare = true,
@@ -2637,6 +2671,7 @@ mod tests {
                         lit: TsLit::Number(Number {
                             span: DUMMY_SP,
                             value: -1.0,
+                            raw: None,
                         }),
                     })),
                 })));
@@ -2672,6 +2707,7 @@ mod tests {
                             arg: Box::new(Expr::Lit(Lit::Num(Number {
                                 span: DUMMY_SP,
                                 value: 1.0,
+                                raw: Some("1".into()),
                             }))),
                         }))),
                         definite: false,
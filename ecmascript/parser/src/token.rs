@@ -118,9 +118,13 @@ pub enum Token {
     #[kind(starts_expr)]
     Regex(JsWord, JsWord),
 
-    /// TODO: Make Num as enum and separate decimal, binary, ..etc
+    /// Numeric literal.
     #[kind(starts_expr)]
-    Num(f64),
+    Num {
+        value: f64,
+        /// The source text of the literal, e.g. `0xFF` or `1e3`.
+        raw: JsWord,
+    },
 
     #[kind(starts_expr)]
     BigInt(BigIntValue),
@@ -610,7 +614,7 @@ impl Debug for Token {
             Tilde => write!(f, "~")?,
             Str { value, .. } => write!(f, "string literal ({})", value)?,
             Regex(exp, flags) => write!(f, "regexp literal ({}, {})", exp, flags)?,
-            Num(..) => write!(f, "numeric literal")?,
+            Num { .. } => write!(f, "numeric literal")?,
             BigInt(..) => write!(f, "bigint literal")?,
             JSXName { name } => write!(f, "jsx name ({})", name)?,
             JSXText { raw } => write!(f, "jsx text ({})", raw)?,
@@ -49,6 +49,7 @@ fn test(input: PathBuf) {
                     import_meta: true,
                     top_level_await: true,
                     import_assertions: true,
+                    error_recovery: false,
                 }),
                 "ts" | "tsx" => Syntax::Typescript(TsConfig {
                     tsx: ext == "tsx",
@@ -93,6 +93,21 @@ impl Fold for Normalizer {
         }
     }
 
+    fn fold_number(&mut self, n: Number) -> Number {
+        let span = n.span.fold_with(self);
+
+        if self.is_test262 {
+            // `pass-explicit` may spell numbers differently.
+            Number {
+                span,
+                raw: None,
+                ..n
+            }
+        } else {
+            Number { span, ..n }
+        }
+    }
+
     fn fold_str(&mut self, s: Str) -> Str {
         let span = s.span.fold_with(self);
 
@@ -0,0 +1,176 @@
+use swc_common::{sync::Lrc, BytePos, FileName, SourceFile, SourceMap, Span};
+use swc_ecma_ast::Module;
+use swc_ecma_parser::{
+    lexer::Lexer, reparse_module, JscTarget, PResult, Parser, StringInput, Syntax, TextEdit,
+};
+
+fn parse(syntax: Syntax, fm: &SourceFile) -> PResult<Module> {
+    let lexer = Lexer::new(syntax, JscTarget::Es2020, StringInput::from(fm), None);
+    let mut p = Parser::new_from(lexer);
+    if syntax.typescript() {
+        p.parse_typescript_module()
+    } else {
+        p.parse_module()
+    }
+}
+
+/// Replaces the first occurrence of `from` in `src` with `to`, and compares
+/// the result of [reparse_module] with a full parse.
+fn check(syntax: Syntax, src: &str, from: &str, to: &str, incremental: bool) {
+    let cm: Lrc<SourceMap> = Default::default();
+    // Don't start at zero.
+    cm.new_source_file(FileName::Anon, "void 0;".into());
+    let fm = cm.new_source_file(FileName::Anon, src.into());
+
+    let start = src.find(from).expect("failed to find the edited text") as u32;
+    let edit = TextEdit {
+        span: Span::new(
+            fm.start_pos + BytePos(start),
+            fm.start_pos + BytePos(start + from.len() as u32),
+            Default::default(),
+        ),
+        text: to.into(),
+    };
+
+    let old = parse(syntax, &fm).expect("failed to parse the old source");
+    let reparsed = reparse_module(syntax, JscTarget::Es2020, old, &fm, &edit);
+
+    assert_eq!(reparsed.fm.src.as_str(), src.replacen(from, to, 1));
+    assert_eq!(reparsed.fm.start_pos, fm.start_pos);
+    assert_eq!(
+        reparsed.incremental, incremental,
+        "incremental should be {}",
+        incremental
+    );
+    match (reparsed.module, parse(syntax, &reparsed.fm)) {
+        (Ok(actual), Ok(expected)) => assert_eq!(actual, expected),
+        (Err(..), Err(..)) => {}
+        (actual, expected) => panic!("expected {:?}, got {:?}", expected, actual),
+    }
+}
+
+fn es(src: &str, from: &str, to: &str, incremental: bool) {
+    check(Default::default(), src, from, to, incremental)
+}
+
+#[test]
+fn fn_body() {
+    es(
+        "function foo() {\n    a;\n    b;\n    c;\n}\nfoo();\n",
+        "b;",
+        "bar(b, 1);",
+        true,
+    );
+}
+
+#[test]
+fn fn_body_remove_stmt() {
+    es(
+        "function foo() {\n    a;\n    b;\n}\nfoo();\n",
+        "a;",
+        "",
+        true,
+    );
+}
+
+#[test]
+fn nested_fn() {
+    es(
+        "export function foo() {\n    return function* () {\n        yield a;\n    };\n}\n",
+        "yield a",
+        "yield* a",
+        true,
+    );
+}
+
+#[test]
+fn async_arrow() {
+    es(
+        "const foo = async () => {\n    await a;\n};\n",
+        "await a",
+        "await b",
+        true,
+    );
+}
+
+#[test]
+fn top_level() {
+    es("import a from 'a';\na();\nb();\n", "b()", "b(a)", true);
+}
+
+#[test]
+fn append() {
+    es("a();\nb();\n", "b();", "b();\nc();", true);
+}
+
+#[test]
+fn comment() {
+    es("a();\n// foo\nb();\n", "foo", "bar", true);
+}
+
+#[test]
+fn asi_hazard_prev() {
+    es("a\nb;\n", "b", "(b)", false);
+}
+
+#[test]
+fn asi_hazard_next() {
+    es("a;\nb;\n(c);\n", "b;", "b", false);
+}
+
+#[test]
+fn syntax_error() {
+    es("a();\nb();\n", "b()", "b(", false);
+}
+
+#[test]
+fn directive() {
+    es("a;\nb;\n", "a;", "'use strict';\na;", false);
+}
+
+#[test]
+fn fn_directive() {
+    // The whole function is parsed again.
+    es(
+        "function foo() {\n    a;\n}\n",
+        "a;",
+        "'use strict';\n    a;",
+        true,
+    );
+}
+
+#[test]
+fn cond_expr() {
+    es("a ? function () {\n    b;\n} : c;\n", "b;", "d;", true);
+}
+
+#[test]
+fn class_method() {
+    es(
+        "class Foo {\n    #foo = 1;\n    static is(obj) {\n        return false;\n    }\n}\n",
+        "false",
+        "#foo in obj",
+        true,
+    );
+}
+
+#[test]
+fn class_method_undeclared_brand_check() {
+    es(
+        "class Foo {\n    #foo = 1;\n    static is(obj) {\n        return false;\n    }\n}\n",
+        "false",
+        "#bar in obj",
+        false,
+    );
+}
+
+#[test]
+fn typescript() {
+    check(
+        Syntax::Typescript(Default::default()),
+        "interface Foo {\n    a: string\n}\nfunction foo(): Foo {\n    return { a: '' };\n}\n",
+        "''",
+        "'a'",
+        true,
+    );
+}
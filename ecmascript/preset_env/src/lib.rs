@@ -14,7 +14,7 @@ use swc_atoms::{js_word, JsWord};
 use swc_common::{chain, FromVariant, Mark, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_transforms::{
-    compat::{bugfixes, es2015, es2016, es2017, es2018, es2020, es3},
+    compat::{bugfixes, es2015, es2016, es2017, es2018, es2020, es2021, es2022, es3},
     pass::{noop, Optional},
 };
 use swc_ecma_utils::prepend_stmts;
@@ -78,6 +78,16 @@ pub fn preset_env(global_mark: Mark, c: Config) -> impl Fold {
         bugfixes::template_literal_caching()
     );
 
+    // ES2022
+    let pass = add!(pass, ClassStaticBlock, es2022::static_blocks());
+
+    // ES2021
+    let pass = add!(
+        pass,
+        LogicalAssignmentOperators,
+        es2021::logical_assignments()
+    );
+
     // ES2020
 
     let pass = add!(pass, NullishCoalescing, es2020::nullish_coalescing());
@@ -85,6 +95,13 @@ pub fn preset_env(global_mark: Mark, c: Config) -> impl Fold {
     let pass = add!(pass, ClassProperties, es2020::class_properties());
 
     // ES2018
+    let pass = add!(
+        pass,
+        NamedCapturingGroupsRegex,
+        es2018::named_capturing_groups_regex()
+    );
+    let pass = add!(pass, UnicodePropertyRegex, es2018::unicode_property_regex());
+    let pass = add!(pass, DotAllRegex, es2018::dotall_regex());
     let pass = add!(pass, ObjectRestSpread, es2018::object_rest_spread());
     let pass = add!(pass, OptionalCatchBinding, es2018::optional_catch_binding());
 
@@ -107,6 +124,7 @@ pub fn preset_env(global_mark: Mark, c: Config) -> impl Fold {
     let pass = add!(pass, FunctionName, es2015::function_name());
     let pass = add!(pass, ArrowFunctions, es2015::arrow());
     let pass = add!(pass, DuplicateKeys, es2015::duplicate_keys());
+    let pass = add!(pass, UnicodeRegex, es2015::unicode_regex());
     let pass = add!(pass, StickyRegex, es2015::sticky_regex());
     // TODO:    InstanceOf,
     let pass = add!(pass, TypeOfSymbol, es2015::typeof_symbol());
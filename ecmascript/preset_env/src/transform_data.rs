@@ -131,6 +131,12 @@ pub enum Feature {
     /// `proposal-private-methods`
     PrivateMethods,
 
+    /// `proposal-class-static-block`
+    ClassStaticBlock,
+
+    /// `proposal-logical-assignment-operators`
+    LogicalAssignmentOperators,
+
     /// `transform-unicode-escapes`
     UnicodeEscapes,
 
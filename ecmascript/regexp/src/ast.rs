@@ -0,0 +1,200 @@
+use serde::{Deserialize, Serialize};
+use swc_atoms::JsWord;
+use swc_common::{ast_node, Span};
+
+/// `/pattern/flags`
+#[ast_node("RegExp")]
+pub struct Regexp {
+    pub span: Span,
+    pub pattern: Pattern,
+    pub flags: Flags,
+}
+
+#[ast_node("Flags")]
+#[derive(Copy, Eq, Default)]
+pub struct Flags {
+    pub span: Span,
+    /// `d`
+    pub has_indices: bool,
+    /// `g`
+    pub global: bool,
+    /// `i`
+    pub ignore_case: bool,
+    /// `m`
+    pub multiline: bool,
+    /// `s`
+    pub dot_all: bool,
+    /// `u`
+    pub unicode: bool,
+    /// `y`
+    pub sticky: bool,
+}
+
+/// Alternatives separated by `|`.
+#[ast_node("Pattern")]
+pub struct Pattern {
+    pub span: Span,
+    pub alternatives: Vec<Alternative>,
+}
+
+#[ast_node("Alternative")]
+pub struct Alternative {
+    pub span: Span,
+    pub elements: Vec<Element>,
+}
+
+#[ast_node]
+pub enum Element {
+    #[tag("Assertion")]
+    Assertion(Assertion),
+    #[tag("Lookaround")]
+    Lookaround(Lookaround),
+    #[tag("Quantifier")]
+    Quantifier(Quantifier),
+    #[tag("Group")]
+    Group(Group),
+    #[tag("CapturingGroup")]
+    CapturingGroup(CapturingGroup),
+    #[tag("Character")]
+    Character(Character),
+    #[tag("CharacterClass")]
+    CharacterClass(CharacterClass),
+    #[tag("CharacterSet")]
+    CharacterSet(CharacterSet),
+    #[tag("Backreference")]
+    Backreference(Backreference),
+}
+
+/// `^`, `$`, `\b` or `\B`
+#[ast_node("Assertion")]
+#[derive(Copy, Eq, Hash)]
+pub struct Assertion {
+    pub span: Span,
+    pub kind: AssertionKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AssertionKind {
+    /// `^`
+    Start,
+    /// `$`
+    End,
+    /// `\b`
+    WordBoundary,
+    /// `\B`
+    NonWordBoundary,
+}
+
+/// `(?=a)`, `(?!a)`, `(?<=a)` or `(?<!a)`
+#[ast_node("Lookaround")]
+pub struct Lookaround {
+    pub span: Span,
+    /// `true` for lookbehinds.
+    pub behind: bool,
+    pub negate: bool,
+    pub alternatives: Vec<Alternative>,
+}
+
+/// `a*`, `a+`, `a?` or `a{min,max}`, optionally followed by `?`.
+#[ast_node("Quantifier")]
+pub struct Quantifier {
+    pub span: Span,
+    pub min: u32,
+    /// [None] if unbounded.
+    pub max: Option<u32>,
+    pub greedy: bool,
+    pub element: Box<Element>,
+}
+
+/// `(?:a)`
+#[ast_node("Group")]
+pub struct Group {
+    pub span: Span,
+    pub alternatives: Vec<Alternative>,
+}
+
+/// `(a)` or `(?<name>a)`
+#[ast_node("CapturingGroup")]
+pub struct CapturingGroup {
+    pub span: Span,
+    pub name: Option<JsWord>,
+    pub alternatives: Vec<Alternative>,
+}
+
+/// A character, which may be written as an escape sequence.
+///
+/// Without the `u` flag, a pattern is a sequence of UTF-16 code units, so a
+/// character outside of the basic multilingual plane is parsed as two
+/// surrogates.
+#[ast_node("Character")]
+#[derive(Copy, Eq, Hash)]
+pub struct Character {
+    pub span: Span,
+    /// Code point, or code unit without the `u` flag.
+    pub value: u32,
+}
+
+/// `[a-z]` or `[^a-z]`
+#[ast_node("CharacterClass")]
+pub struct CharacterClass {
+    pub span: Span,
+    pub negate: bool,
+    pub elements: Vec<ClassElement>,
+}
+
+#[ast_node]
+pub enum ClassElement {
+    #[tag("Character")]
+    Character(Character),
+    #[tag("ClassRange")]
+    Range(ClassRange),
+    #[tag("CharacterSet")]
+    Set(CharacterSet),
+}
+
+/// `a-z`
+#[ast_node("ClassRange")]
+#[derive(Copy, Eq, Hash)]
+pub struct ClassRange {
+    pub span: Span,
+    pub min: Character,
+    pub max: Character,
+}
+
+/// `.`, `\d`, `\s`, `\w`, `\p{..}` or their negations.
+#[ast_node("CharacterSet")]
+#[derive(Eq, Hash)]
+pub struct CharacterSet {
+    pub span: Span,
+    pub kind: CharacterSetKind,
+    /// `true` for `\D`, `\S`, `\W` and `\P{..}`.
+    pub negate: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CharacterSetKind {
+    /// `.`
+    Any,
+    /// `\d`
+    Digit,
+    /// `\s`
+    Space,
+    /// `\w`
+    Word,
+    /// `\p{name}` or `\p{name=value}`
+    Property { name: JsWord, value: Option<JsWord> },
+}
+
+/// `\1` or `\k<name>`
+#[ast_node("Backreference")]
+#[derive(Eq, Hash)]
+pub struct Backreference {
+    pub span: Span,
+    pub reference: BackreferenceRef,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BackreferenceRef {
+    Index(u32),
+    Name(JsWord),
+}
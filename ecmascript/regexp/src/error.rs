@@ -0,0 +1,90 @@
+use std::fmt::{self, Display, Formatter};
+use swc_common::{Span, Spanned};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    pub span: Span,
+    pub kind: ErrorKind,
+}
+
+impl Spanned for Error {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.kind, f)
+    }
+}
+
+/// Messages follow the ones used by v8.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    InvalidFlag(char),
+    DuplicateFlag(char),
+
+    NothingToRepeat,
+    LoneQuantifierBrackets,
+    IncompleteQuantifier,
+    QuantifierOutOfOrder,
+
+    UnmatchedParen,
+    UnterminatedGroup,
+    InvalidGroup,
+
+    UnterminatedCharacterClass,
+    RangeOutOfOrder,
+    /// A range containing a character set, like `[\d-z]`.
+    InvalidCharacterClass,
+
+    EscapeAtEndOfPattern,
+    InvalidEscape,
+    InvalidClassEscape,
+    InvalidUnicodeEscape,
+    InvalidDecimalEscape,
+    InvalidPropertyName,
+
+    InvalidCaptureGroupName,
+    DuplicateCaptureGroupName,
+    InvalidNamedReference,
+    InvalidNamedCaptureReference,
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let msg = match *self {
+            ErrorKind::InvalidFlag(c) => return write!(f, "Invalid flag '{}'", c),
+            ErrorKind::DuplicateFlag(c) => return write!(f, "Duplicate flag '{}'", c),
+
+            ErrorKind::NothingToRepeat => "Nothing to repeat",
+            ErrorKind::LoneQuantifierBrackets => "Lone quantifier brackets",
+            ErrorKind::IncompleteQuantifier => "Incomplete quantifier",
+            ErrorKind::QuantifierOutOfOrder => "numbers out of order in {} quantifier",
+
+            ErrorKind::UnmatchedParen => "Unmatched ')'",
+            ErrorKind::UnterminatedGroup => "Unterminated group",
+            ErrorKind::InvalidGroup => "Invalid group",
+
+            ErrorKind::UnterminatedCharacterClass => "Unterminated character class",
+            ErrorKind::RangeOutOfOrder => "Range out of order in character class",
+            ErrorKind::InvalidCharacterClass => "Invalid character class",
+
+            ErrorKind::EscapeAtEndOfPattern => "\\ at end of pattern",
+            ErrorKind::InvalidEscape => "Invalid escape",
+            ErrorKind::InvalidClassEscape => "Invalid class escape",
+            ErrorKind::InvalidUnicodeEscape => "Invalid Unicode escape",
+            ErrorKind::InvalidDecimalEscape => "Invalid decimal escape",
+            ErrorKind::InvalidPropertyName => "Invalid property name",
+
+            ErrorKind::InvalidCaptureGroupName => "Invalid capture group name",
+            ErrorKind::DuplicateCaptureGroupName => "Duplicate capture group name",
+            ErrorKind::InvalidNamedReference => "Invalid named reference",
+            ErrorKind::InvalidNamedCaptureReference => "Invalid named capture referenced",
+        };
+
+        f.write_str(msg)
+    }
+}
@@ -0,0 +1,46 @@
+//! Parser for regular expressions of ecmascript.
+//!
+//! Annex B of the specification is supported, so patterns without the `u`
+//! flag are parsed like browsers do.
+//!
+//! The ast can be printed using [std::fmt::Display].
+
+pub use self::{
+    error::{Error, ErrorKind},
+    printer::PatternDisplay,
+};
+use crate::ast::*;
+use swc_common::{BytePos, Span, SyntaxContext};
+
+pub mod ast;
+mod error;
+mod parser;
+mod printer;
+mod property;
+
+/// Parses `/exp/flags`.
+///
+/// `start` is the position of the first `/`.
+pub fn parse(start: BytePos, exp: &str, flags: &str) -> Result<Regexp, Error> {
+    let pattern_start = start + BytePos(1);
+    let flags_start = pattern_start + BytePos(exp.len() as u32 + 1);
+
+    let flags = parse_flags(flags_start, flags)?;
+    let pattern = parse_pattern(pattern_start, exp, flags)?;
+
+    Ok(Regexp {
+        span: Span::new(start, flags.span.hi(), SyntaxContext::empty()),
+        pattern,
+        flags,
+    })
+}
+
+/// `start` is the position of the first flag.
+pub fn parse_flags(start: BytePos, flags: &str) -> Result<Flags, Error> {
+    parser::parse_flags(start, flags)
+}
+
+/// `start` is the position of the first character of `exp`.
+pub fn parse_pattern(start: BytePos, exp: &str, flags: Flags) -> Result<Pattern, Error> {
+    parser::parse_pattern(start, exp, flags)
+}
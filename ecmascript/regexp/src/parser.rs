@@ -0,0 +1,945 @@
+use crate::{
+    ast::*,
+    error::{Error, ErrorKind},
+    property,
+};
+use swc_atoms::JsWord;
+use swc_common::{BytePos, Span, SyntaxContext};
+use unicode_xid::UnicodeXID;
+
+pub(crate) fn parse_flags(start: BytePos, s: &str) -> Result<Flags, Error> {
+    let mut flags = Flags {
+        span: Span::new(
+            start,
+            start + BytePos(s.len() as u32),
+            SyntaxContext::empty(),
+        ),
+        ..Default::default()
+    };
+
+    for (i, c) in s.char_indices() {
+        let lo = start + BytePos(i as u32);
+        let span = Span::new(
+            lo,
+            lo + BytePos(c.len_utf8() as u32),
+            SyntaxContext::empty(),
+        );
+
+        let flag = match c {
+            'd' => &mut flags.has_indices,
+            'g' => &mut flags.global,
+            'i' => &mut flags.ignore_case,
+            'm' => &mut flags.multiline,
+            's' => &mut flags.dot_all,
+            'u' => &mut flags.unicode,
+            'y' => &mut flags.sticky,
+            _ => {
+                return Err(Error {
+                    span,
+                    kind: ErrorKind::InvalidFlag(c),
+                })
+            }
+        };
+        if *flag {
+            return Err(Error {
+                span,
+                kind: ErrorKind::DuplicateFlag(c),
+            });
+        }
+        *flag = true;
+    }
+
+    Ok(flags)
+}
+
+pub(crate) fn parse_pattern(start: BytePos, s: &str, flags: Flags) -> Result<Pattern, Error> {
+    let mut p = Parser::new(start, s, flags.unicode);
+
+    let alternatives = p.parse_disjunction()?;
+    if p.peek().is_some() {
+        // Only `)` stops a disjunction.
+        let lo = p.cur_pos();
+        p.bump();
+        return Err(p.error(lo, ErrorKind::UnmatchedParen));
+    }
+
+    for (span, name) in &p.named_refs {
+        if !p.group_names.contains(name) {
+            return Err(Error {
+                span: *span,
+                kind: ErrorKind::InvalidNamedCaptureReference,
+            });
+        }
+    }
+
+    Ok(Pattern {
+        span: Span::new(start, p.end, SyntaxContext::empty()),
+        alternatives,
+    })
+}
+
+/// A code point, or a code unit without the `u` flag.
+#[derive(Debug, Clone, Copy)]
+struct Unit {
+    value: u32,
+    lo: BytePos,
+    hi: BytePos,
+}
+
+struct Parser {
+    units: Vec<Unit>,
+    pos: usize,
+    start: BytePos,
+    end: BytePos,
+    unicode: bool,
+    /// Number of capturing groups in the whole pattern.
+    group_count: u32,
+    /// `\k` is a named reference if the pattern contains a named group, even
+    /// without the `u` flag.
+    has_named_groups: bool,
+    group_names: Vec<JsWord>,
+    /// A group can be defined after a reference to it, so named references
+    /// are checked after parsing.
+    named_refs: Vec<(Span, JsWord)>,
+}
+
+fn is_syntax_char(c: u32) -> bool {
+    matches!(
+        char_of(c),
+        '^' | '$' | '\\' | '.' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{' | '}' | '|'
+    )
+}
+
+fn is_lead_surrogate(c: u32) -> bool {
+    (0xd800..=0xdbff).contains(&c)
+}
+
+fn is_trail_surrogate(c: u32) -> bool {
+    (0xdc00..=0xdfff).contains(&c)
+}
+
+fn combine_surrogates(lead: u32, trail: u32) -> u32 {
+    (lead - 0xd800) * 0x400 + (trail - 0xdc00) + 0x10000
+}
+
+/// Returns `U+FFFD` for surrogates, which is fine for checking if `c` is a
+/// specific ascii character.
+fn char_of(c: u32) -> char {
+    std::char::from_u32(c).unwrap_or('\u{fffd}')
+}
+
+impl Parser {
+    fn new(start: BytePos, s: &str, unicode: bool) -> Self {
+        let mut units = Vec::with_capacity(s.len());
+        for (i, c) in s.char_indices() {
+            let lo = start + BytePos(i as u32);
+            let hi = lo + BytePos(c.len_utf8() as u32);
+            if unicode || (c as u32) < 0x10000 {
+                units.push(Unit {
+                    value: c as u32,
+                    lo,
+                    hi,
+                });
+            } else {
+                let mut buf = [0; 2];
+                for &u in c.encode_utf16(&mut buf).iter() {
+                    units.push(Unit {
+                        value: u as u32,
+                        lo,
+                        hi,
+                    });
+                }
+            }
+        }
+
+        let mut p = Parser {
+            units,
+            pos: 0,
+            start,
+            end: start + BytePos(s.len() as u32),
+            unicode,
+            group_count: 0,
+            has_named_groups: false,
+            group_names: vec![],
+            named_refs: vec![],
+        };
+        p.count_groups();
+        p
+    }
+
+    /// Backreferences and `\k` depend on groups which may appear later in the
+    /// pattern, so they are counted before parsing.
+    fn count_groups(&mut self) {
+        let (mut escaped, mut in_class) = (false, false);
+
+        for i in 0..self.units.len() {
+            if escaped {
+                escaped = false;
+                continue;
+            }
+
+            let at = |n: usize| self.units.get(i + n).map(|u| char_of(u.value));
+            match at(0) {
+                Some('\\') => escaped = true,
+                Some('[') => in_class = true,
+                Some(']') => in_class = false,
+                Some('(') if !in_class => match (at(1), at(2), at(3)) {
+                    (Some('?'), Some('<'), Some(c)) if c != '=' && c != '!' => {
+                        self.group_count += 1;
+                        self.has_named_groups = true;
+                    }
+                    (Some('?'), ..) => {}
+                    _ => self.group_count += 1,
+                },
+                _ => {}
+            }
+        }
+    }
+
+    fn peek(&self) -> Option<u32> {
+        self.peek_at(0)
+    }
+
+    fn peek_at(&self, n: usize) -> Option<u32> {
+        self.units.get(self.pos + n).map(|u| u.value)
+    }
+
+    fn is(&self, c: char) -> bool {
+        self.peek() == Some(c as u32)
+    }
+
+    fn is_at(&self, n: usize, c: char) -> bool {
+        self.peek_at(n) == Some(c as u32)
+    }
+
+    fn is_digit_at(&self, n: usize) -> bool {
+        match self.peek_at(n) {
+            Some(c) => char_of(c).is_ascii_digit(),
+            None => false,
+        }
+    }
+
+    fn bump(&mut self) {
+        debug_assert!(self.pos < self.units.len());
+        self.pos += 1;
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.is(c) {
+            self.bump();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn cur_pos(&self) -> BytePos {
+        self.units.get(self.pos).map(|u| u.lo).unwrap_or(self.end)
+    }
+
+    /// Span from `lo` to the end of the last consumed unit.
+    fn span(&self, lo: BytePos) -> Span {
+        let hi = match self.pos {
+            0 => self.start,
+            pos => self.units[pos - 1].hi,
+        };
+        Span::new(lo, if hi < lo { lo } else { hi }, SyntaxContext::empty())
+    }
+
+    fn error(&self, lo: BytePos, kind: ErrorKind) -> Error {
+        Error {
+            span: self.span(lo),
+            kind,
+        }
+    }
+
+    fn parse_disjunction(&mut self) -> Result<Vec<Alternative>, Error> {
+        let mut alternatives = vec![self.parse_alternative()?];
+        while self.eat('|') {
+            alternatives.push(self.parse_alternative()?);
+        }
+
+        Ok(alternatives)
+    }
+
+    fn parse_alternative(&mut self) -> Result<Alternative, Error> {
+        let lo = self.cur_pos();
+
+        let mut elements = vec![];
+        while !self.is('|') && !self.is(')') && self.peek().is_some() {
+            elements.push(self.parse_term()?);
+        }
+
+        Ok(Alternative {
+            span: self.span(lo),
+            elements,
+        })
+    }
+
+    fn parse_term(&mut self) -> Result<Element, Error> {
+        let lo = self.cur_pos();
+
+        let kind = match char_of(self.peek().unwrap()) {
+            '^' => Some(AssertionKind::Start),
+            '$' => Some(AssertionKind::End),
+            '\\' if self.is_at(1, 'b') => Some(AssertionKind::WordBoundary),
+            '\\' if self.is_at(1, 'B') => Some(AssertionKind::NonWordBoundary),
+            _ => None,
+        };
+        if let Some(kind) = kind {
+            self.pos += if self.is('\\') { 2 } else { 1 };
+            return Ok(Element::Assertion(Assertion {
+                span: self.span(lo),
+                kind,
+            }));
+        }
+
+        let atom = if self.is('(') && self.is_at(1, '?') {
+            match (self.peek_at(2).map(char_of), self.peek_at(3).map(char_of)) {
+                (Some('='), _) | (Some('!'), _) => {
+                    let lookahead = self.parse_lookaround()?;
+                    // Annex B allows quantified lookaheads.
+                    if self.unicode {
+                        return Ok(lookahead);
+                    }
+                    lookahead
+                }
+                (Some('<'), Some('=')) | (Some('<'), Some('!')) => {
+                    return self.parse_lookaround();
+                }
+                _ => self.parse_atom()?,
+            }
+        } else {
+            self.parse_atom()?
+        };
+
+        self.parse_quantifier(lo, atom)
+    }
+
+    fn parse_quantifier(&mut self, lo: BytePos, atom: Element) -> Result<Element, Error> {
+        let quantifier_lo = self.cur_pos();
+        let (min, max) = match self.peek().map(char_of) {
+            Some('*') => {
+                self.bump();
+                (0, None)
+            }
+            Some('+') => {
+                self.bump();
+                (1, None)
+            }
+            Some('?') => {
+                self.bump();
+                (0, Some(1))
+            }
+            Some('{') => match self.parse_braces() {
+                Some(v) => v,
+                None if self.unicode => {
+                    self.bump();
+                    return Err(self.error(quantifier_lo, ErrorKind::IncompleteQuantifier));
+                }
+                // Annex B: `{` is a literal character.
+                None => return Ok(atom),
+            },
+            _ => return Ok(atom),
+        };
+        if let Some(max) = max {
+            if max < min {
+                return Err(self.error(quantifier_lo, ErrorKind::QuantifierOutOfOrder));
+            }
+        }
+        let greedy = !self.eat('?');
+
+        Ok(Element::Quantifier(Quantifier {
+            span: self.span(lo),
+            min,
+            max,
+            greedy,
+            element: Box::new(atom),
+        }))
+    }
+
+    /// `{min}`, `{min,}` or `{min,max}`.
+    ///
+    /// Returns [None] without consuming anything if the current token does
+    /// not start a valid quantifier.
+    fn parse_braces(&mut self) -> Option<(u32, Option<u32>)> {
+        let start = self.pos;
+        debug_assert!(self.is('{'));
+        self.bump();
+
+        let res = (|| {
+            let min = self.parse_decimal()?;
+            let max = if self.eat(',') {
+                if self.is('}') {
+                    None
+                } else {
+                    Some(self.parse_decimal()?)
+                }
+            } else {
+                Some(min)
+            };
+            if !self.eat('}') {
+                return None;
+            }
+            Some((min, max))
+        })();
+
+        if res.is_none() {
+            self.pos = start;
+        }
+        res
+    }
+
+    /// Reads decimal digits. Values larger than [u32::MAX] are clamped.
+    fn parse_decimal(&mut self) -> Option<u32> {
+        let mut value: Option<u32> = None;
+        while let Some(d) = self.peek().and_then(|c| char_of(c).to_digit(10)) {
+            self.bump();
+            value = Some(value.unwrap_or(0).saturating_mul(10).saturating_add(d));
+        }
+
+        value
+    }
+
+    fn parse_atom(&mut self) -> Result<Element, Error> {
+        let lo = self.cur_pos();
+        let c = self.peek().unwrap();
+
+        match char_of(c) {
+            '.' => {
+                self.bump();
+                Ok(Element::CharacterSet(CharacterSet {
+                    span: self.span(lo),
+                    kind: CharacterSetKind::Any,
+                    negate: false,
+                }))
+            }
+            '(' => self.parse_group(),
+            '[' => self.parse_class(),
+            '\\' => self.parse_atom_escape(),
+            '*' | '+' | '?' => {
+                self.bump();
+                Err(self.error(lo, ErrorKind::NothingToRepeat))
+            }
+            '{' => {
+                if self.parse_braces().is_some() {
+                    return Err(self.error(lo, ErrorKind::NothingToRepeat));
+                }
+                self.bump();
+                if self.unicode {
+                    return Err(self.error(lo, ErrorKind::LoneQuantifierBrackets));
+                }
+                Ok(Element::Character(Character {
+                    span: self.span(lo),
+                    value: c,
+                }))
+            }
+            '}' | ']' if self.unicode => {
+                self.bump();
+                Err(self.error(lo, ErrorKind::LoneQuantifierBrackets))
+            }
+            _ => {
+                self.bump();
+                Ok(Element::Character(Character {
+                    span: self.span(lo),
+                    value: c,
+                }))
+            }
+        }
+    }
+
+    /// `(?=`, `(?!`, `(?<=` or `(?<!`.
+    fn parse_lookaround(&mut self) -> Result<Element, Error> {
+        let lo = self.cur_pos();
+        self.pos += 2;
+        let behind = self.eat('<');
+        let negate = self.is('!');
+        self.bump();
+
+        let alternatives = self.parse_disjunction()?;
+        if !self.eat(')') {
+            return Err(self.error(lo, ErrorKind::UnterminatedGroup));
+        }
+
+        Ok(Element::Lookaround(Lookaround {
+            span: self.span(lo),
+            behind,
+            negate,
+            alternatives,
+        }))
+    }
+
+    fn parse_group(&mut self) -> Result<Element, Error> {
+        let lo = self.cur_pos();
+        self.bump();
+
+        let name = if self.eat('?') {
+            if self.eat(':') {
+                None
+            } else if self.eat('<') {
+                let name_lo = self.cur_pos();
+                let name = self.parse_group_name(ErrorKind::InvalidCaptureGroupName)?;
+                if self.group_names.contains(&name) {
+                    return Err(self.error(name_lo, ErrorKind::DuplicateCaptureGroupName));
+                }
+                self.group_names.push(name.clone());
+                Some(Some(name))
+            } else {
+                return Err(self.error(lo, ErrorKind::InvalidGroup));
+            }
+        } else {
+            Some(None)
+        };
+
+        let alternatives = self.parse_disjunction()?;
+        if !self.eat(')') {
+            return Err(self.error(lo, ErrorKind::UnterminatedGroup));
+        }
+
+        let span = self.span(lo);
+        Ok(match name {
+            Some(name) => Element::CapturingGroup(CapturingGroup {
+                span,
+                name,
+                alternatives,
+            }),
+            None => Element::Group(Group { span, alternatives }),
+        })
+    }
+
+    /// Parses a name and the closing `>`.
+    fn parse_group_name(&mut self, kind: ErrorKind) -> Result<JsWord, Error> {
+        let lo = self.cur_pos();
+
+        let mut name = String::new();
+        loop {
+            let c = match self.peek() {
+                Some(c) if c == '>' as u32 => {
+                    self.bump();
+                    break;
+                }
+                Some(c) if c == '\\' as u32 => {
+                    self.bump();
+                    if !self.eat('u') {
+                        return Err(self.error(lo, kind));
+                    }
+                    match self.parse_unicode_escape(true) {
+                        Some(c) => c,
+                        None => return Err(self.error(lo, kind)),
+                    }
+                }
+                Some(c) => {
+                    self.bump();
+                    match self.peek() {
+                        Some(trail) if is_lead_surrogate(c) && is_trail_surrogate(trail) => {
+                            self.bump();
+                            combine_surrogates(c, trail)
+                        }
+                        _ => c,
+                    }
+                }
+                None => return Err(self.error(lo, kind)),
+            };
+
+            let c = std::char::from_u32(c).filter(|&c| {
+                c == '$'
+                    || c == '_'
+                    || if name.is_empty() {
+                        UnicodeXID::is_xid_start(c)
+                    } else {
+                        c == '\u{200c}' || c == '\u{200d}' || UnicodeXID::is_xid_continue(c)
+                    }
+            });
+            match c {
+                Some(c) => name.push(c),
+                None => return Err(self.error(lo, kind)),
+            }
+        }
+
+        if name.is_empty() {
+            return Err(self.error(lo, kind));
+        }
+        Ok(name.into())
+    }
+
+    /// Parses `XXXX` or `{X..}` after `\u`.
+    ///
+    /// `\u{X..}` and surrogate pairs are only recognized if `unicode` is
+    /// true. Returns [None] without consuming anything if the escape is
+    /// invalid.
+    fn parse_unicode_escape(&mut self, unicode: bool) -> Option<u32> {
+        let start = self.pos;
+
+        let res = if unicode && self.eat('{') {
+            let mut value: u32 = 0;
+            let mut digits = 0;
+            while let Some(d) = self.peek().and_then(|c| char_of(c).to_digit(16)) {
+                self.bump();
+                value = value.saturating_mul(16).saturating_add(d);
+                digits += 1;
+            }
+            if digits != 0 && value <= 0x10ffff && self.eat('}') {
+                Some(value)
+            } else {
+                None
+            }
+        } else {
+            self.parse_hex(4).map(|lead| {
+                if unicode && is_lead_surrogate(lead) && self.is('\\') && self.is_at(1, 'u') {
+                    let before_trail = self.pos;
+                    self.pos += 2;
+                    match self.parse_hex(4) {
+                        Some(trail) if is_trail_surrogate(trail) => {
+                            return combine_surrogates(lead, trail);
+                        }
+                        _ => self.pos = before_trail,
+                    }
+                }
+                lead
+            })
+        };
+
+        if res.is_none() {
+            self.pos = start;
+        }
+        res
+    }
+
+    /// Reads exactly `len` hex digits.
+    fn parse_hex(&mut self, len: usize) -> Option<u32> {
+        let mut value = 0;
+        for i in 0..len {
+            value = value * 16 + self.peek_at(i).and_then(|c| char_of(c).to_digit(16))?;
+        }
+        self.pos += len;
+
+        Some(value)
+    }
+
+    fn parse_atom_escape(&mut self) -> Result<Element, Error> {
+        let lo = self.cur_pos();
+        self.bump();
+
+        let c = match self.peek() {
+            Some(c) => c,
+            None => return Err(self.error(lo, ErrorKind::EscapeAtEndOfPattern)),
+        };
+
+        match char_of(c) {
+            'd' | 'D' | 's' | 'S' | 'w' | 'W' => Ok(Element::CharacterSet(
+                self.parse_character_class_escape(lo)?,
+            )),
+            'p' | 'P' if self.unicode => Ok(Element::CharacterSet(
+                self.parse_character_class_escape(lo)?,
+            )),
+            '1'..='9' => {
+                let digits = self.pos;
+                let index = self.parse_decimal().unwrap();
+                if index <= self.group_count {
+                    return Ok(Element::Backreference(Backreference {
+                        span: self.span(lo),
+                        reference: BackreferenceRef::Index(index),
+                    }));
+                }
+                if self.unicode {
+                    return Err(self.error(lo, ErrorKind::InvalidEscape));
+                }
+
+                // Annex B: a legacy octal escape or an identity escape.
+                self.pos = digits;
+                Ok(Element::Character(self.parse_character_escape(lo, false)?))
+            }
+            'k' if self.unicode || self.has_named_groups => {
+                self.bump();
+                if !self.eat('<') {
+                    return Err(self.error(lo, ErrorKind::InvalidNamedReference));
+                }
+                let name = self.parse_group_name(ErrorKind::InvalidNamedReference)?;
+                let span = self.span(lo);
+                self.named_refs.push((span, name.clone()));
+
+                Ok(Element::Backreference(Backreference {
+                    span,
+                    reference: BackreferenceRef::Name(name),
+                }))
+            }
+            _ => Ok(Element::Character(self.parse_character_escape(lo, false)?)),
+        }
+    }
+
+    /// `\d`, `\s`, `\w`, `\p{..}` or their negations, after `\`.
+    fn parse_character_class_escape(&mut self, lo: BytePos) -> Result<CharacterSet, Error> {
+        let c = char_of(self.peek().unwrap());
+        self.bump();
+
+        let kind = match c.to_ascii_lowercase() {
+            'd' => CharacterSetKind::Digit,
+            's' => CharacterSetKind::Space,
+            'w' => CharacterSetKind::Word,
+            'p' => {
+                if !self.eat('{') {
+                    return Err(self.error(lo, ErrorKind::InvalidPropertyName));
+                }
+                let name = self.parse_property_word();
+                let value = if self.eat('=') {
+                    Some(self.parse_property_word())
+                } else {
+                    None
+                };
+                if !self.eat('}') || !property::is_valid(&name, value.as_deref()) {
+                    return Err(self.error(lo, ErrorKind::InvalidPropertyName));
+                }
+
+                CharacterSetKind::Property {
+                    name: name.into(),
+                    value: value.map(From::from),
+                }
+            }
+            _ => unreachable!("parse_character_class_escape: {}", c),
+        };
+
+        Ok(CharacterSet {
+            span: self.span(lo),
+            kind,
+            negate: c.is_ascii_uppercase(),
+        })
+    }
+
+    fn parse_property_word(&mut self) -> String {
+        let mut s = String::new();
+        while let Some(c) = self.peek().map(char_of) {
+            if !c.is_ascii_alphanumeric() && c != '_' {
+                break;
+            }
+            self.bump();
+            s.push(c);
+        }
+
+        s
+    }
+
+    /// Parses an escaped character after `\`.
+    fn parse_character_escape(&mut self, lo: BytePos, in_class: bool) -> Result<Character, Error> {
+        let invalid = if in_class {
+            ErrorKind::InvalidClassEscape
+        } else {
+            ErrorKind::InvalidEscape
+        };
+
+        let c = self.peek().unwrap();
+        let value = match char_of(c) {
+            'f' => 0x0c,
+            'n' => 0x0a,
+            'r' => 0x0d,
+            't' => 0x09,
+            'v' => 0x0b,
+            'c' => match self.peek_at(1).map(char_of) {
+                Some(l) if l.is_ascii_alphabetic() => {
+                    self.bump();
+                    l as u32 % 32
+                }
+                // Annex B: `ClassControlLetter`
+                Some(l) if in_class && !self.unicode && (l.is_ascii_digit() || l == '_') => {
+                    self.bump();
+                    l as u32 % 32
+                }
+                _ if self.unicode => {
+                    self.bump();
+                    return Err(self.error(lo, ErrorKind::InvalidUnicodeEscape));
+                }
+                // Annex B: `\` is a literal backslash and `c` is parsed
+                // again.
+                _ => {
+                    return Ok(Character {
+                        span: self.span(lo),
+                        value: '\\' as u32,
+                    })
+                }
+            },
+            '0' if !self.is_digit_at(1) => 0,
+            '0'..='9' if self.unicode => {
+                self.bump();
+                return Err(self.error(
+                    lo,
+                    if in_class {
+                        invalid
+                    } else {
+                        ErrorKind::InvalidDecimalEscape
+                    },
+                ));
+            }
+            // Annex B: `LegacyOctalEscapeSequence`
+            '0'..='7' => {
+                let max_len = if c <= '3' as u32 { 3 } else { 2 };
+                let mut value = 0;
+                for _ in 0..max_len {
+                    match self.peek().and_then(|c| char_of(c).to_digit(8)) {
+                        Some(d) => {
+                            self.bump();
+                            value = value * 8 + d;
+                        }
+                        None => break,
+                    }
+                }
+
+                return Ok(Character {
+                    span: self.span(lo),
+                    value,
+                });
+            }
+            'x' => {
+                self.bump();
+                if let Some(value) = self.parse_hex(2) {
+                    return Ok(Character {
+                        span: self.span(lo),
+                        value,
+                    });
+                }
+                if self.unicode {
+                    return Err(self.error(lo, invalid));
+                }
+                // Annex B: identity escape
+                return Ok(Character {
+                    span: self.span(lo),
+                    value: c,
+                });
+            }
+            'u' => {
+                self.bump();
+                let value = match self.parse_unicode_escape(self.unicode) {
+                    Some(value) => value,
+                    None if self.unicode => {
+                        return Err(self.error(lo, ErrorKind::InvalidUnicodeEscape))
+                    }
+                    // Annex B: identity escape
+                    None => c,
+                };
+
+                return Ok(Character {
+                    span: self.span(lo),
+                    value,
+                });
+            }
+            _ if self.unicode => {
+                if !is_syntax_char(c) && c != '/' as u32 && !(in_class && c == '-' as u32) {
+                    self.bump();
+                    return Err(self.error(lo, invalid));
+                }
+                c
+            }
+            'k' if self.has_named_groups => {
+                self.bump();
+                return Err(self.error(lo, invalid));
+            }
+            _ => c,
+        };
+        self.bump();
+
+        Ok(Character {
+            span: self.span(lo),
+            value,
+        })
+    }
+
+    fn parse_class(&mut self) -> Result<Element, Error> {
+        let lo = self.cur_pos();
+        self.bump();
+        let negate = self.eat('^');
+
+        let mut elements = vec![];
+        loop {
+            match self.peek() {
+                None => {
+                    return Err(Error {
+                        span: Span::new(lo, self.end, SyntaxContext::empty()),
+                        kind: ErrorKind::UnterminatedCharacterClass,
+                    })
+                }
+                Some(c) if c == ']' as u32 => {
+                    self.bump();
+                    break;
+                }
+                _ => {}
+            }
+
+            let first = self.parse_class_atom()?;
+            if !self.is('-') || self.is_at(1, ']') || self.peek_at(1).is_none() {
+                elements.push(first);
+                continue;
+            }
+
+            let dash_lo = self.cur_pos();
+            self.bump();
+            let dash = Character {
+                span: self.span(dash_lo),
+                value: '-' as u32,
+            };
+            let second = self.parse_class_atom()?;
+
+            match (first, second) {
+                (ClassElement::Character(min), ClassElement::Character(max)) => {
+                    let span = min.span.to(max.span);
+                    if min.value > max.value {
+                        return Err(Error {
+                            span,
+                            kind: ErrorKind::RangeOutOfOrder,
+                        });
+                    }
+                    elements.push(ClassElement::Range(ClassRange { span, min, max }));
+                }
+                (first, second) => {
+                    if self.unicode {
+                        return Err(self.error(dash_lo, ErrorKind::InvalidCharacterClass));
+                    }
+                    // Annex B: a range containing a character set is not a
+                    // range.
+                    elements.push(first);
+                    elements.push(ClassElement::Character(dash));
+                    elements.push(second);
+                }
+            }
+        }
+
+        Ok(Element::CharacterClass(CharacterClass {
+            span: self.span(lo),
+            negate,
+            elements,
+        }))
+    }
+
+    fn parse_class_atom(&mut self) -> Result<ClassElement, Error> {
+        let lo = self.cur_pos();
+        let c = self.peek().unwrap();
+        self.bump();
+
+        if c != '\\' as u32 {
+            return Ok(ClassElement::Character(Character {
+                span: self.span(lo),
+                value: c,
+            }));
+        }
+
+        let c = match self.peek() {
+            Some(c) => c,
+            None => return Err(self.error(lo, ErrorKind::EscapeAtEndOfPattern)),
+        };
+        match char_of(c) {
+            'b' => {
+                self.bump();
+                Ok(ClassElement::Character(Character {
+                    span: self.span(lo),
+                    value: 0x08,
+                }))
+            }
+            'd' | 'D' | 's' | 'S' | 'w' | 'W' => {
+                Ok(ClassElement::Set(self.parse_character_class_escape(lo)?))
+            }
+            'p' | 'P' if self.unicode => {
+                Ok(ClassElement::Set(self.parse_character_class_escape(lo)?))
+            }
+            _ => Ok(ClassElement::Character(
+                self.parse_character_escape(lo, true)?,
+            )),
+        }
+    }
+}
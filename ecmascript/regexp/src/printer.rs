@@ -0,0 +1,278 @@
+use crate::ast::*;
+use std::fmt::{self, Display, Formatter, Write};
+
+impl Display for Regexp {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let pattern = self.pattern.display(self.flags.unicode).to_string();
+        // `//` is a comment.
+        let pattern = if pattern.is_empty() { "(?:)" } else { &pattern };
+
+        write!(f, "/{}/{}", pattern, self.flags)
+    }
+}
+
+impl Display for Flags {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let flags = [
+            (self.has_indices, 'd'),
+            (self.global, 'g'),
+            (self.ignore_case, 'i'),
+            (self.multiline, 'm'),
+            (self.dot_all, 's'),
+            (self.unicode, 'u'),
+            (self.sticky, 'y'),
+        ];
+        for &(enabled, c) in flags.iter() {
+            if enabled {
+                f.write_char(c)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Pattern {
+    /// Prints the pattern, without the surrounding slashes.
+    ///
+    /// `unicode` should be the `u` flag of the regular expression, as some
+    /// characters should be escaped differently.
+    pub fn display(&self, unicode: bool) -> PatternDisplay<'_> {
+        PatternDisplay {
+            pattern: self,
+            unicode,
+        }
+    }
+}
+
+/// Returned by [Pattern::display].
+pub struct PatternDisplay<'a> {
+    pattern: &'a Pattern,
+    unicode: bool,
+}
+
+impl Display for PatternDisplay<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Printer {
+            f,
+            unicode: self.unicode,
+        }
+        .print_alternatives(&self.pattern.alternatives)
+    }
+}
+
+struct Printer<'a, 'b> {
+    f: &'a mut Formatter<'b>,
+    unicode: bool,
+}
+
+impl Printer<'_, '_> {
+    fn print_alternatives(&mut self, alternatives: &[Alternative]) -> fmt::Result {
+        for (i, alt) in alternatives.iter().enumerate() {
+            if i != 0 {
+                self.f.write_char('|')?;
+            }
+            self.print_elements(&alt.elements)?;
+        }
+
+        Ok(())
+    }
+
+    fn print_elements(&mut self, elements: &[Element]) -> fmt::Result {
+        let mut iter = elements.iter().peekable();
+        while let Some(element) = iter.next() {
+            match (element, iter.peek()) {
+                // Without the `u` flag, a surrogate pair matches the same
+                // code units as the character itself.
+                (Element::Character(lead), Some(Element::Character(trail)))
+                    if !self.unicode && is_surrogate_pair(lead.value, trail.value) =>
+                {
+                    self.print_surrogate_pair(lead.value, trail.value)?;
+                    iter.next();
+                }
+                // `\1` followed by `0` would be `\10`.
+                (
+                    Element::Backreference(Backreference {
+                        reference: BackreferenceRef::Index(index),
+                        ..
+                    }),
+                    Some(Element::Character(c)),
+                ) if is_digit(c.value) => {
+                    write!(self.f, "\\{}\\x{:02x}", index, c.value)?;
+                    iter.next();
+                }
+                _ => self.print_element(element)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    fn print_element(&mut self, element: &Element) -> fmt::Result {
+        match element {
+            Element::Assertion(a) => self.f.write_str(match a.kind {
+                AssertionKind::Start => "^",
+                AssertionKind::End => "$",
+                AssertionKind::WordBoundary => "\\b",
+                AssertionKind::NonWordBoundary => "\\B",
+            }),
+            Element::Lookaround(l) => {
+                self.f.write_str(match (l.behind, l.negate) {
+                    (false, false) => "(?=",
+                    (false, true) => "(?!",
+                    (true, false) => "(?<=",
+                    (true, true) => "(?<!",
+                })?;
+                self.print_alternatives(&l.alternatives)?;
+                self.f.write_char(')')
+            }
+            Element::Quantifier(q) => {
+                self.print_element(&q.element)?;
+                match (q.min, q.max) {
+                    (0, None) => self.f.write_char('*')?,
+                    (1, None) => self.f.write_char('+')?,
+                    (0, Some(1)) => self.f.write_char('?')?,
+                    (min, None) => write!(self.f, "{{{},}}", min)?,
+                    (min, Some(max)) if min == max => write!(self.f, "{{{}}}", min)?,
+                    (min, Some(max)) => write!(self.f, "{{{},{}}}", min, max)?,
+                }
+                if !q.greedy {
+                    self.f.write_char('?')?;
+                }
+                Ok(())
+            }
+            Element::Group(g) => {
+                self.f.write_str("(?:")?;
+                self.print_alternatives(&g.alternatives)?;
+                self.f.write_char(')')
+            }
+            Element::CapturingGroup(g) => {
+                self.f.write_char('(')?;
+                if let Some(name) = &g.name {
+                    write!(self.f, "?<{}>", name)?;
+                }
+                self.print_alternatives(&g.alternatives)?;
+                self.f.write_char(')')
+            }
+            Element::Character(c) => self.print_char(c.value, false),
+            Element::CharacterClass(class) => self.print_class(class),
+            Element::CharacterSet(set) => self.print_set(set),
+            Element::Backreference(b) => match &b.reference {
+                BackreferenceRef::Index(index) => write!(self.f, "\\{}", index),
+                BackreferenceRef::Name(name) => write!(self.f, "\\k<{}>", name),
+            },
+        }
+    }
+
+    fn print_class(&mut self, class: &CharacterClass) -> fmt::Result {
+        self.f.write_char('[')?;
+        if class.negate {
+            self.f.write_char('^')?;
+        }
+
+        let mut iter = class.elements.iter().peekable();
+        while let Some(element) = iter.next() {
+            match (element, iter.peek()) {
+                (ClassElement::Character(lead), Some(ClassElement::Character(trail)))
+                    if !self.unicode && is_surrogate_pair(lead.value, trail.value) =>
+                {
+                    self.print_surrogate_pair(lead.value, trail.value)?;
+                    iter.next();
+                }
+                (ClassElement::Character(c), _) => self.print_char(c.value, true)?,
+                (ClassElement::Range(r), _) => {
+                    self.print_char(r.min.value, true)?;
+                    self.f.write_char('-')?;
+                    self.print_char(r.max.value, true)?;
+                }
+                (ClassElement::Set(set), _) => self.print_set(set)?,
+            }
+        }
+
+        self.f.write_char(']')
+    }
+
+    fn print_set(&mut self, set: &CharacterSet) -> fmt::Result {
+        let c = match &set.kind {
+            CharacterSetKind::Any => return self.f.write_char('.'),
+            CharacterSetKind::Digit => 'd',
+            CharacterSetKind::Space => 's',
+            CharacterSetKind::Word => 'w',
+            CharacterSetKind::Property { name, value } => {
+                write!(self.f, "\\{}{{{}", if set.negate { 'P' } else { 'p' }, name)?;
+                if let Some(value) = value {
+                    write!(self.f, "={}", value)?;
+                }
+                return self.f.write_char('}');
+            }
+        };
+
+        write!(
+            self.f,
+            "\\{}",
+            if set.negate {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        )
+    }
+
+    fn print_char(&mut self, value: u32, in_class: bool) -> fmt::Result {
+        let c = match std::char::from_u32(value) {
+            Some(c) => c,
+            // Surrogates
+            None if self.unicode => return write!(self.f, "\\u{{{:X}}}", value),
+            None => return write!(self.f, "\\u{:04X}", value),
+        };
+
+        match c {
+            '\n' => self.f.write_str("\\n"),
+            '\r' => self.f.write_str("\\r"),
+            '\t' => self.f.write_str("\\t"),
+            '\u{000b}' => self.f.write_str("\\v"),
+            '\u{000c}' => self.f.write_str("\\f"),
+            '\u{2028}' | '\u{2029}' => write!(self.f, "\\u{:04X}", value),
+            // `\0` can't be followed by a digit.
+            c if c.is_control() => write!(self.f, "\\x{:02x}", value),
+            c if is_invisible(c) && value <= 0xffff => write!(self.f, "\\u{:04X}", value),
+            c if is_invisible(c) && self.unicode => write!(self.f, "\\u{{{:X}}}", value),
+            '\\' | ']' | '[' | '-' | '^' | '/' if in_class => write!(self.f, "\\{}", c),
+            _ if in_class => self.f.write_char(c),
+            '^' | '$' | '\\' | '.' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{' | '}' | '|'
+            | '/' => write!(self.f, "\\{}", c),
+            c => self.f.write_char(c),
+        }
+    }
+
+    fn print_surrogate_pair(&mut self, lead: u32, trail: u32) -> fmt::Result {
+        let c = (lead - 0xd800) * 0x400 + (trail - 0xdc00) + 0x10000;
+        self.f.write_char(std::char::from_u32(c).unwrap())
+    }
+}
+
+fn is_surrogate_pair(lead: u32, trail: u32) -> bool {
+    (0xd800..=0xdbff).contains(&lead) && (0xdc00..=0xdfff).contains(&trail)
+}
+
+/// Whitespaces (except for the space), format characters and private use
+/// characters, which are confusing when printed as is.
+fn is_invisible(c: char) -> bool {
+    (c != ' ' && c.is_whitespace())
+        || matches!(
+            c,
+            '\u{ad}'
+                | '\u{200b}'..='\u{200f}'
+                | '\u{202a}'..='\u{202e}'
+                | '\u{2060}'..='\u{206f}'
+                | '\u{e000}'..='\u{f8ff}'
+                | '\u{feff}'
+                | '\u{fff0}'..='\u{ffff}'
+                | '\u{e0000}'..='\u{e007f}'
+                | '\u{f0000}'..='\u{10ffff}'
+        )
+}
+
+fn is_digit(c: u32) -> bool {
+    (b'0' as u32..=b'9' as u32).contains(&c)
+}
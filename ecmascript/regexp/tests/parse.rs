@@ -0,0 +1,186 @@
+use swc_common::BytePos;
+use swc_ecma_regexp::{ast::*, parse, ErrorKind};
+
+/// Parses a regular expression literal.
+fn parse_lit(s: &str) -> Result<Regexp, swc_ecma_regexp::Error> {
+    let slash = s.rfind('/').unwrap();
+    parse(BytePos(0), &s[1..slash], &s[slash + 1..])
+}
+
+/// Asserts that `src` is printed as `expected`, and that the output is
+/// printed as is.
+fn roundtrip(src: &str, expected: &str) {
+    let printed = match parse_lit(src) {
+        Ok(re) => re.to_string(),
+        Err(err) => panic!("failed to parse {}: {}", src, err),
+    };
+    assert_eq!(printed, expected, "printed {}", src);
+
+    let reprinted = parse_lit(&printed).unwrap().to_string();
+    assert_eq!(reprinted, printed, "reprinted {}", src);
+}
+
+fn same(src: &str) {
+    roundtrip(src, src)
+}
+
+fn error(src: &str, kind: ErrorKind) {
+    match parse_lit(src) {
+        Ok(re) => panic!("{} should be invalid, got {:?}", src, re),
+        Err(err) => assert_eq!(err.kind, kind, "{}", src),
+    }
+}
+
+#[test]
+fn print() {
+    same("/a|b|/");
+    same("/^a$/gimsuy");
+    same("/\\bfoo\\B/d");
+    same("/(?=a)(?!b)(?<=c)(?<!d)/");
+    same("/a*b+c?d{2}e{2,}f{2,3}g*?h+?/");
+    same("/(a)(?:b)(?<name>c)\\1\\k<name>/");
+    same("/[a-z\\d_][^\\s\\S]\\w\\W./");
+    same("/\\p{Letter}\\P{Script=Greek}\\p{ASCII}/u");
+    same("/\\//");
+    same("/[\\]\\-\\\\]/");
+    same("/😀/u");
+    same("/😀/");
+    same("/[😀]/");
+}
+
+#[test]
+fn print_normalized() {
+    roundtrip("//", "/(?:)/");
+    roundtrip("/a{0,}b{1,}c{0,1}/", "/a*b+c?/");
+    roundtrip("/\\x41\\u0042\\u{43}/u", "/ABC/u");
+    roundtrip("/\\0\\n\\cJ\\x07/", "/\\x00\\n\\n\\x07/");
+    roundtrip("/\\uD83D\\uDE00/u", "/😀/u");
+    roundtrip("/\\uD83D\\uDE00/", "/😀/");
+    roundtrip("/\\u{D83D}\\u{DE00}/u", "/\\u{D83D}\\u{DE00}/u");
+    roundtrip("/[\\b]/", "/[\\x08]/");
+    roundtrip("/(a)\\1\\x30/", "/(a)\\1\\x30/");
+    roundtrip("/\\u2028/", "/\\u2028/");
+    roundtrip("/\u{a0}\u{200b}[\u{feff}]/", "/\\u00A0\\u200B[\\uFEFF]/");
+    roundtrip("/\\u{F0000}/u", "/\\u{F0000}/u");
+}
+
+#[test]
+fn annex_b() {
+    roundtrip("/{/", "/\\{/");
+    roundtrip("/}/", "/\\}/");
+    roundtrip("/]/", "/\\]/");
+    roundtrip("/a{/", "/a\\{/");
+    roundtrip("/a{1/", "/a\\{1/");
+    roundtrip("/\\1/", "/\\x01/");
+    roundtrip("/\\8/", "/8/");
+    roundtrip("/\\012/", "/\\n/");
+    roundtrip("/\\c/", "/\\\\c/");
+    roundtrip("/[\\c_]/", "/[\\x1f]/");
+    roundtrip("/\\k/", "/k/");
+    roundtrip("/\\a\\-/", "/a-/");
+    roundtrip("/\\u{1}/", "/u{1}/");
+    roundtrip("/(?=a)*/", "/(?=a)*/");
+    roundtrip("/[\\d-z]/", "/[\\d\\-z]/");
+}
+
+#[test]
+fn backreference() {
+    // A group can be referenced before it is defined.
+    same("/\\1(a)/");
+    same("/\\k<a>(?<a>b)/");
+
+    let re = parse_lit("/(a)(b)\\2/").unwrap();
+    match &re.pattern.alternatives[0].elements[2] {
+        Element::Backreference(Backreference {
+            reference: BackreferenceRef::Index(2),
+            ..
+        }) => {}
+        e => panic!("expected a backreference, got {:?}", e),
+    }
+}
+
+#[test]
+fn surrogates() {
+    let re = parse_lit("/😀/").unwrap();
+    let elements = &re.pattern.alternatives[0].elements;
+    assert_eq!(elements.len(), 2);
+
+    let re = parse_lit("/😀/u").unwrap();
+    let elements = &re.pattern.alternatives[0].elements;
+    match &elements[..] {
+        [Element::Character(c)] => assert_eq!(c.value, 0x1f600),
+        _ => panic!("expected a character, got {:?}", elements),
+    }
+}
+
+#[test]
+fn span() {
+    let re = parse_lit("/a(b)/g").unwrap();
+    assert_eq!(re.span.lo(), BytePos(0));
+    assert_eq!(re.span.hi(), BytePos(7));
+    assert_eq!(re.pattern.span.lo(), BytePos(1));
+    assert_eq!(re.pattern.span.hi(), BytePos(5));
+    assert_eq!(re.flags.span.lo(), BytePos(6));
+
+    match &re.pattern.alternatives[0].elements[1] {
+        Element::CapturingGroup(g) => {
+            assert_eq!(g.span.lo(), BytePos(2));
+            assert_eq!(g.span.hi(), BytePos(5));
+        }
+        e => panic!("expected a group, got {:?}", e),
+    }
+
+    let err = parse_lit("/(?<a>.)(?<a>.)/").unwrap_err();
+    assert_eq!(err.span.lo(), BytePos(11));
+    assert_eq!(err.span.hi(), BytePos(13));
+}
+
+#[test]
+fn flags() {
+    error("/a/x", ErrorKind::InvalidFlag('x'));
+    error("/a/gg", ErrorKind::DuplicateFlag('g'));
+    error("/a/G", ErrorKind::InvalidFlag('G'));
+}
+
+#[test]
+fn errors() {
+    error("/*/", ErrorKind::NothingToRepeat);
+    error("/a|+/", ErrorKind::NothingToRepeat);
+    error("/^*/", ErrorKind::NothingToRepeat);
+    error("/a**/", ErrorKind::NothingToRepeat);
+    error("/{1}/", ErrorKind::NothingToRepeat);
+    error("/(?<=a)*/", ErrorKind::NothingToRepeat);
+    error("/a{2,1}/", ErrorKind::QuantifierOutOfOrder);
+    error("/a)/", ErrorKind::UnmatchedParen);
+    error("/(a/", ErrorKind::UnterminatedGroup);
+    error("/(?a)/", ErrorKind::InvalidGroup);
+    error("/[a/", ErrorKind::UnterminatedCharacterClass);
+    error("/[z-a]/", ErrorKind::RangeOutOfOrder);
+    error("/a\\/", ErrorKind::EscapeAtEndOfPattern);
+    error("/(?<1>a)/", ErrorKind::InvalidCaptureGroupName);
+    error("/(?<>a)/", ErrorKind::InvalidCaptureGroupName);
+    error("/(?<a>.)(?<a>.)/", ErrorKind::DuplicateCaptureGroupName);
+    error("/(?<a>.)\\k/", ErrorKind::InvalidNamedReference);
+    error("/(?<a>.)\\k<b>/", ErrorKind::InvalidNamedCaptureReference);
+}
+
+#[test]
+fn unicode_errors() {
+    error("/{/u", ErrorKind::LoneQuantifierBrackets);
+    error("/}/u", ErrorKind::LoneQuantifierBrackets);
+    error("/]/u", ErrorKind::LoneQuantifierBrackets);
+    error("/a{/u", ErrorKind::IncompleteQuantifier);
+    error("/(?=a)*/u", ErrorKind::NothingToRepeat);
+    error("/\\1/u", ErrorKind::InvalidEscape);
+    error("/\\01/u", ErrorKind::InvalidDecimalEscape);
+    error("/\\a/u", ErrorKind::InvalidEscape);
+    error("/[\\a]/u", ErrorKind::InvalidClassEscape);
+    error("/\\c/u", ErrorKind::InvalidUnicodeEscape);
+    error("/\\u{110000}/u", ErrorKind::InvalidUnicodeEscape);
+    error("/\\xZ/u", ErrorKind::InvalidEscape);
+    error("/[\\d-z]/u", ErrorKind::InvalidCharacterClass);
+    error("/\\k/u", ErrorKind::InvalidNamedReference);
+    error("/\\p{Foo}/u", ErrorKind::InvalidPropertyName);
+    error("/\\p{Letter=Greek}/u", ErrorKind::InvalidPropertyName);
+    error("/\\p/u", ErrorKind::InvalidPropertyName);
+}
@@ -3,8 +3,12 @@ pub use swc_ecma_ast as ast;
 pub use swc_ecma_codegen as codegen;
 #[cfg(feature = "dep_graph")]
 pub use swc_ecma_dep_graph as dep_graph;
+#[cfg(feature = "minifier")]
+pub use swc_ecma_minifier as minifier;
 #[cfg(feature = "parser")]
 pub use swc_ecma_parser as parser;
+#[cfg(feature = "regexp")]
+pub use swc_ecma_regexp as regexp;
 #[cfg(feature = "transforms")]
 pub use swc_ecma_transforms as transforms;
 #[cfg(feature = "utils")]
@@ -0,0 +1,6 @@
+function _checkInRHS(value) {
+  if (Object(value) !== value) {
+    throw new TypeError("right-hand side of 'in' should be an object, got " + (value !== null ? typeof value : "null"));
+  }
+  return value;
+}
@@ -168,6 +168,7 @@ define_helpers!(Helpers {
     async_to_generator: (),
     await_async_generator: (await_value),
     await_value: (),
+    check_in_rhs: (),
     class_call_check: (),
     class_name_tdz_error: (),
     class_private_field_get: (),
@@ -234,6 +235,7 @@ define_helpers!(Helpers {
         set_prototype_of,
         is_native_function
     ),
+    wrap_reg_exp: (inherits, set_prototype_of),
 
     class_private_field_destructure: (),
 });
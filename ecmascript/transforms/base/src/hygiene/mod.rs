@@ -7,12 +7,11 @@ use crate::scope::ScopeKind;
 use fxhash::{FxHashMap, FxHashSet};
 use smallvec::{smallvec, SmallVec};
 use std::cell::RefCell;
-use std::collections::HashMap;
 use swc_atoms::js_word;
 use swc_atoms::JsWord;
 use swc_common::{chain, SyntaxContext};
 use swc_ecma_ast::*;
-use swc_ecma_utils::ident::IdentLike;
+use swc_ecma_utils::{ident::IdentLike, Id};
 use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
 
 mod ops;
@@ -202,6 +201,16 @@ pub fn hygiene_with_config(config: Config) -> impl Fold + 'static {
     )
 }
 
+/// Renames identifiers in `map`.
+///
+/// Keys of `map` should have span hygiene information (e.g. added by
+/// [resolver](crate::resolver)). Renamed identifiers lose their syntax context,
+/// and exports or shorthand properties are rewritten so that names visible
+/// from outside are preserved.
+pub fn rename(map: &FxHashMap<Id, JsWord>) -> impl '_ + VisitMut {
+    Operator(map)
+}
+
 #[derive(Clone, Copy)]
 struct MarkClearer;
 impl VisitMut for MarkClearer {
@@ -222,7 +231,7 @@ impl<'a> Hygiene<'a> {
         if ops.rename.is_empty() {
             return;
         }
-        node.visit_mut_with(&mut Operator(&ops))
+        node.visit_mut_with(&mut Operator(&ops.rename))
     }
 
     fn keep_class_name(&mut self, ident: &mut Ident, class: &mut Class) -> Option<ClassExpr> {
@@ -235,12 +244,11 @@ impl<'a> Hygiene<'a> {
 
         {
             // Remove span hygiene of the class.
-            let mut rename = HashMap::default();
+            let mut rename = FxHashMap::default();
 
             rename.insert(ident.to_id(), orig_name.sym.clone());
 
-            let ops = Operations { rename };
-            let mut operator = Operator(&ops);
+            let mut operator = Operator(&rename);
 
             class.visit_mut_with(&mut operator);
         }
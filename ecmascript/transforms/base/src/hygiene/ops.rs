@@ -10,7 +10,7 @@ pub(super) struct Operations {
     pub rename: FxHashMap<Id, JsWord>,
 }
 
-pub(super) struct Operator<'a>(pub &'a Operations);
+pub(super) struct Operator<'a>(pub &'a FxHashMap<Id, JsWord>);
 
 impl<'a> VisitMut for Operator<'a> {
     noop_visit_mut_type!();
@@ -308,7 +308,7 @@ impl VisitMut for VarFolder<'_, '_> {
 impl<'a> Operator<'a> {
     /// Returns `Ok(renamed_ident)` if ident should be renamed.
     fn rename_ident(&mut self, ident: &mut Ident) -> Result<(), ()> {
-        if let Some(sym) = self.0.rename.get(&ident.to_id()) {
+        if let Some(sym) = self.0.get(&ident.to_id()) {
             ident.span = ident.span.with_ctxt(SyntaxContext::empty());
             ident.sym = sym.clone();
             return Ok(());
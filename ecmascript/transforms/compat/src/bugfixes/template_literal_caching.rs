@@ -99,6 +99,7 @@ impl Fold for TemplateLiteralCaching {
                                 Box::new(Expr::Lit(Lit::Num(Number {
                                     span: DUMMY_SP,
                                     value: 0.0,
+                                    raw: None,
                                 })))
                             })
                             .collect(),
@@ -4,7 +4,7 @@ pub use self::{
     duplicate_keys::duplicate_keys, for_of::for_of, function_name::function_name,
     instanceof::instance_of, parameters::parameters, regenerator::regenerator,
     shorthand_property::shorthand, spread::spread, sticky_regex::sticky_regex,
-    template_literal::template_literal, typeof_symbol::typeof_symbol,
+    template_literal::template_literal, typeof_symbol::typeof_symbol, unicode_regex::unicode_regex,
 };
 use serde::Deserialize;
 use swc_common::{chain, Mark};
@@ -27,11 +27,13 @@ pub mod spread;
 mod sticky_regex;
 mod template_literal;
 mod typeof_symbol;
+mod unicode_regex;
 
 fn exprs() -> impl Fold {
     chain!(
         arrow(),
         duplicate_keys(),
+        unicode_regex(),
         sticky_regex(),
         instance_of(),
         typeof_symbol(),
@@ -382,6 +382,7 @@ impl Classes {
 
         let mut priv_methods = vec![];
         let mut methods = vec![];
+        let mut static_blocks = vec![];
         let mut constructor = None;
         for member in class.body {
             match member {
@@ -401,6 +402,7 @@ impl Classes {
                 ClassMember::PrivateProp(..) => unreachable!(
                     "classes pass: private property\nclass_properties pass should remove this"
                 ),
+                ClassMember::StaticBlock(b) => static_blocks.push(b),
                 ClassMember::TsIndexSignature(..) => {
                     // We just strip this.
                 }
@@ -574,6 +576,42 @@ impl Classes {
         // stmts.extend(self.fold_class_methods(class_name.clone(), priv_methods));
         stmts.extend(self.fold_class_methods(class_name.clone(), methods));
 
+        // `static { ... }` is normally lowered by the static_blocks pass, but
+        // this pass may be used without it.
+        //
+        // (function () { ... }).call(Foo);
+        for block in static_blocks {
+            let function = fold_method_super_access(
+                &class_name,
+                Function {
+                    params: vec![],
+                    decorators: vec![],
+                    span: block.span,
+                    body: Some(block.body),
+                    is_generator: false,
+                    is_async: false,
+                    type_params: None,
+                    return_type: None,
+                },
+                true,
+            );
+
+            stmts.push(
+                CallExpr {
+                    span: DUMMY_SP,
+                    callee: FnExpr {
+                        ident: None,
+                        function,
+                    }
+                    .make_member(quote_ident!("call"))
+                    .as_callee(),
+                    args: vec![class_name.clone().as_arg()],
+                    type_args: Default::default(),
+                }
+                .into_stmt(),
+            );
+        }
+
         if stmts.first().map(|v| !v.is_use_strict()).unwrap_or(false) && !self.in_strict {
             prepend(
                 &mut stmts,
@@ -686,7 +724,7 @@ impl Classes {
                         Box::new(Expr::Lit(Lit::Str(quote_str!(i.span, i.sym.clone()))))
                     }
                     PropName::Str(ref s) => Box::new(Expr::Lit(Lit::Str(s.clone()))),
-                    PropName::Num(n) => Box::new(Expr::Lit(Lit::Num(n))),
+                    PropName::Num(ref n) => Box::new(Expr::Lit(Lit::Num(n.clone()))),
                     PropName::BigInt(ref b) => Box::new(Expr::Lit(Lit::BigInt(b.clone()))),
                     PropName::Computed(ref c) => c.expr.clone(),
                 },
@@ -768,49 +806,7 @@ impl Classes {
                 &mut props
             };
 
-            let mut vars = vec![];
-            let mut folder = SuperFieldAccessFolder {
-                class_name: &class_name,
-                vars: &mut vars,
-                constructor_this_mark: None,
-                is_static: m.is_static,
-                folding_constructor: false,
-                in_nested_scope: false,
-                in_injected_define_property_call: false,
-                this_alias_mark: None,
-            };
-            let mut function = m.function.fold_with(&mut folder);
-
-            if let Some(mark) = folder.this_alias_mark {
-                prepend(
-                    &mut function.body.as_mut().unwrap().stmts,
-                    Stmt::Decl(Decl::Var(VarDecl {
-                        span: DUMMY_SP,
-                        declare: false,
-                        kind: VarDeclKind::Var,
-                        decls: vec![VarDeclarator {
-                            span: DUMMY_SP,
-                            name: Pat::Ident(
-                                quote_ident!(DUMMY_SP.apply_mark(mark), "_this").into(),
-                            ),
-                            init: Some(Box::new(Expr::This(ThisExpr { span: DUMMY_SP }))),
-                            definite: false,
-                        }],
-                    })),
-                );
-            }
-
-            if !vars.is_empty() {
-                prepend(
-                    &mut function.body.as_mut().unwrap().stmts,
-                    Stmt::Decl(Decl::Var(VarDecl {
-                        span: DUMMY_SP,
-                        kind: VarDeclKind::Var,
-                        declare: false,
-                        decls: vars,
-                    })),
-                );
-            }
+            let function = fold_method_super_access(&class_name, m.function, m.is_static);
 
             let value = Box::new(Expr::Fn(FnExpr {
                 ident: if m.kind == MethodKind::Method && !computed {
@@ -927,3 +923,50 @@ fn is_always_initialized(body: &[Stmt]) -> bool {
 
     true
 }
+
+/// Handles `super` in a method.
+fn fold_method_super_access(class_name: &Ident, function: Function, is_static: bool) -> Function {
+    let mut vars = vec![];
+    let mut folder = SuperFieldAccessFolder {
+        class_name,
+        vars: &mut vars,
+        constructor_this_mark: None,
+        is_static,
+        folding_constructor: false,
+        in_nested_scope: false,
+        in_injected_define_property_call: false,
+        this_alias_mark: None,
+    };
+    let mut function = function.fold_with(&mut folder);
+
+    if let Some(mark) = folder.this_alias_mark {
+        prepend(
+            &mut function.body.as_mut().unwrap().stmts,
+            Stmt::Decl(Decl::Var(VarDecl {
+                span: DUMMY_SP,
+                declare: false,
+                kind: VarDeclKind::Var,
+                decls: vec![VarDeclarator {
+                    span: DUMMY_SP,
+                    name: Pat::Ident(quote_ident!(DUMMY_SP.apply_mark(mark), "_this").into()),
+                    init: Some(Box::new(Expr::This(ThisExpr { span: DUMMY_SP }))),
+                    definite: false,
+                }],
+            })),
+        );
+    }
+
+    if !vars.is_empty() {
+        prepend(
+            &mut function.body.as_mut().unwrap().stmts,
+            Stmt::Decl(Decl::Var(VarDecl {
+                span: DUMMY_SP,
+                kind: VarDeclKind::Var,
+                declare: false,
+                decls: vars,
+            })),
+        );
+    }
+
+    function
+}
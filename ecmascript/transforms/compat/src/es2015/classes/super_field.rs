@@ -125,6 +125,7 @@ impl<'a> Fold for SuperCalleeFolder<'a> {
                         Box::new(Expr::Lit(Lit::Num(Number {
                             span: DUMMY_SP,
                             value: 1.0,
+                            raw: None,
                         }))),
                     )
                 }
@@ -243,6 +243,7 @@ impl AssignFolder {
                                 args: vec![Lit::Num(Number {
                                     value: i as f64,
                                     span: dot3_token,
+                                    raw: None,
                                 })
                                 .as_arg()],
                                 type_args: Default::default(),
@@ -1013,6 +1014,7 @@ fn make_ref_ident_for_array(
                                     Lit::Num(Number {
                                         span: DUMMY_SP,
                                         value: value as _,
+                                        raw: None,
                                     })
                                     .as_arg(),
                                 ],
@@ -1058,6 +1060,7 @@ fn make_cond_expr(tmp: Ident, def_value: Box<Expr>) -> Expr {
                 arg: Box::new(Expr::Lit(Lit::Num(Number {
                     span: DUMMY_SP,
                     value: 0.0,
+                    raw: None,
                 }))),
             })),
         })),
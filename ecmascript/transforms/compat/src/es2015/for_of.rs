@@ -117,6 +117,7 @@ impl Actual {
                 init: Some(Box::new(Expr::Lit(Lit::Num(Number {
                     span: DUMMY_SP,
                     value: 0f64,
+                    raw: None,
                 })))),
                 definite: false,
             });
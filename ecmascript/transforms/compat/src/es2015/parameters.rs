@@ -100,6 +100,7 @@ impl Params {
                                 right: Box::new(Expr::Lit(Lit::Num(Number {
                                     span,
                                     value: i as f64,
+                                    raw: None,
                                 }))),
                             }
                             .into();
@@ -117,12 +118,17 @@ impl Params {
                                         right: Box::new(Expr::Lit(Lit::Num(Number {
                                             span,
                                             value: i as _,
+                                            raw: None,
                                         }))),
                                     }
                                     .into(),
                                 ),
                                 cons: Box::new(bin),
-                                alt: Box::new(Expr::Lit(Lit::Num(Number { span, value: 0.0 }))),
+                                alt: Box::new(Expr::Lit(Lit::Num(Number {
+                                    span,
+                                    value: 0.0,
+                                    raw: None,
+                                }))),
                             })
                         }
                     };
@@ -162,6 +168,7 @@ impl Params {
                                     init: Some(Box::new(Expr::Lit(Lit::Num(Number {
                                         span,
                                         value: i as f64,
+                                        raw: None,
                                     })))),
                                     definite: false,
                                 },
@@ -28,6 +28,7 @@ impl Loc {
         Expr::Lit(Lit::Num(Number {
             span: DUMMY_SP,
             value: self.stmt_index as _,
+            raw: None,
         }))
     }
 
@@ -444,6 +445,7 @@ impl CaseHandler<'_> {
                                             Lit::Num(Number {
                                                 span: DUMMY_SP,
                                                 value: 0.0,
+                                                raw: None,
                                             })
                                             .into(),
                                         ),
@@ -796,6 +798,7 @@ impl CaseHandler<'_> {
                 test: Some(Box::new(Expr::Lit(Lit::Num(Number {
                     span: DUMMY_SP,
                     value: i as _,
+                    raw: None,
                 })))),
                 cons: vec![],
             };
@@ -1522,6 +1525,7 @@ impl Fold for UnmarkedInvalidHandler {
             Expr::Invalid(Invalid { span }) => Expr::Lit(Lit::Num(Number {
                 span,
                 value: self.case_id as _,
+                raw: None,
             })),
             _ => e,
         }
@@ -1549,6 +1553,7 @@ impl Fold for InvalidToLit<'_> {
                         return Expr::Lit(Lit::Num(Number {
                             span: DUMMY_SP,
                             value: (*stmt_index) as _,
+                            raw: None,
                         }));
                     }
                 }
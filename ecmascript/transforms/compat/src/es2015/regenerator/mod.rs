@@ -347,6 +347,7 @@ impl Regenerator {
             test: Some(Box::new(Expr::Lit(Lit::Num(Number {
                 span: DUMMY_SP,
                 value: handler.final_loc() as _,
+                raw: None,
             })))),
             // fallthrough
             cons: vec![],
@@ -377,6 +378,7 @@ impl Regenerator {
             test: Box::new(Expr::Lit(Lit::Num(Number {
                 span: DUMMY_SP,
                 value: 1.0,
+                raw: None,
             }))),
             body: Box::new(
                 SwitchStmt {
@@ -0,0 +1,241 @@
+use crate::regexp::{
+    character, transform_regex, visit_element_lists, CodePointSet, MAX_CODE_POINT,
+};
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::Regex;
+use swc_ecma_regexp::ast::*;
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut};
+
+/// Compiles regular expressions with the `u` flag to ones without it.
+///
+/// # Example
+///
+/// ## In
+///
+/// ```js
+/// /[😀-😂]+/u;
+/// ```
+///
+/// ## Out
+///
+/// ```js
+/// /(?:\uD83D[\uDE00-\uDE02])+/;
+/// ```
+///
+/// Case folding of the `i` flag is not emulated, so `/ſ/iu` does not
+/// match `s` after the transform. Also, a lone surrogate matched by `.` or a
+/// negated class may be a half of a surrogate pair on backtracking.
+pub fn unicode_regex() -> impl 'static + Fold {
+    as_folder(UnicodeRegex)
+}
+
+struct UnicodeRegex;
+
+impl VisitMut for UnicodeRegex {
+    noop_visit_mut_type!();
+
+    fn visit_mut_regex(&mut self, regex: &mut Regex) {
+        if !regex.flags.contains('u') {
+            return;
+        }
+
+        transform_regex(regex, |re| {
+            let dot_all = re.flags.dot_all;
+            let mut ok = true;
+            visit_element_lists(&mut re.pattern.alternatives, &mut |elements| {
+                ok &= lower_elements(elements, dot_all);
+            });
+            if !ok {
+                return false;
+            }
+
+            re.flags.unicode = false;
+            true
+        });
+    }
+}
+
+/// Returns false if an element cannot be lowered.
+fn lower_elements(elements: &mut Vec<Element>, dot_all: bool) -> bool {
+    let mut buf = Vec::with_capacity(elements.len());
+
+    for element in elements.drain(..) {
+        match element {
+            // A character outside of the BMP becomes a surrogate pair.
+            Element::Character(c) if c.value > 0xffff => {
+                let (lead, trail) = to_surrogates(c.value);
+                buf.push(Element::Character(character(lead)));
+                buf.push(Element::Character(character(trail)));
+            }
+            Element::Quantifier(mut q) => {
+                let element = match *q.element {
+                    Element::Character(c) if c.value > 0xffff => {
+                        let (lead, trail) = to_surrogates(c.value);
+                        Some(Element::Group(Group {
+                            span: c.span,
+                            alternatives: vec![Alternative {
+                                span: c.span,
+                                elements: vec![
+                                    Element::Character(character(lead)),
+                                    Element::Character(character(trail)),
+                                ],
+                            }],
+                        }))
+                    }
+                    ref element => match lower_atom(element, dot_all) {
+                        Ok(element) => element,
+                        Err(()) => return false,
+                    },
+                };
+                if let Some(element) = element {
+                    q.element = Box::new(element);
+                }
+                buf.push(Element::Quantifier(q));
+            }
+            element => match lower_atom(&element, dot_all) {
+                // Unquantified, so the group is not required.
+                Ok(Some(Element::Group(mut g))) if g.alternatives.len() == 1 => {
+                    buf.extend(g.alternatives.pop().unwrap().elements)
+                }
+                Ok(Some(lowered)) => buf.push(lowered),
+                Ok(None) => buf.push(element),
+                Err(()) => return false,
+            },
+        }
+    }
+
+    *elements = buf;
+    true
+}
+
+/// Returns `Ok(None)` if `element` matches the same code units without the
+/// `u` flag.
+fn lower_atom(element: &Element, dot_all: bool) -> Result<Option<Element>, ()> {
+    let set = match element {
+        Element::CharacterSet(set) => match set.kind {
+            CharacterSetKind::Digit | CharacterSetKind::Space | CharacterSetKind::Word
+                if !set.negate =>
+            {
+                return Ok(None)
+            }
+            _ => CodePointSet::from_set(&set.kind, set.negate, dot_all),
+        },
+        Element::CharacterClass(class) => match CodePointSet::from_class(class) {
+            // A negated class matches code points outside of the BMP.
+            Some(set) if !class.negate && set.slice(0x10000, MAX_CODE_POINT).is_empty() => {
+                return Ok(None)
+            }
+            set => set,
+        },
+        _ => return Ok(None),
+    };
+
+    let set = match set {
+        Some(set) => set,
+        None => return Err(()),
+    };
+
+    // `.`, `\S` or `[^a]` can be kept for the BMP, which is more readable.
+    if set.slice(0x10000, MAX_CODE_POINT).ranges() == [(0x10000, MAX_CODE_POINT)]
+        && is_bmp_only(element)
+    {
+        let astral = CodePointSet::from_ranges(vec![(0x10000, MAX_CODE_POINT)]);
+        if let Element::Group(mut group) = to_code_units(&astral) {
+            group.alternatives.push(Alternative {
+                span: DUMMY_SP,
+                elements: vec![element.clone()],
+            });
+            return Ok(Some(Element::Group(group)));
+        }
+    }
+
+    Ok(Some(to_code_units(&set)))
+}
+
+/// Returns true if `element` matches the same code units in the BMP without
+/// the `u` flag.
+fn is_bmp_only(element: &Element) -> bool {
+    match element {
+        Element::CharacterSet(set) => !matches!(set.kind, CharacterSetKind::Property { .. }),
+        Element::CharacterClass(class) => class.elements.iter().all(|e| match e {
+            ClassElement::Character(c) => c.value <= 0xffff,
+            ClassElement::Range(r) => r.max.value <= 0xffff,
+            ClassElement::Set(set) => !matches!(set.kind, CharacterSetKind::Property { .. }),
+        }),
+        _ => false,
+    }
+}
+
+/// Builds an element matching code points of `set`, using surrogate pairs for
+/// code points outside of the BMP.
+fn to_code_units(set: &CodePointSet) -> Element {
+    let bmp = set.slice(0, 0xffff);
+    let astral = set.slice(0x10000, MAX_CODE_POINT);
+    if astral.is_empty() {
+        return Element::CharacterClass(bmp.to_class());
+    }
+
+    let mut alternatives = vec![];
+    let mut alt = |lead: (u32, u32), trail: (u32, u32)| {
+        let elements = [lead, trail]
+            .iter()
+            .map(|&(lo, hi)| {
+                if lo == hi {
+                    Element::Character(character(lo))
+                } else {
+                    Element::CharacterClass(CodePointSet::from_ranges(vec![(lo, hi)]).to_class())
+                }
+            })
+            .collect();
+        alternatives.push(Alternative {
+            span: DUMMY_SP,
+            elements,
+        });
+    };
+
+    // Surrogate pairs come first, so that a lead surrogate matched by `bmp`
+    // does not split a pair.
+    for &(lo, hi) in astral.ranges() {
+        let (mut lo_lead, lo_trail) = to_surrogates(lo);
+        let (mut hi_lead, hi_trail) = to_surrogates(hi);
+
+        if lo_lead == hi_lead {
+            alt((lo_lead, lo_lead), (lo_trail, hi_trail));
+            continue;
+        }
+
+        if lo_trail != 0xdc00 {
+            alt((lo_lead, lo_lead), (lo_trail, 0xdfff));
+            lo_lead += 1;
+        }
+        let tail = if hi_trail != 0xdfff {
+            hi_lead -= 1;
+            Some((hi_lead + 1, hi_trail))
+        } else {
+            None
+        };
+        if lo_lead <= hi_lead {
+            alt((lo_lead, hi_lead), (0xdc00, 0xdfff));
+        }
+        if let Some((lead, trail)) = tail {
+            alt((lead, lead), (0xdc00, trail));
+        }
+    }
+
+    if !bmp.is_empty() {
+        alternatives.push(Alternative {
+            span: DUMMY_SP,
+            elements: vec![Element::CharacterClass(bmp.to_class())],
+        });
+    }
+
+    Element::Group(Group {
+        span: DUMMY_SP,
+        alternatives,
+    })
+}
+
+fn to_surrogates(c: u32) -> (u32, u32) {
+    let c = c - 0x10000;
+    (0xd800 + (c >> 10), 0xdc00 + (c & 0x3ff))
+}
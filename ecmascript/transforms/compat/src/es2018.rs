@@ -1,12 +1,23 @@
 pub use self::{
+    dotall_regex::dotall_regex, named_capturing_groups_regex::named_capturing_groups_regex,
     object_rest_spread::object_rest_spread, optional_catch_binding::optional_catch_binding,
+    unicode_property_regex::unicode_property_regex,
 };
 use swc_common::chain;
 use swc_ecma_visit::Fold;
 
+mod dotall_regex;
+mod named_capturing_groups_regex;
 mod object_rest_spread;
 mod optional_catch_binding;
+mod unicode_property_regex;
 
 pub fn es2018() -> impl Fold {
-    chain!(object_rest_spread(), optional_catch_binding())
+    chain!(
+        named_capturing_groups_regex(),
+        unicode_property_regex(),
+        dotall_regex(),
+        object_rest_spread(),
+        optional_catch_binding()
+    )
 }
@@ -0,0 +1,68 @@
+use crate::regexp::{transform_regex, visit_elements};
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::Regex;
+use swc_ecma_regexp::ast::*;
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut};
+
+/// Compiles the `s` flag of regular expressions.
+///
+/// # Example
+///
+/// ## In
+///
+/// ```js
+/// /a.b/s;
+/// ```
+///
+/// ## Out
+///
+/// ```js
+/// /a[\s\S]b/;
+/// ```
+pub fn dotall_regex() -> impl 'static + Fold {
+    as_folder(DotAllRegex)
+}
+
+struct DotAllRegex;
+
+impl VisitMut for DotAllRegex {
+    noop_visit_mut_type!();
+
+    fn visit_mut_regex(&mut self, regex: &mut Regex) {
+        if !regex.flags.contains('s') {
+            return;
+        }
+
+        transform_regex(regex, |re| {
+            visit_elements(&mut re.pattern.alternatives, &mut |element| {
+                if let Element::CharacterSet(CharacterSet {
+                    kind: CharacterSetKind::Any,
+                    ..
+                }) = element
+                {
+                    *element = Element::CharacterClass(any_char());
+                }
+            });
+
+            re.flags.dot_all = false;
+            true
+        });
+    }
+}
+
+/// `[\s\S]`
+fn any_char() -> CharacterClass {
+    let space = |negate| {
+        ClassElement::Set(CharacterSet {
+            span: DUMMY_SP,
+            kind: CharacterSetKind::Space,
+            negate,
+        })
+    };
+
+    CharacterClass {
+        span: DUMMY_SP,
+        negate: false,
+        elements: vec![space(false), space(true)],
+    }
+}
@@ -0,0 +1,115 @@
+use crate::regexp::{transform_regex, visit_elements};
+use swc_atoms::JsWord;
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_regexp::ast::{Alternative, BackreferenceRef, Element};
+use swc_ecma_transforms_base::helper;
+use swc_ecma_utils::{quote_ident, ExprFactory};
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
+
+/// Compiles named capturing groups to numbered ones.
+///
+/// # Example
+///
+/// ## In
+///
+/// ```js
+/// /(?<year>\d{4})-\k<year>/;
+/// ```
+///
+/// ## Out
+///
+/// ```js
+/// _wrapRegExp(/(\d{4})-\1/, {
+///     year: 1
+/// });
+/// ```
+///
+/// `_wrapRegExp` populates `groups` of match results and supports `$<name>`
+/// in the replacement of `String.prototype.replace`.
+pub fn named_capturing_groups_regex() -> impl 'static + Fold {
+    as_folder(NamedCapturingGroupsRegex)
+}
+
+struct NamedCapturingGroupsRegex;
+
+impl VisitMut for NamedCapturingGroupsRegex {
+    noop_visit_mut_type!();
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        e.visit_mut_children_with(self);
+
+        let regex = match e {
+            Expr::Lit(Lit::Regex(regex)) if regex.exp.contains("(?<") => regex,
+            _ => return,
+        };
+
+        let mut groups = vec![];
+        transform_regex(regex, |re| {
+            groups = strip_names(&mut re.pattern.alternatives);
+            !groups.is_empty()
+        });
+        if groups.is_empty() {
+            return;
+        }
+
+        let span = regex.span;
+        let regex = Expr::Lit(Lit::Regex(regex.clone()));
+        let groups = ObjectLit {
+            span: DUMMY_SP,
+            props: groups
+                .into_iter()
+                .map(|(name, index)| {
+                    PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                        key: PropName::Ident(quote_ident!(name)),
+                        value: Box::new(Expr::Lit(Lit::Num(Number {
+                            span: DUMMY_SP,
+                            value: index as f64,
+                            raw: None,
+                        }))),
+                    })))
+                })
+                .collect(),
+        };
+
+        *e = Expr::Call(CallExpr {
+            span,
+            callee: helper!(wrap_reg_exp, "wrapRegExp"),
+            args: vec![regex.as_arg(), groups.as_arg()],
+            type_args: Default::default(),
+        });
+    }
+}
+
+/// Removes names of capturing groups and replaces named backreferences with
+/// numbered ones.
+///
+/// Returns the names and indices of groups.
+fn strip_names(alternatives: &mut [Alternative]) -> Vec<(JsWord, u32)> {
+    let mut groups = vec![];
+    let mut index = 0;
+    visit_elements(alternatives, &mut |element| {
+        if let Element::CapturingGroup(group) = element {
+            index += 1;
+            if let Some(name) = group.name.take() {
+                groups.push((name, index));
+            }
+        }
+    });
+
+    if groups.is_empty() {
+        return groups;
+    }
+
+    visit_elements(alternatives, &mut |element| {
+        if let Element::Backreference(b) = element {
+            if let BackreferenceRef::Name(name) = &b.reference {
+                // The parser rejects references to unknown groups.
+                let index = groups.iter().find(|g| g.0 == *name).unwrap().1;
+                b.reference = BackreferenceRef::Index(index);
+            }
+        }
+    });
+
+    groups
+}
@@ -825,7 +825,7 @@ impl RestFolder {
                             let value = value.clone();
                             (key, Box::new(Expr::Ident(quote_ident!(span, value))))
                         }
-                        PropName::Num(Number { span, value }) => (
+                        PropName::Num(Number { span, value, .. }) => (
                             key,
                             Box::new(Expr::Lit(Lit::Str(Str {
                                 span,
@@ -961,7 +961,7 @@ fn object_without_properties(obj: Box<Expr>, excluded_props: Vec<Option<ExprOrSp
         .into_iter()
         .map(|v| {
             v.map(|v| match *v.expr {
-                Expr::Lit(Lit::Num(Number { span, value })) => ExprOrSpread {
+                Expr::Lit(Lit::Num(Number { span, value, .. })) => ExprOrSpread {
                     expr: Box::new(Expr::Lit(Lit::Str(Str {
                         span,
                         value: value.to_string().into(),
@@ -1020,7 +1020,7 @@ fn excluded_props(props: &[ObjectPatProp]) -> Vec<Option<ExprOrSpread>> {
                 })
                 .as_arg(),
                 PropName::Str(s) => Lit::Str(s.clone()).as_arg(),
-                PropName::Num(Number { span, value }) => Lit::Str(Str {
+                PropName::Num(Number { span, value, .. }) => Lit::Str(Str {
                     span: *span,
                     value: format!("{}", value).into(),
                     has_escape: false,
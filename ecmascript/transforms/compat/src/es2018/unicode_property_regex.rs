@@ -0,0 +1,75 @@
+use crate::regexp::{transform_regex, visit_elements, CodePointSet};
+use swc_ecma_ast::Regex;
+use swc_ecma_regexp::ast::*;
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut};
+
+/// Compiles unicode property escapes to character classes.
+///
+/// # Example
+///
+/// ## In
+///
+/// ```js
+/// /\p{ASCII_Hex_Digit}/u;
+/// ```
+///
+/// ## Out
+///
+/// ```js
+/// /[0-9A-Fa-f]/u;
+/// ```
+///
+/// The `u` flag is kept. It is compiled by
+/// [unicode_regex](crate::es2015::unicode_regex).
+pub fn unicode_property_regex() -> impl 'static + Fold {
+    as_folder(UnicodePropertyRegex)
+}
+
+struct UnicodePropertyRegex;
+
+impl VisitMut for UnicodePropertyRegex {
+    noop_visit_mut_type!();
+
+    fn visit_mut_regex(&mut self, regex: &mut Regex) {
+        if !regex.flags.contains('u') {
+            return;
+        }
+        if !regex.exp.contains("\\p") && !regex.exp.contains("\\P") {
+            return;
+        }
+
+        transform_regex(regex, |re| {
+            let mut ok = true;
+            visit_elements(&mut re.pattern.alternatives, &mut |element| match element {
+                Element::CharacterSet(set) if is_property(set) => {
+                    match CodePointSet::from_set(&set.kind, set.negate, false) {
+                        Some(set) => *element = Element::CharacterClass(set.to_class()),
+                        None => ok = false,
+                    }
+                }
+                Element::CharacterClass(class) => {
+                    let mut elements = Vec::with_capacity(class.elements.len());
+                    for element in class.elements.drain(..) {
+                        match element {
+                            ClassElement::Set(set) if is_property(&set) => {
+                                match CodePointSet::from_set(&set.kind, set.negate, false) {
+                                    Some(set) => elements.extend(set.to_class_elements()),
+                                    None => ok = false,
+                                }
+                            }
+                            _ => elements.push(element),
+                        }
+                    }
+                    class.elements = elements;
+                }
+                _ => {}
+            });
+
+            ok
+        });
+    }
+}
+
+fn is_property(set: &CharacterSet) -> bool {
+    matches!(set.kind, CharacterSetKind::Property { .. })
+}
@@ -316,12 +316,14 @@ impl ClassProperties {
         let mut used_names = vec![];
         let mut used_key_names = vec![];
         let mut statics = HashSet::default();
+        let mut private_props = HashSet::default();
 
         for member in class.body {
             match member {
                 ClassMember::PrivateMethod(..)
                 | ClassMember::Empty(..)
-                | ClassMember::TsIndexSignature(..) => members.push(member),
+                | ClassMember::TsIndexSignature(..)
+                | ClassMember::StaticBlock(..) => members.push(member),
 
                 ClassMember::Method(method) => {
                     // we handle computed key here to preserve the execution order
@@ -512,6 +514,7 @@ impl ClassProperties {
                     if prop.is_static {
                         statics.insert(prop.key.id.sym.clone());
                     }
+                    private_props.insert(prop.key.id.sym.clone());
 
                     let ident = Ident::new(
                         format!("_{}", prop.key.id.sym).into(),
@@ -666,6 +669,7 @@ impl ClassProperties {
         let members = members.fold_with(&mut FieldAccessFolder {
             mark: self.mark,
             statics: &statics,
+            private_props: &private_props,
             vars: vec![],
             class_name: &ident,
             in_assign_pat: false,
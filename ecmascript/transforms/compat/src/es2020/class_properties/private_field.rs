@@ -14,6 +14,8 @@ pub(super) struct FieldAccessFolder<'a> {
     pub class_name: &'a Ident,
     pub vars: Vec<VarDeclarator>,
     pub statics: &'a HashSet<JsWord>,
+    /// Private properties of the class. Private methods are not lowered.
+    pub private_props: &'a HashSet<JsWord>,
     pub in_assign_pat: bool,
 }
 
@@ -156,6 +158,7 @@ impl<'a> Fold for FieldAccessFolder<'a> {
                         right: Box::new(Expr::Lit(Lit::Num(Number {
                             span: DUMMY_SP,
                             value: 1.0,
+                            raw: None,
                         }))),
                     }
                     .as_arg()
@@ -380,6 +383,59 @@ impl<'a> Fold for FieldAccessFolder<'a> {
                 let e = e.fold_with(self);
                 self.fold_private_get(e, None).0
             }
+
+            // `#x in obj`
+            Expr::Bin(BinExpr {
+                span,
+                op: op!("in"),
+                left,
+                right,
+            }) if left.is_private_name() => {
+                let right = right.fold_with(self);
+                let n = match *left {
+                    Expr::PrivateName(n) => n,
+                    _ => unreachable!(),
+                };
+
+                if !self.private_props.contains(&n.id.sym) {
+                    return Expr::Bin(BinExpr {
+                        span,
+                        op: op!("in"),
+                        left: Box::new(Expr::PrivateName(n)),
+                        right,
+                    });
+                }
+
+                // `in` throws if the right operand is not an object.
+                let checked = Box::new(Expr::Call(CallExpr {
+                    span: DUMMY_SP,
+                    callee: helper!(check_in_rhs, "checkInRHS"),
+                    args: vec![right.as_arg()],
+                    type_args: Default::default(),
+                }));
+
+                if self.statics.contains(&n.id.sym) {
+                    // Static private properties only exist on the class.
+                    Expr::Bin(BinExpr {
+                        span,
+                        op: op!("==="),
+                        left: checked,
+                        right: Box::new(Expr::Ident(self.class_name.clone())),
+                    })
+                } else {
+                    let ident = Ident::new(
+                        format!("_{}", n.id.sym).into(),
+                        n.id.span.apply_mark(self.mark),
+                    );
+
+                    Expr::Call(CallExpr {
+                        span,
+                        callee: ident.make_member(quote_ident!("has")).as_callee(),
+                        args: vec![checked.as_arg()],
+                        type_args: Default::default(),
+                    })
+                }
+            }
             _ => e.fold_children_with(self),
         }
     }
@@ -0,0 +1,8 @@
+pub use self::logical_assignments::logical_assignments;
+use swc_ecma_visit::Fold;
+
+mod logical_assignments;
+
+pub fn es2021() -> impl Fold {
+    logical_assignments()
+}
@@ -0,0 +1,315 @@
+use std::mem::{replace, take};
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_transforms_base::ext::PatOrExprExt;
+use swc_ecma_transforms_base::perf::Check;
+use swc_ecma_transforms_macros::fast_path;
+use swc_ecma_utils::{alias_ident_for, ExprFactory, StmtLike};
+use swc_ecma_visit::{noop_fold_type, noop_visit_type, Fold, FoldWith, Node, Visit, VisitWith};
+
+/// Lowers `&&=`, `||=` and `??=`.
+///
+/// # Example
+///
+/// ## In
+///
+/// ```js
+/// a ||= b;
+/// foo().bar ??= baz;
+/// ```
+///
+/// ## Out
+///
+/// ```js
+/// a || (a = b);
+/// var _ref;
+/// (_ref = foo()).bar ?? (_ref.bar = baz);
+/// ```
+///
+/// Objects and computed keys of member expressions are evaluated only once.
+/// Variables for them are declared in the innermost function, so bodies of
+/// arrow functions and initializers of class fields are wrapped if required.
+/// `??` is lowered by [nullish_coalescing](crate::es2020::nullish_coalescing).
+pub fn logical_assignments() -> impl Fold + 'static {
+    LogicalAssignments::default()
+}
+
+#[derive(Debug, Default)]
+struct LogicalAssignments {
+    vars: Vec<VarDeclarator>,
+}
+
+impl LogicalAssignments {
+    fn fold_stmt_like<T>(&mut self, stmts: Vec<T>) -> Vec<T>
+    where
+        T: FoldWith<Self> + StmtLike,
+    {
+        let old_vars = take(&mut self.vars);
+        let mut buf = Vec::with_capacity(stmts.len() + 2);
+
+        for stmt in stmts {
+            let stmt = stmt.fold_with(self);
+
+            if !self.vars.is_empty() {
+                buf.push(T::from_stmt(Stmt::Decl(Decl::Var(VarDecl {
+                    span: DUMMY_SP,
+                    kind: VarDeclKind::Var,
+                    decls: replace(&mut self.vars, Default::default()),
+                    declare: false,
+                }))));
+            }
+
+            buf.push(stmt);
+        }
+
+        self.vars = old_vars;
+
+        buf
+    }
+
+    /// Folds `e` and returns it with variables declared while folding it.
+    fn fold_in_scope(&mut self, e: Box<Expr>) -> (Box<Expr>, Vec<VarDeclarator>) {
+        let old_vars = take(&mut self.vars);
+        let e = e.fold_with(self);
+
+        (e, replace(&mut self.vars, old_vars))
+    }
+
+    /// Folds the initializer of a class field, which is evaluated in its own
+    /// function scope.
+    ///
+    /// `a = foo().b ||= c` => `a = (() => { var _ref; return ... })()`
+    fn fold_class_field_value(&mut self, value: Option<Box<Expr>>) -> Option<Box<Expr>> {
+        let (value, vars) = self.fold_in_scope(value?);
+        if vars.is_empty() {
+            return Some(value);
+        }
+
+        Some(Box::new(Expr::Call(CallExpr {
+            span: DUMMY_SP,
+            callee: ArrowExpr {
+                span: DUMMY_SP,
+                params: vec![],
+                body: BlockStmtOrExpr::BlockStmt(block_with_vars(vars, value)),
+                is_async: false,
+                is_generator: false,
+                type_params: None,
+                return_type: None,
+            }
+            .as_callee(),
+            args: vec![],
+            type_args: None,
+        })))
+    }
+
+    /// Returns `(init, alias)`, where `init` evaluates `e` and `alias` reads
+    /// the result of `init`.
+    fn memorize(&mut self, e: Box<Expr>) -> (Box<Expr>, Box<Expr>) {
+        match *e {
+            Expr::Ident(..)
+            | Expr::This(..)
+            | Expr::Lit(Lit::Str(..))
+            | Expr::Lit(Lit::Num(..)) => return (e.clone(), e),
+            _ => {}
+        }
+
+        let alias = alias_ident_for(&e, "_ref");
+        self.vars.push(VarDeclarator {
+            span: DUMMY_SP,
+            name: Pat::Ident(alias.clone().into()),
+            init: None,
+            definite: false,
+        });
+
+        (
+            Box::new(Expr::Assign(AssignExpr {
+                span: DUMMY_SP,
+                op: op!("="),
+                left: PatOrExpr::Pat(Box::new(Pat::Ident(alias.clone().into()))),
+                right: e,
+            })),
+            Box::new(Expr::Ident(alias)),
+        )
+    }
+}
+
+#[fast_path(ShouldWork)]
+impl Fold for LogicalAssignments {
+    noop_fold_type!();
+
+    fn fold_module_items(&mut self, n: Vec<ModuleItem>) -> Vec<ModuleItem> {
+        self.fold_stmt_like(n)
+    }
+
+    fn fold_stmts(&mut self, n: Vec<Stmt>) -> Vec<Stmt> {
+        self.fold_stmt_like(n)
+    }
+
+    fn fold_arrow_expr(&mut self, n: ArrowExpr) -> ArrowExpr {
+        // Parameters are not in the scope of the body.
+        let params = n.params.fold_with(self);
+
+        let body = match n.body {
+            BlockStmtOrExpr::BlockStmt(body) => BlockStmtOrExpr::BlockStmt(body.fold_with(self)),
+            BlockStmtOrExpr::Expr(body) => {
+                let (body, vars) = self.fold_in_scope(body);
+
+                if vars.is_empty() {
+                    BlockStmtOrExpr::Expr(body)
+                } else {
+                    BlockStmtOrExpr::BlockStmt(block_with_vars(vars, body))
+                }
+            }
+        };
+
+        ArrowExpr { params, body, ..n }
+    }
+
+    fn fold_class_prop(&mut self, n: ClassProp) -> ClassProp {
+        let key = n.key.fold_with(self);
+        let value = self.fold_class_field_value(n.value);
+
+        ClassProp { key, value, ..n }
+    }
+
+    fn fold_private_prop(&mut self, n: PrivateProp) -> PrivateProp {
+        let value = self.fold_class_field_value(n.value);
+
+        PrivateProp { value, ..n }
+    }
+
+    fn fold_expr(&mut self, e: Expr) -> Expr {
+        let e = e.fold_children_with(self);
+
+        let (span, op, left, right) = match e {
+            Expr::Assign(AssignExpr {
+                span,
+                op,
+                left,
+                right,
+            }) => match op {
+                op!("&&=") => (span, op!("&&"), left, right),
+                op!("||=") => (span, op!("||"), left, right),
+                op!("??=") => (span, op!("??"), left, right),
+                _ => {
+                    return Expr::Assign(AssignExpr {
+                        span,
+                        op,
+                        left,
+                        right,
+                    })
+                }
+            },
+            _ => return e,
+        };
+
+        let left = match left.normalize_expr() {
+            PatOrExpr::Expr(left) => left,
+            PatOrExpr::Pat(left) => match *left {
+                Pat::Ident(i) => Box::new(Expr::Ident(i.id)),
+                _ => unreachable!("invalid left-hand side of a logical assignment"),
+            },
+        };
+
+        // `a.b ||= c` => `a.b || (a.b = c)`
+        let (read, write) = match *left {
+            Expr::Member(MemberExpr {
+                span: member_span,
+                obj,
+                prop,
+                computed,
+            }) => {
+                let (obj_read, obj_write) = match obj {
+                    ExprOrSuper::Super(s) => (ExprOrSuper::Super(s), ExprOrSuper::Super(s)),
+                    ExprOrSuper::Expr(obj) => {
+                        let (init, alias) = self.memorize(obj);
+                        (ExprOrSuper::Expr(init), ExprOrSuper::Expr(alias))
+                    }
+                };
+                let (prop_read, prop_write) = if computed {
+                    self.memorize(prop)
+                } else {
+                    (prop.clone(), prop)
+                };
+
+                (
+                    Box::new(Expr::Member(MemberExpr {
+                        span: member_span,
+                        obj: obj_read,
+                        prop: prop_read,
+                        computed,
+                    })),
+                    PatOrExpr::Expr(Box::new(Expr::Member(MemberExpr {
+                        span: member_span,
+                        obj: obj_write,
+                        prop: prop_write,
+                        computed,
+                    }))),
+                )
+            }
+            Expr::Ident(i) => (
+                Box::new(Expr::Ident(i.clone())),
+                PatOrExpr::Pat(Box::new(Pat::Ident(i.into()))),
+            ),
+            left => {
+                let left = Box::new(left);
+                (left.clone(), PatOrExpr::Expr(left))
+            }
+        };
+
+        Expr::Bin(BinExpr {
+            span,
+            op,
+            left: read,
+            right: Box::new(Expr::Assign(AssignExpr {
+                span: DUMMY_SP,
+                op: op!("="),
+                left: write,
+                right,
+            })),
+        })
+    }
+}
+
+/// Creates `{ var vars; return value; }`.
+fn block_with_vars(vars: Vec<VarDeclarator>, value: Box<Expr>) -> BlockStmt {
+    BlockStmt {
+        span: DUMMY_SP,
+        stmts: vec![
+            Stmt::Decl(Decl::Var(VarDecl {
+                span: DUMMY_SP,
+                kind: VarDeclKind::Var,
+                decls: vars,
+                declare: false,
+            })),
+            Stmt::Return(ReturnStmt {
+                span: DUMMY_SP,
+                arg: Some(value),
+            }),
+        ],
+    }
+}
+
+#[derive(Default)]
+struct ShouldWork {
+    found: bool,
+}
+
+impl Visit for ShouldWork {
+    noop_visit_type!();
+
+    fn visit_assign_expr(&mut self, e: &AssignExpr, _: &dyn Node) {
+        match e.op {
+            op!("&&=") | op!("||=") | op!("??=") => {
+                self.found = true;
+            }
+            _ => e.visit_children_with(self),
+        }
+    }
+}
+
+impl Check for ShouldWork {
+    fn should_handle(&self) -> bool {
+        self.found
+    }
+}
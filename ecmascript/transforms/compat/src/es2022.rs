@@ -0,0 +1,8 @@
+pub use self::static_blocks::static_blocks;
+use swc_ecma_visit::Fold;
+
+mod static_blocks;
+
+pub fn es2022() -> impl Fold {
+    static_blocks()
+}
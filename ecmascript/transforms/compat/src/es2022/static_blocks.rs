@@ -0,0 +1,158 @@
+use fxhash::FxHashSet;
+use std::mem::take;
+use swc_atoms::JsWord;
+use swc_ecma_ast::*;
+use swc_ecma_transforms_base::perf::Check;
+use swc_ecma_transforms_macros::fast_path;
+use swc_ecma_utils::ExprFactory;
+use swc_ecma_visit::{
+    as_folder, noop_visit_mut_type, noop_visit_type, Fold, Node, Visit, VisitMut, VisitMutWith,
+};
+
+/// Lowers class static blocks.
+///
+/// # Example
+///
+/// ## In
+///
+/// ```js
+/// class A {
+///     static {
+///         const a = init();
+///         this.a = a;
+///     }
+/// }
+/// ```
+///
+/// ## Out
+///
+/// ```js
+/// class A {
+///     static #_ = (() => {
+///         const a = init();
+///         this.a = a;
+///     })();
+/// }
+/// ```
+///
+/// The static private properties are lowered by
+/// [class_properties](crate::es2020::class_properties).
+pub fn static_blocks() -> impl Fold {
+    as_folder(StaticBlocks)
+}
+
+struct StaticBlocks;
+
+#[fast_path(ShouldWork)]
+impl VisitMut for StaticBlocks {
+    noop_visit_mut_type!();
+
+    fn visit_mut_class(&mut self, class: &mut Class) {
+        class.visit_mut_children_with(self);
+
+        let mut private_names = class
+            .body
+            .iter()
+            .filter_map(|member| match member {
+                ClassMember::PrivateProp(p) => Some(p.key.id.sym.clone()),
+                ClassMember::PrivateMethod(m) => Some(m.key.id.sym.clone()),
+                _ => None,
+            })
+            .collect::<FxHashSet<_>>();
+
+        for member in &mut class.body {
+            let block = match member {
+                ClassMember::StaticBlock(block) => block,
+                _ => continue,
+            };
+            let span = block.span;
+            let mut stmts = take(&mut block.body.stmts);
+
+            if stmts.is_empty() {
+                *member = ClassMember::Empty(EmptyStmt { span });
+                continue;
+            }
+
+            // `static { a(); }` does not need a function.
+            let value = if stmts.len() == 1 && stmts[0].is_expr() {
+                match stmts.pop().unwrap() {
+                    Stmt::Expr(ExprStmt { expr, .. }) => expr,
+                    _ => unreachable!(),
+                }
+            } else {
+                // `this` of an arrow function in a static initializer is the
+                // class, like in the static block.
+                Box::new(Expr::Call(CallExpr {
+                    span,
+                    callee: ArrowExpr {
+                        span,
+                        params: vec![],
+                        body: BlockStmtOrExpr::BlockStmt(BlockStmt {
+                            span: block.body.span,
+                            stmts,
+                        }),
+                        is_async: false,
+                        is_generator: false,
+                        type_params: None,
+                        return_type: None,
+                    }
+                    .as_callee(),
+                    args: vec![],
+                    type_args: None,
+                }))
+            };
+
+            let id = Ident::new(generate_name(&mut private_names), span);
+            *member = ClassMember::PrivateProp(PrivateProp {
+                span,
+                key: PrivateName { span, id },
+                value: Some(value),
+                type_ann: None,
+                is_static: true,
+                decorators: vec![],
+                computed: false,
+                accessibility: None,
+                is_abstract: false,
+                is_optional: false,
+                is_override: false,
+                readonly: false,
+                definite: false,
+            });
+        }
+    }
+}
+
+/// Returns `_`, `_2`, `_3`, ... which is not used by the class.
+fn generate_name(used: &mut FxHashSet<JsWord>) -> JsWord {
+    let mut i = 1;
+    loop {
+        let name: JsWord = if i == 1 {
+            "_".into()
+        } else {
+            format!("_{}", i).into()
+        };
+        if used.insert(name.clone()) {
+            return name;
+        }
+        i += 1;
+    }
+}
+
+#[derive(Default)]
+struct ShouldWork {
+    found: bool,
+}
+
+impl Visit for ShouldWork {
+    noop_visit_type!();
+
+    fn visit_static_block(&mut self, _: &StaticBlock, _: &dyn Node) {
+        self.found = true;
+    }
+}
+
+impl Check for ShouldWork {
+    fn should_handle(&self) -> bool {
+        self.found
+    }
+}
@@ -0,0 +1,257 @@
+//! Utilities for passes lowering regular expressions.
+
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::Regex;
+use swc_ecma_regexp::ast::*;
+
+/// Parses `regex` and passes it to `op`. If `op` returns true, the modified
+/// regular expression is printed back to `regex`.
+///
+/// Invalid regular expressions are left as is.
+pub(crate) fn transform_regex<F>(regex: &mut Regex, op: F)
+where
+    F: FnOnce(&mut Regexp) -> bool,
+{
+    let mut parsed = match swc_ecma_regexp::parse(regex.span.lo, &regex.exp, &regex.flags) {
+        Ok(v) => v,
+        Err(..) => return,
+    };
+
+    if !op(&mut parsed) {
+        return;
+    }
+
+    let exp = parsed.pattern.display(parsed.flags.unicode).to_string();
+    // `//` is a comment.
+    regex.exp = if exp.is_empty() {
+        "(?:)".into()
+    } else {
+        exp.into()
+    };
+    regex.flags = parsed.flags.to_string().into();
+}
+
+/// Calls `op` with each list of elements in `alternatives`, including nested
+/// ones. Inner lists are visited first.
+pub(crate) fn visit_element_lists<F>(alternatives: &mut [Alternative], op: &mut F)
+where
+    F: FnMut(&mut Vec<Element>),
+{
+    for alt in alternatives {
+        for element in &mut alt.elements {
+            visit_nested(element, op);
+        }
+        op(&mut alt.elements);
+    }
+}
+
+fn visit_nested<F>(element: &mut Element, op: &mut F)
+where
+    F: FnMut(&mut Vec<Element>),
+{
+    match element {
+        Element::Lookaround(Lookaround { alternatives, .. })
+        | Element::Group(Group { alternatives, .. })
+        | Element::CapturingGroup(CapturingGroup { alternatives, .. }) => {
+            visit_element_lists(alternatives, op)
+        }
+        Element::Quantifier(q) => visit_nested(&mut q.element, op),
+        _ => {}
+    }
+}
+
+/// Calls `op` with each element in `alternatives`, in the order of appearance.
+/// An element is visited before the elements nested in it.
+pub(crate) fn visit_elements<F>(alternatives: &mut [Alternative], op: &mut F)
+where
+    F: FnMut(&mut Element),
+{
+    for alt in alternatives {
+        for element in &mut alt.elements {
+            visit_element(element, op);
+        }
+    }
+}
+
+fn visit_element<F>(element: &mut Element, op: &mut F)
+where
+    F: FnMut(&mut Element),
+{
+    op(element);
+
+    match element {
+        Element::Lookaround(Lookaround { alternatives, .. })
+        | Element::Group(Group { alternatives, .. })
+        | Element::CapturingGroup(CapturingGroup { alternatives, .. }) => {
+            visit_elements(alternatives, op)
+        }
+        Element::Quantifier(q) => visit_element(&mut q.element, op),
+        _ => {}
+    }
+}
+
+pub(crate) const MAX_CODE_POINT: u32 = 0x10ffff;
+
+const LINE_TERMINATORS: &[(u32, u32)] = &[(0x0a, 0x0a), (0x0d, 0x0d), (0x2028, 0x2029)];
+
+const DIGIT: &[(u32, u32)] = &[(0x30, 0x39)];
+
+const SPACE: &[(u32, u32)] = &[
+    (0x09, 0x0d),
+    (0x20, 0x20),
+    (0xa0, 0xa0),
+    (0x1680, 0x1680),
+    (0x2000, 0x200a),
+    (0x2028, 0x2029),
+    (0x202f, 0x202f),
+    (0x205f, 0x205f),
+    (0x3000, 0x3000),
+    (0xfeff, 0xfeff),
+];
+
+const WORD: &[(u32, u32)] = &[(0x30, 0x39), (0x41, 0x5a), (0x5f, 0x5f), (0x61, 0x7a)];
+
+/// A set of code points (or code units), stored as sorted inclusive ranges
+/// which don't overlap nor touch.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(crate) struct CodePointSet {
+    ranges: Vec<(u32, u32)>,
+}
+
+impl CodePointSet {
+    pub fn from_ranges<I>(ranges: I) -> Self
+    where
+        I: IntoIterator<Item = (u32, u32)>,
+    {
+        let mut ranges = ranges.into_iter().collect::<Vec<_>>();
+        ranges.sort_unstable();
+
+        let mut merged: Vec<(u32, u32)> = Vec::with_capacity(ranges.len());
+        for (lo, hi) in ranges {
+            match merged.last_mut() {
+                Some(last) if lo <= last.1.saturating_add(1) => last.1 = last.1.max(hi),
+                _ => merged.push((lo, hi)),
+            }
+        }
+
+        CodePointSet { ranges: merged }
+    }
+
+    /// Returns [None] if `kind` is an unknown unicode property.
+    pub fn from_set(kind: &CharacterSetKind, negate: bool, dot_all: bool) -> Option<Self> {
+        let set = match kind {
+            CharacterSetKind::Any if dot_all => Self::from_ranges(vec![(0, MAX_CODE_POINT)]),
+            CharacterSetKind::Any => Self::from_ranges(LINE_TERMINATORS.iter().cloned()).negate(),
+            CharacterSetKind::Digit => Self::from_ranges(DIGIT.iter().cloned()),
+            CharacterSetKind::Space => Self::from_ranges(SPACE.iter().cloned()),
+            CharacterSetKind::Word => Self::from_ranges(WORD.iter().cloned()),
+            CharacterSetKind::Property { name, value } => property(name, value.as_deref())?,
+        };
+
+        Some(if negate { set.negate() } else { set })
+    }
+
+    /// Returns [None] if `class` contains an unknown unicode property.
+    pub fn from_class(class: &CharacterClass) -> Option<Self> {
+        let mut ranges = vec![];
+        for element in &class.elements {
+            match element {
+                ClassElement::Character(c) => ranges.push((c.value, c.value)),
+                ClassElement::Range(r) => ranges.push((r.min.value, r.max.value)),
+                ClassElement::Set(s) => {
+                    ranges.extend(Self::from_set(&s.kind, s.negate, false)?.ranges)
+                }
+            }
+        }
+
+        let set = Self::from_ranges(ranges);
+        Some(if class.negate { set.negate() } else { set })
+    }
+
+    pub fn ranges(&self) -> &[(u32, u32)] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Complement in `0..=MAX_CODE_POINT`.
+    pub fn negate(&self) -> Self {
+        let mut ranges = vec![];
+        let mut next = 0;
+        for &(lo, hi) in &self.ranges {
+            if next < lo {
+                ranges.push((next, lo - 1));
+            }
+            next = hi + 1;
+        }
+        if next <= MAX_CODE_POINT {
+            ranges.push((next, MAX_CODE_POINT));
+        }
+
+        CodePointSet { ranges }
+    }
+
+    /// Intersection with `lo..=hi`.
+    pub fn slice(&self, lo: u32, hi: u32) -> Self {
+        CodePointSet {
+            ranges: self
+                .ranges
+                .iter()
+                .filter(|r| r.0 <= hi && lo <= r.1)
+                .map(|r| (r.0.max(lo), r.1.min(hi)))
+                .collect(),
+        }
+    }
+
+    pub fn to_class_elements(&self) -> Vec<ClassElement> {
+        self.ranges
+            .iter()
+            .map(|&(lo, hi)| {
+                if lo == hi {
+                    ClassElement::Character(character(lo))
+                } else {
+                    ClassElement::Range(ClassRange {
+                        span: DUMMY_SP,
+                        min: character(lo),
+                        max: character(hi),
+                    })
+                }
+            })
+            .collect()
+    }
+
+    pub fn to_class(&self) -> CharacterClass {
+        CharacterClass {
+            span: DUMMY_SP,
+            negate: false,
+            elements: self.to_class_elements(),
+        }
+    }
+}
+
+pub(crate) fn character(value: u32) -> Character {
+    Character {
+        span: DUMMY_SP,
+        value,
+    }
+}
+
+/// Code points of `\p{name}` or `\p{name=value}`.
+fn property(name: &str, value: Option<&str>) -> Option<CodePointSet> {
+    use regex_syntax::hir::{Class, HirKind};
+
+    let query = match value {
+        Some(value) => format!("\\p{{{}={}}}", name, value),
+        None => format!("\\p{{{}}}", name),
+    };
+    let hir = regex_syntax::Parser::new().parse(&query).ok()?;
+
+    match hir.kind() {
+        HirKind::Class(Class::Unicode(class)) => Some(CodePointSet::from_ranges(
+            class.iter().map(|r| (r.start() as u32, r.end() as u32)),
+        )),
+        _ => None,
+    }
+}
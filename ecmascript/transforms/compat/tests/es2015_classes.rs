@@ -6183,3 +6183,51 @@ test_exec!(
   expect(new Test().foo()).toBe(3);
   "
 );
+
+test!(
+    syntax(),
+    |_| tr(),
+    static_block,
+    r#"
+class Foo extends Bar {
+  static {
+    this.a = super.b();
+  }
+}
+"#,
+    r#"
+let Foo = function(Bar) {
+  'use strict';
+  _inherits(Foo, Bar);
+  function Foo() {
+    _classCallCheck(this, Foo);
+    return _possibleConstructorReturn(this, _getPrototypeOf(Foo).apply(this, arguments));
+  }
+  (function() {
+    this.a = _get(_getPrototypeOf(Foo), "b", this).call(this);
+  }).call(Foo);
+  return Foo;
+}(Bar);
+"#
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    static_block_exec,
+    r#"
+class Bar {
+  static b() {
+    return 1;
+  }
+}
+
+class Foo extends Bar {
+  static {
+    this.a = super.b() + 1;
+  }
+}
+
+expect(Foo.a).toBe(2);
+"#
+);
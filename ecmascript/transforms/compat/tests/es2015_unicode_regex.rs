@@ -0,0 +1,88 @@
+use swc_ecma_parser::Syntax;
+use swc_ecma_transforms_compat::es2015::unicode_regex;
+use swc_ecma_transforms_testing::{test, test_exec};
+use swc_ecma_visit::Fold;
+
+fn tr() -> impl Fold {
+    unicode_regex()
+}
+
+test!(
+    Syntax::default(),
+    |_| tr(),
+    astral_character,
+    "
+var a = /😀/u;
+var b = /😀+/u;
+",
+    "
+var a = /😀/;
+var b = /(?:😀)+/;
+"
+);
+
+test!(
+    Syntax::default(),
+    |_| tr(),
+    astral_class,
+    "
+var a = /[😀-😂]/u;
+var b = /[\\u{1F600}-\\u{1F64F}a]+/gu;
+var c = /[\\u{10000}-\\u{10FFFF}]/u;
+",
+    "
+var a = /\\uD83D[\\uDE00-\\uDE02]/;
+var b = /(?:\\uD83D[\\uDE00-\\uDE4F]|[a])+/g;
+var c = /[\\uD800-\\uDBFF][\\uDC00-\\uDFFF]/;
+"
+);
+
+test!(
+    Syntax::default(),
+    |_| tr(),
+    negated,
+    "
+var a = /./u;
+var b = /\\S/u;
+var c = /[^a]/u;
+",
+    "
+var a = /(?:[\\uD800-\\uDBFF][\\uDC00-\\uDFFF]|.)/;
+var b = /(?:[\\uD800-\\uDBFF][\\uDC00-\\uDFFF]|\\S)/;
+var c = /(?:[\\uD800-\\uDBFF][\\uDC00-\\uDFFF]|[^a])/;
+"
+);
+
+test!(
+    Syntax::default(),
+    |_| tr(),
+    escapes,
+    r"
+var a = /\u{61}\w+/u;
+var b = /\p{Script=Gothic}/u;
+",
+    r"
+var a = /a\w+/;
+var b = /\uD800[\uDF30-\uDF4A]/;
+"
+);
+
+test!(
+    Syntax::default(),
+    |_| tr(),
+    ignore_non_unicode,
+    "var a = /\\u{61}/;",
+    "var a = /\\u{61}/;"
+);
+
+test_exec!(
+    Syntax::default(),
+    |_| tr(),
+    exec,
+    "
+expect(/^.$/u.test('😀')).toBe(true);
+expect(/^[^a]$/u.test('😀')).toBe(true);
+expect(/^[😀-😂]+$/u.test('😀😁😂')).toBe(true);
+expect(/^[😀-😂]$/u.test('😃')).toBe(false);
+"
+);
@@ -0,0 +1,46 @@
+use swc_ecma_parser::Syntax;
+use swc_ecma_transforms_compat::es2018::dotall_regex;
+use swc_ecma_transforms_testing::{test, test_exec};
+use swc_ecma_visit::Fold;
+
+fn tr() -> impl Fold {
+    dotall_regex()
+}
+
+test!(
+    Syntax::default(),
+    |_| tr(),
+    basic,
+    "
+var a = /a.b/s;
+var b = /.+/gsu;
+",
+    r"
+var a = /a[\s\S]b/;
+var b = /[\s\S]+/gu;
+"
+);
+
+test!(
+    Syntax::default(),
+    |_| tr(),
+    ignore_escaped,
+    r"
+var a = /\.[.]/s;
+var b = /a.b/;
+",
+    r"
+var a = /\.[.]/;
+var b = /a.b/;
+"
+);
+
+test_exec!(
+    Syntax::default(),
+    |_| tr(),
+    exec,
+    "
+expect(/^a.b$/s.test('a\\nb')).toBe(true);
+expect(/^a.b$/s.flags).toBe('');
+"
+);
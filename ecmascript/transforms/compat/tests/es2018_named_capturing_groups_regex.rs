@@ -0,0 +1,71 @@
+use swc_ecma_parser::Syntax;
+use swc_ecma_transforms_compat::es2018::named_capturing_groups_regex;
+use swc_ecma_transforms_testing::{test, test_exec};
+use swc_ecma_visit::Fold;
+
+fn tr() -> impl Fold {
+    named_capturing_groups_regex()
+}
+
+test!(
+    Syntax::default(),
+    |_| tr(),
+    basic,
+    r"
+var re = /(?<year>\d{4})-(?<month>\d{2})/;
+",
+    r"
+var re = _wrapRegExp(/(\d{4})-(\d{2})/, {
+    year: 1,
+    month: 2
+});
+"
+);
+
+test!(
+    Syntax::default(),
+    |_| tr(),
+    backreference,
+    r"
+var re = /(a)(?<b>(?<c>x))\k<c>+/gu;
+",
+    r"
+var re = _wrapRegExp(/(a)((x))\3+/gu, {
+    b: 2,
+    c: 3
+});
+"
+);
+
+test!(
+    Syntax::default(),
+    |_| tr(),
+    ignore_unnamed,
+    r"
+var a = /(a)(?:b)(?<=c)/;
+var b = /\(?<a>/;
+",
+    r"
+var a = /(a)(?:b)(?<=c)/;
+var b = /\(?<a>/;
+"
+);
+
+test_exec!(
+    Syntax::default(),
+    |_| tr(),
+    exec,
+    r"
+var re = /(?<year>\d{4})-(?<month>\d{2})/;
+var result = re.exec('2021-03');
+expect(result.groups.year).toBe('2021');
+expect(result.groups.month).toBe('03');
+expect('2021-03'.replace(re, '$<month>/$<year>')).toBe('03/2021');
+expect(
+    '2021-03'.replace(re, function () {
+        var groups = arguments[arguments.length - 1];
+        return groups.month + '/' + groups.year;
+    })
+).toBe('03/2021');
+"
+);
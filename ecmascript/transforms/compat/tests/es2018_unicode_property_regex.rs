@@ -0,0 +1,64 @@
+use swc_common::chain;
+use swc_ecma_parser::Syntax;
+use swc_ecma_transforms_compat::{es2015::unicode_regex, es2018::unicode_property_regex};
+use swc_ecma_transforms_testing::{test, test_exec};
+use swc_ecma_visit::Fold;
+
+fn tr() -> impl Fold {
+    unicode_property_regex()
+}
+
+test!(
+    Syntax::default(),
+    |_| tr(),
+    basic,
+    r"
+var a = /\p{ASCII_Hex_Digit}+/u;
+var b = /\P{ASCII_Hex_Digit}/u;
+",
+    r"
+var a = /[0-9A-Fa-f]+/u;
+var b = /[\x00-\/:-@G-`g-\u{10FFFF}]/u;
+"
+);
+
+test!(
+    Syntax::default(),
+    |_| tr(),
+    in_class,
+    r"
+var a = /[\p{ASCII_Hex_Digit}_]/u;
+var b = /[^\P{ASCII_Hex_Digit}]/u;
+",
+    r"
+var a = /[0-9A-Fa-f_]/u;
+var b = /[^\x00-\/:-@G-`g-\u{10FFFF}]/u;
+"
+);
+
+test!(
+    Syntax::default(),
+    |_| tr(),
+    ignore_non_unicode,
+    r"var a = /\p{L}/;",
+    r"var a = /\p{L}/;"
+);
+
+test!(
+    Syntax::default(),
+    |_| chain!(tr(), unicode_regex()),
+    with_unicode_regex,
+    r"var a = /\p{Script=Gothic}/u;",
+    r"var a = /\uD800[\uDF30-\uDF4A]/;"
+);
+
+test_exec!(
+    Syntax::default(),
+    |_| tr(),
+    exec,
+    r"
+expect(/^\p{Script=Greek}+$/u.test('αβγ')).toBe(true);
+expect(/^\p{Lu}$/u.test('a')).toBe(false);
+expect(/^\P{Lu}$/u.test('a')).toBe(true);
+"
+);
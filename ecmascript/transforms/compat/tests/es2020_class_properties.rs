@@ -5200,3 +5200,115 @@ test!(
     }
     "
 );
+
+test!(
+    syntax(),
+    |_| class_properties(),
+    brand_check,
+    "
+    class Foo {
+      #bar = 1;
+
+      static is(obj) {
+        return #bar in obj;
+      }
+    }
+    ",
+    "
+    class Foo {
+      static is(obj) {
+        return _bar.has(_checkInRHS(obj));
+      }
+
+      constructor() {
+        _bar.set(this, {
+          writable: true,
+          value: 1
+        });
+      }
+    }
+
+    var _bar = new WeakMap();
+    "
+);
+
+test!(
+    syntax(),
+    |_| class_properties(),
+    brand_check_static,
+    "
+    class Foo {
+      static #bar = 1;
+
+      static is(obj) {
+        return #bar in obj;
+      }
+    }
+    ",
+    "
+    class Foo {
+      static is(obj) {
+        return _checkInRHS(obj) === Foo;
+      }
+    }
+
+    var _bar = {
+      writable: true,
+      value: 1
+    };
+    "
+);
+
+test!(
+    syntax(),
+    |_| class_properties(),
+    brand_check_private_method,
+    "
+    class Foo {
+      #bar() {}
+
+      static is(obj) {
+        return #bar in obj;
+      }
+    }
+    ",
+    "
+    class Foo {
+      #bar() {}
+
+      static is(obj) {
+        return #bar in obj;
+      }
+    }
+    "
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    brand_check_exec,
+    "
+    class Foo {
+      #bar = 1;
+      static #baz = 2;
+
+      static isFoo(obj) {
+        return #bar in obj;
+      }
+
+      static isClass(obj) {
+        return #baz in obj;
+      }
+    }
+
+    class Sub extends Foo {}
+
+    expect(Foo.isFoo(new Foo())).toBe(true);
+    expect(Foo.isFoo(new Sub())).toBe(true);
+    expect(Foo.isFoo({})).toBe(false);
+    expect(Foo.isClass(Foo)).toBe(true);
+    expect(Foo.isClass(Sub)).toBe(false);
+    expect(() => Foo.isFoo(1)).toThrow(TypeError);
+    expect(() => Foo.isClass(null)).toThrow(TypeError);
+    "
+);
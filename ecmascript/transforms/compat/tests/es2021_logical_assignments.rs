@@ -0,0 +1,225 @@
+use swc_common::chain;
+use swc_ecma_parser::{EsConfig, Syntax};
+use swc_ecma_transforms_compat::{es2020::nullish_coalescing, es2021::logical_assignments};
+use swc_ecma_transforms_testing::{test, test_exec};
+use swc_ecma_visit::Fold;
+
+fn tr() -> impl Fold {
+    logical_assignments()
+}
+
+test!(
+    Syntax::default(),
+    |_| tr(),
+    ident,
+    "
+a &&= b;
+a ||= b;
+a ??= b;
+",
+    "
+a && (a = b);
+a || (a = b);
+a ?? (a = b);
+"
+);
+
+test!(
+    Syntax::default(),
+    |_| tr(),
+    member,
+    "
+obj.a ||= 1;
+class A extends B {
+    m() {
+        this.a &&= 2;
+        super.a ??= 3;
+    }
+}
+",
+    "
+obj.a || (obj.a = 1);
+class A extends B {
+    m() {
+        this.a && (this.a = 2);
+        super.a ?? (super.a = 3);
+    }
+}
+"
+);
+
+test!(
+    Syntax::default(),
+    |_| tr(),
+    memoize_object,
+    "
+foo().a ||= 1;
+",
+    "
+var _ref;
+(_ref = foo()).a || (_ref.a = 1);
+"
+);
+
+test!(
+    Syntax::default(),
+    |_| tr(),
+    memoize_computed_key,
+    "
+obj[key()] ||= 1;
+obj['a'] ||= 2;
+",
+    "
+var _ref;
+obj[_ref = key()] || (obj[_ref] = 1);
+obj['a'] || (obj['a'] = 2);
+"
+);
+
+test!(
+    Syntax::default(),
+    |_| tr(),
+    nested,
+    "
+function f() {
+    a.b.c ||= (d.e ??= 1);
+}
+",
+    "
+function f() {
+    var _b;
+    (_b = a.b).c || (_b.c = d.e ?? (d.e = 1));
+}
+"
+);
+
+test!(
+    Syntax::default(),
+    |_| tr(),
+    arrow,
+    "
+const f = () => a.b.c ||= 1;
+const g = (x = d.e[f.g] &&= 2) => {
+    h.i.j ??= 3;
+};
+",
+    "
+const f = () => {
+    var _b;
+    return (_b = a.b).c || (_b.c = 1);
+};
+var _e, _g;
+const g = (x = (_e = d.e)[_g = f.g] && (_e[_g] = 2)) => {
+    var _i;
+    (_i = h.i).j ?? (_i.j = 3);
+};
+"
+);
+
+test!(
+    Syntax::Es(EsConfig {
+        class_props: true,
+        class_private_props: true,
+        ..Default::default()
+    }),
+    |_| tr(),
+    class_field,
+    "
+class A {
+    a = b.c.d ||= 1;
+    #e = this.f ??= 2;
+    static g = h.i[j.k] &&= 3;
+}
+",
+    "
+class A {
+    a = (() => {
+        var _c;
+        return (_c = b.c).d || (_c.d = 1);
+    })();
+    #e = this.f ?? (this.f = 2);
+    static g = (() => {
+        var _i, _k;
+        return (_i = h.i)[_k = j.k] && (_i[_k] = 3);
+    })();
+}
+"
+);
+
+test_exec!(
+    Syntax::Es(EsConfig {
+        class_props: true,
+        ..Default::default()
+    }),
+    |_| chain!(tr(), nullish_coalescing()),
+    exec_scope,
+    "
+let calls = 0;
+const objs = [{ a: 0 }, { a: 1 }];
+const get = (i) => {
+    calls++;
+    return objs[i];
+};
+const f = (i) => get(i).a ||= (i === 0 ? f(1) : 10);
+
+expect(f(0)).toBe(1);
+expect(objs).toEqual([{ a: 1 }, { a: 1 }]);
+expect(calls).toBe(2);
+
+class A {
+    a = get(0).b ??= 2;
+}
+expect(new A().a).toBe(2);
+expect(objs[0].b).toBe(2);
+"
+);
+
+test_exec!(
+    Syntax::default(),
+    |_| chain!(tr(), nullish_coalescing()),
+    exec_evaluates_once,
+    "
+let objCalls = 0;
+let keyCalls = 0;
+const obj = { a: 0, b: 1, c: null };
+const getObj = () => {
+    objCalls++;
+    return obj;
+};
+const getKey = (key) => {
+    keyCalls++;
+    return key;
+};
+
+getObj()[getKey('a')] ||= 10;
+getObj()[getKey('b')] &&= 20;
+getObj()[getKey('c')] ??= 30;
+
+expect(obj).toEqual({ a: 10, b: 20, c: 30 });
+expect(objCalls).toBe(3);
+expect(keyCalls).toBe(3);
+"
+);
+
+test_exec!(
+    Syntax::default(),
+    |_| chain!(tr(), nullish_coalescing()),
+    exec_short_circuit,
+    "
+let calls = 0;
+const value = () => {
+    calls++;
+    return 'value';
+};
+
+let a = 1;
+a ||= value();
+let b = 0;
+b &&= value();
+let c = false;
+c ??= value();
+
+expect([a, b, c]).toEqual([1, 0, false]);
+expect(calls).toBe(0);
+"
+);
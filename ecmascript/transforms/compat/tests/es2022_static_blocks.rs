@@ -0,0 +1,140 @@
+use swc_common::chain;
+use swc_ecma_parser::{EsConfig, Syntax};
+use swc_ecma_transforms_compat::{es2020::class_properties, es2022::static_blocks};
+use swc_ecma_transforms_testing::{test, test_exec};
+
+fn syntax() -> Syntax {
+    Syntax::Es(EsConfig {
+        class_private_props: true,
+        ..Default::default()
+    })
+}
+
+test!(
+    syntax(),
+    |_| static_blocks(),
+    single_expr,
+    r#"
+class A {
+  static {
+    this.a = 1;
+  }
+}
+"#,
+    r#"
+class A {
+  static #_ = this.a = 1;
+}
+"#
+);
+
+test!(
+    syntax(),
+    |_| static_blocks(),
+    multiple_stmts,
+    r#"
+class A {
+  static {
+    const a = init();
+    this.a = a;
+  }
+}
+"#,
+    r#"
+class A {
+  static #_ = (() => {
+    const a = init();
+    this.a = a;
+  })();
+}
+"#
+);
+
+test!(
+    syntax(),
+    |_| static_blocks(),
+    empty,
+    r#"
+class A {
+  static {}
+  m() {}
+}
+"#,
+    r#"
+class A {
+  m() {}
+}
+"#
+);
+
+test!(
+    syntax(),
+    |_| static_blocks(),
+    name_conflict,
+    r#"
+class A {
+  #_ = 1;
+  static #_2() {}
+  static {
+    a();
+  }
+  static {
+    b();
+  }
+}
+"#,
+    r#"
+class A {
+  #_ = 1;
+  static #_2() {}
+  static #_3 = a();
+  static #_4 = b();
+}
+"#
+);
+
+test!(
+    syntax(),
+    |_| static_blocks(),
+    nested_class,
+    r#"
+class A {
+  static {
+    class B {
+      static {
+        this.b = 1;
+      }
+    }
+  }
+}
+"#,
+    r#"
+class A {
+  static #_ = (() => {
+    class B {
+      static #_ = this.b = 1;
+    }
+  })();
+}
+"#
+);
+
+test_exec!(
+    syntax(),
+    |_| chain!(static_blocks(), class_properties()),
+    exec,
+    r#"
+const order = [];
+class A {
+  static a = order.push("a");
+  static {
+    order.push("block");
+    this.self = this;
+  }
+  static b = order.push("b");
+}
+
+expect(order).toEqual(["a", "block", "b"]);
+expect(A.self).toBe(A);
+"#
+);
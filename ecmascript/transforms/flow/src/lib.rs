@@ -0,0 +1,3 @@
+pub use self::strip::strip;
+
+pub mod strip;
@@ -0,0 +1,183 @@
+use swc_atoms::js_word;
+use swc_ecma_ast::*;
+use swc_ecma_transforms_base::ext::MapWithMut;
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
+
+/// Strips flow type annotations out.
+///
+/// The flow parser represents flow types using typescript nodes, but unlike
+/// `swc_ecma_transforms_typescript::strip`, this does not remove imports
+/// which are only used as types. Flow requires `import type` for them, so
+/// only type-only imports and exports are removed.
+///
+/// The parser drops type-only specifiers like `import { type A, typeof B, c }`
+/// because there's no node for them, and marks an import as type-only if all
+/// of its specifiers are type-only or it's `import typeof`.
+pub fn strip() -> impl Fold {
+    as_folder(Strip)
+}
+
+struct Strip;
+
+macro_rules! type_to_none {
+    ($name:ident, $T:ty) => {
+        fn $name(&mut self, node: &mut Option<$T>) {
+            *node = None;
+        }
+    };
+}
+
+impl VisitMut for Strip {
+    noop_visit_mut_type!();
+
+    type_to_none!(visit_mut_opt_ts_type_ann, TsTypeAnn);
+    type_to_none!(visit_mut_opt_ts_type_param_decl, TsTypeParamDecl);
+    type_to_none!(
+        visit_mut_opt_ts_type_param_instantiation,
+        TsTypeParamInstantiation
+    );
+
+    fn visit_mut_class(&mut self, class: &mut Class) {
+        class.is_abstract = false;
+        class.implements.clear();
+
+        class.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_class_members(&mut self, members: &mut Vec<ClassMember>) {
+        members.retain(|member| match *member {
+            ClassMember::TsIndexSignature(..) => false,
+            ClassMember::Constructor(Constructor { body: None, .. }) => false,
+            ClassMember::Method(ClassMethod {
+                function: Function { body: None, .. },
+                ..
+            })
+            | ClassMember::PrivateMethod(PrivateMethod {
+                function: Function { body: None, .. },
+                ..
+            }) => false,
+            ClassMember::ClassProp(ClassProp { declare: true, .. }) => false,
+            _ => true,
+        });
+
+        members.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_class_prop(&mut self, prop: &mut ClassProp) {
+        prop.visit_mut_children_with(self);
+        prop.readonly = false;
+        prop.is_optional = false;
+        prop.definite = false;
+    }
+
+    fn visit_mut_private_prop(&mut self, prop: &mut PrivateProp) {
+        prop.visit_mut_children_with(self);
+        prop.readonly = false;
+        prop.is_optional = false;
+        prop.definite = false;
+    }
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        // Type casts
+        loop {
+            match e {
+                Expr::TsAs(TsAsExpr { expr, .. })
+                | Expr::TsNonNull(TsNonNullExpr { expr, .. })
+                | Expr::TsTypeAssertion(TsTypeAssertion { expr, .. })
+                | Expr::TsConstAssertion(TsConstAssertion { expr, .. }) => {
+                    let expr = *expr.take();
+                    *e = expr;
+                }
+                _ => break,
+            }
+        }
+
+        e.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_ident(&mut self, i: &mut Ident) {
+        i.optional = false;
+        i.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_array_pat(&mut self, pat: &mut ArrayPat) {
+        pat.visit_mut_children_with(self);
+        pat.optional = false;
+    }
+
+    fn visit_mut_object_pat(&mut self, pat: &mut ObjectPat) {
+        pat.visit_mut_children_with(self);
+        pat.optional = false;
+    }
+
+    /// Removes `this` from the parameter list.
+    fn visit_mut_params(&mut self, params: &mut Vec<Param>) {
+        params.visit_mut_children_with(self);
+
+        params.retain(|param| !is_this_param(&param.pat));
+    }
+
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        items.retain(|item| match item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                type_only: true, ..
+            }))
+            | ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+                type_only: true,
+                ..
+            }))
+            | ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                decl: DefaultDecl::TsInterfaceDecl(..),
+                ..
+            }))
+            | ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                decl:
+                    DefaultDecl::Fn(FnExpr {
+                        function: Function { body: None, .. },
+                        ..
+                    }),
+                ..
+            })) => false,
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. }))
+            | ModuleItem::Stmt(Stmt::Decl(decl)) => !is_type_decl(decl),
+            _ => true,
+        });
+
+        items.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        stmts.retain(|stmt| match stmt {
+            Stmt::Decl(decl) => !is_type_decl(decl),
+            _ => true,
+        });
+
+        stmts.visit_mut_children_with(self);
+    }
+}
+
+/// Returns true if `decl` does not exist at runtime.
+fn is_type_decl(decl: &Decl) -> bool {
+    match decl {
+        Decl::TsInterface(..) | Decl::TsTypeAlias(..) => true,
+        // `declare module`
+        Decl::TsModule(..) => true,
+        Decl::Class(ClassDecl { declare, .. })
+        | Decl::Fn(FnDecl { declare, .. })
+        | Decl::Var(VarDecl { declare, .. })
+        | Decl::TsEnum(TsEnumDecl { declare, .. }) => *declare,
+    }
+}
+
+fn is_this_param(pat: &Pat) -> bool {
+    match pat {
+        Pat::Ident(BindingIdent {
+            id: Ident {
+                sym: js_word!("this"),
+                ..
+            },
+            ..
+        }) => true,
+        _ => false,
+    }
+}
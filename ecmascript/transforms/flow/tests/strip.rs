@@ -0,0 +1,120 @@
+use swc_ecma_parser::{FlowConfig, Syntax};
+use swc_ecma_transforms_flow::strip;
+use swc_ecma_transforms_testing::test;
+
+macro_rules! to {
+    ($name:ident, $from:expr, $to:expr) => {
+        test!(
+            Syntax::Flow(FlowConfig {
+                jsx: true,
+                ..Default::default()
+            }),
+            |_| strip(),
+            $name,
+            $from,
+            $to,
+            ok_if_code_eq
+        );
+    };
+}
+
+to!(
+    annotations,
+    "function foo<T>(a: number, b?: ?string, ...rest: Array<T>): void {}
+const bar = (x: number): number => x;
+let baz: {| a: number |} = { a: 1 };",
+    "function foo(a, b, ...rest) {}
+const bar = (x) => x;
+let baz = { a: 1 };"
+);
+
+to!(
+    type_decls,
+    "type A = number;
+opaque type B: A = number;
+interface C { a: A }
+declare var d: number;
+declare function e(): void;
+declare class F {}
+declare module 'g' { declare export var h: number; }
+export type I = A;
+export opaque type J = string;
+export interface K {}
+declare export function l(): void;
+const m = 1;",
+    "const m = 1;"
+);
+
+to!(
+    imports,
+    "import type A from 'a';
+import typeof B from 'b';
+import { type C, typeof D } from 'c';
+import { type E, f } from 'e';
+import G from 'g';
+export type { A };",
+    "import { f } from 'e';
+import G from 'g';"
+);
+
+to!(
+    type_cast,
+    "const a = (b: any);
+const c = ((d: any): string);",
+    "const a = b;
+const c = d;"
+);
+
+to!(
+    generic_call,
+    "const a = foo<string>(1);
+const b = new Bar<number>();",
+    "const a = foo(1);
+const b = new Bar();"
+);
+
+to!(
+    class,
+    "class A<T> extends B<T> implements C {
+    a: number;
+    b: string = '';
+    static c: ?T;
+    declare d: number;
+    m(this: A, x: T): T { return x; }
+}",
+    "class A extends B {
+    a;
+    b = '';
+    static c;
+    m(x) { return x; }
+}"
+);
+
+to!(
+    predicate,
+    "function isString(x: mixed): boolean %checks {
+    return typeof x === 'string';
+}",
+    "function isString(x) {
+    return typeof x === 'string';
+}"
+);
+
+to!(
+    inline_type_imports,
+    "import D, { type E, typeof F as G, h } from 'd';
+import { type I, typeof J } from 'i';
+import typeof K, { l } from 'k';
+import { type as m } from 'm';
+h(D, m);",
+    "import D, { h } from 'd';
+import { type as m } from 'm';
+h(D, m);"
+);
+
+to!(
+    object_types,
+    "type A = { [string]: number, ...B, c: (string, ?number) => void, ... };
+function f(a: {| [key: string]: A |}, b: { ...A }): (A) => void {}",
+    "function f(a, b) {}"
+);
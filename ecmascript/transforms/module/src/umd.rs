@@ -504,7 +504,10 @@ impl Fold for Umd {
                 pat: Pat::Ident(ident.clone().into()),
             });
             factory_args.push(make_require_call(self.root_mark, src.clone()).as_arg());
-            global_factory_args.push(quote_ident!("global").make_member(global_ident).as_arg());
+            global_factory_args.push(match self.config.globals.get(&*src) {
+                Some(global) => global.clone().as_arg(),
+                None => quote_ident!("global").make_member(global_ident).as_arg(),
+            });
 
             {
                 // handle interop
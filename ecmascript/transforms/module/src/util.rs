@@ -584,6 +584,7 @@ impl Scope {
                                     right: Box::new(Expr::Lit(Lit::Num(Number {
                                         span: DUMMY_SP,
                                         value: 1.0,
+                                        raw: None
                                     }))),
                                 })),
                             }))
@@ -951,6 +951,46 @@ import fizzBuzz from "fizzbuzz";
 "#
 );
 
+test!(
+    syntax(),
+    |tester| tr(
+        tester,
+        Config {
+            globals: vec![("react".into(), "window.React".into())]
+                .into_iter()
+                .collect(),
+            ..Default::default()
+        }
+    ),
+    imports_globals,
+    r#"
+import React from "react";
+import fizzBuzz from "fizzbuzz";
+
+"#,
+    r#"
+(function (global, factory) {
+  if (typeof define === "function" && define.amd) {
+    define(["react", "fizzbuzz"], factory);
+  } else if (typeof exports !== "undefined") {
+    factory(require("react"), require("fizzbuzz"));
+  } else {
+    var mod = {
+      exports: {}
+    };
+    factory(window.React, global.fizzbuzz);
+    global.input = mod.exports;
+  }
+})(this, function (_react, _fizzbuzz) {
+  "use strict";
+
+  _react = _interopRequireDefault(_react);
+  _fizzbuzz = _interopRequireDefault(_fizzbuzz);
+});
+
+"#
+);
+
 // module_id_with_overridden_global_in_namespace
 
 // regression_4192
@@ -103,6 +103,7 @@ impl SimplifyExpr {
                 KnownOp::Len => Expr::Lit(Lit::Num(Number {
                     value: value.chars().count() as f64,
                     span,
+                    raw: None,
                 })),
 
                 // 'foo'[1]
@@ -150,6 +151,7 @@ impl SimplifyExpr {
                 Expr::Lit(Lit::Num(Number {
                     value: elems.len() as _,
                     span,
+                    raw: None,
                 }))
             }
 
@@ -298,7 +300,11 @@ impl SimplifyExpr {
                     Known(v) => {
                         return preserve_effects(
                             span,
-                            Expr::Lit(Lit::Num(Number { value: v, span })),
+                            Expr::Lit(Lit::Num(Number {
+                                value: v,
+                                span,
+                                raw: None,
+                            })),
                             { iter::once(left).chain(iter::once(right)) },
                         );
                     }
@@ -373,7 +379,11 @@ impl SimplifyExpr {
                                 Known(v) => {
                                     return preserve_effects(
                                         span,
-                                        Expr::Lit(Lit::Num(Number { value: v, span })),
+                                        Expr::Lit(Lit::Num(Number {
+                                            value: v,
+                                            span,
+                                            raw: None,
+                                        })),
                                         iter::once(left).chain(iter::once(right)),
                                     );
                                 }
@@ -561,7 +571,11 @@ impl SimplifyExpr {
                                 span,
                                 left: left_lhs,
                                 op: left_op,
-                                right: Box::new(Expr::Lit(Lit::Num(Number { value, span }))),
+                                right: Box::new(Expr::Lit(Lit::Num(Number {
+                                    value,
+                                    span,
+                                    raw: None,
+                                }))),
                             });
                         }
                     }
@@ -655,7 +669,11 @@ impl SimplifyExpr {
                 Known(v) => {
                     return preserve_effects(
                         span,
-                        Expr::Lit(Lit::Num(Number { value: v, span })),
+                        Expr::Lit(Lit::Num(Number {
+                            value: v,
+                            span,
+                            raw: None,
+                        })),
                         iter::once(arg),
                     );
                 }
@@ -673,7 +691,11 @@ impl SimplifyExpr {
                     ..
                 }) => return *arg,
                 Expr::Lit(Lit::Num(Number { value: f, .. })) => {
-                    return Expr::Lit(Lit::Num(Number { value: -f, span }));
+                    return Expr::Lit(Lit::Num(Number {
+                        value: -f,
+                        span,
+                        raw: None,
+                    }));
                 }
                 _ => {
 
@@ -687,6 +709,7 @@ impl SimplifyExpr {
                     arg: Box::new(Expr::Lit(Lit::Num(Number {
                         value: 0.0,
                         span: arg.span(),
+                        raw: None,
                     }))),
                     span,
                 });
@@ -702,6 +725,7 @@ impl SimplifyExpr {
                             } else {
                                 !(value as u32) as i32 as f64
                             },
+                            raw: None,
                         }));
                     }
                     // TODO: Report error
@@ -929,7 +953,11 @@ impl SimplifyExpr {
                 self.perform_abstract_eq_cmp(
                     span,
                     left,
-                    &Expr::Lit(Lit::Num(Number { value: rv, span })),
+                    &Expr::Lit(Lit::Num(Number {
+                        value: rv,
+                        span,
+                        raw: None,
+                    })),
                 )
             }
 
@@ -937,7 +965,11 @@ impl SimplifyExpr {
                 let lv = try_val!(left.as_number());
                 self.perform_abstract_eq_cmp(
                     span,
-                    &Expr::Lit(Lit::Num(Number { value: lv, span })),
+                    &Expr::Lit(Lit::Num(Number {
+                        value: lv,
+                        span,
+                        raw: None,
+                    })),
                     right,
                 )
             }
@@ -102,6 +102,7 @@ impl ParamMetadata {
                                     Lit::Num(Number {
                                         span: DUMMY_SP,
                                         value: param_index as _,
+                                        raw: None,
                                     })
                                     .as_arg(),
                                 ],
@@ -98,7 +98,7 @@ impl Fold for DisplayName {
                             },
                         }))),
                         PropName::Str(ref s) => Box::new(Expr::Lit(Lit::Str(s.clone()))),
-                        PropName::Num(n) => Box::new(Expr::Lit(Lit::Num(n))),
+                        PropName::Num(ref n) => Box::new(Expr::Lit(Lit::Num(n.clone()))),
                         PropName::BigInt(ref b) => Box::new(Expr::Lit(Lit::BigInt(b.clone()))),
                         PropName::Computed(ref c) => c.expr.clone(),
                     }),
@@ -55,6 +55,7 @@ impl Fold for JsxSrc {
                                 value: Box::new(Expr::Lit(Lit::Num(Number {
                                     span: DUMMY_SP,
                                     value: (file_lines.lines[0].line_index + 1) as _,
+                                    raw: None,
                                 }))),
                             }))),
                         ],
@@ -13,6 +13,8 @@ pub use swc_ecma_transforms_base::perf;
 pub use swc_ecma_transforms_base::resolver;
 #[cfg(feature = "swc_ecma_transforms_compat")]
 pub use swc_ecma_transforms_compat as compat;
+#[cfg(feature = "swc_ecma_transforms_flow")]
+pub use swc_ecma_transforms_flow as flow;
 #[cfg(feature = "swc_ecma_transforms_module")]
 pub use swc_ecma_transforms_module as modules;
 #[cfg(feature = "swc_ecma_transforms_optimization")]
@@ -436,6 +436,10 @@ impl VisitMut for Normalizer {
         }
     }
 
+    fn visit_mut_number(&mut self, n: &mut Number) {
+        n.raw = None;
+    }
+
     fn visit_mut_str(&mut self, s: &mut Str) {
         s.kind = Default::default();
     }
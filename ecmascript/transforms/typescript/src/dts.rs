@@ -0,0 +1,1008 @@
+use fxhash::FxHashSet;
+use std::mem::take;
+use swc_atoms::JsWord;
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_utils::{
+    find_ids, ident::IdentLike, private_ident, prop_name_to_expr, quote_ident, Id,
+};
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, Node, Visit, VisitMut, VisitWith};
+
+/// Creates a declaration file (`.d.ts`) out of a typescript module.
+///
+/// This works on a single file and does not type check it, so it's an
+/// "isolated declarations" emitter. Function bodies, initializers and the
+/// types of private members are removed, and declarations which are not
+/// reachable from an export are dropped. If a binding does not have a type
+/// annotation, its type is inferred from the initializer when it's trivial and
+/// `any` is used otherwise.
+///
+/// The output is meant to be printed by `swc_ecma_codegen`, after
+/// `hygiene` renames the synthesized bindings.
+pub fn dts() -> impl Fold {
+    as_folder(Dts)
+}
+
+struct Dts;
+
+impl VisitMut for Dts {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module(&mut self, module: &mut Module) {
+        let is_module = module.body.iter().any(|item| item.is_module_decl());
+
+        let (mut body, has_local) = module_items(take(&mut module.body), !is_module, false);
+
+        // Declarations in a declaration file are exported implicitly unless the
+        // file has an export statement.
+        if is_module && (has_local || !body.iter().any(|item| item.is_module_decl())) {
+            body.push(ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(
+                NamedExport {
+                    span: DUMMY_SP,
+                    specifiers: vec![],
+                    src: None,
+                    type_only: false,
+                    asserts: None,
+                },
+            )));
+        }
+
+        module.body = body;
+    }
+}
+
+struct Item {
+    item: ModuleItem,
+    /// Bindings introduced by the item.
+    ids: Vec<Id>,
+    used: bool,
+    /// `true` for declarations which are not exported.
+    local: bool,
+}
+
+/// Converts the items of a module or a namespace.
+///
+/// If `export_all` is true, all declarations are preserved. Otherwise only
+/// exported declarations and declarations referenced by them are preserved.
+///
+/// Returns the new items and whether a declaration which is not exported was
+/// preserved.
+fn module_items(
+    items: Vec<ModuleItem>,
+    export_all: bool,
+    ambient: bool,
+) -> (Vec<ModuleItem>, bool) {
+    let declare = !ambient;
+    let overloads = fn_overloads(&items);
+    let mut default_overload = false;
+
+    let mut buf = vec![];
+    let mut push = |item: ModuleItem, ids: Vec<Id>, used: bool, local: bool| {
+        buf.push(Item {
+            item,
+            ids,
+            used,
+            local,
+        })
+    };
+
+    for item in items {
+        match item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
+                let ids = import
+                    .specifiers
+                    .iter()
+                    .map(|s| import_specifier_local(s).to_id())
+                    .collect();
+                let used = import.specifiers.is_empty();
+                push(
+                    ModuleItem::ModuleDecl(ModuleDecl::Import(import)),
+                    ids,
+                    used,
+                    false,
+                );
+            }
+
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => {
+                if let Some(decl) = convert_decl(export.decl, declare, &overloads) {
+                    push(
+                        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                            decl,
+                            ..export
+                        })),
+                        vec![],
+                        true,
+                        false,
+                    );
+                }
+            }
+
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(mut export)) => {
+                match &mut export.decl {
+                    DefaultDecl::Class(c) => class(&mut c.class),
+                    DefaultDecl::Fn(f) => {
+                        if f.function.body.is_none() {
+                            default_overload = true;
+                        } else if default_overload {
+                            continue;
+                        }
+                        function(&mut f.function);
+                    }
+                    DefaultDecl::TsInterfaceDecl(_) => {}
+                }
+
+                push(
+                    ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(export)),
+                    vec![],
+                    true,
+                    false,
+                );
+            }
+
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(export)) => {
+                if export.expr.is_ident() {
+                    push(
+                        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(export)),
+                        vec![],
+                        true,
+                        false,
+                    );
+                    continue;
+                }
+
+                // `export default expr` is printed as
+                // `declare const _default: T; export default _default;`
+                let id = private_ident!("_default");
+                let ty = infer_type(&export.expr, true).unwrap_or_else(any);
+                push(
+                    ModuleItem::Stmt(Stmt::Decl(Decl::Var(VarDecl {
+                        span: export.span,
+                        kind: VarDeclKind::Const,
+                        declare,
+                        decls: vec![VarDeclarator {
+                            span: DUMMY_SP,
+                            name: Pat::Ident(BindingIdent {
+                                id: id.clone(),
+                                type_ann: Some(type_ann(ty)),
+                            }),
+                            init: None,
+                            definite: false,
+                        }],
+                    }))),
+                    vec![],
+                    true,
+                    false,
+                );
+                push(
+                    ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
+                        span: export.span,
+                        expr: Box::new(Expr::Ident(id)),
+                    })),
+                    vec![],
+                    true,
+                    false,
+                );
+            }
+
+            ModuleItem::ModuleDecl(ModuleDecl::TsImportEquals(import)) if !import.is_export => {
+                let ids = vec![import.id.to_id()];
+                push(
+                    ModuleItem::ModuleDecl(ModuleDecl::TsImportEquals(import)),
+                    ids,
+                    export_all,
+                    !export_all,
+                );
+            }
+
+            ModuleItem::ModuleDecl(decl) => {
+                push(ModuleItem::ModuleDecl(decl), vec![], true, false);
+            }
+
+            ModuleItem::Stmt(Stmt::Decl(decl)) => {
+                let decl = match convert_decl(decl, declare, &overloads) {
+                    Some(decl) => decl,
+                    None => continue,
+                };
+                let ids = decl_ids(&decl);
+                // `declare module 'foo' {}` and `declare global {}` augment other modules.
+                let used = export_all
+                    || match &decl {
+                        Decl::TsModule(m) => m.global || m.id.is_str(),
+                        _ => false,
+                    };
+
+                push(ModuleItem::Stmt(Stmt::Decl(decl)), ids, used, !used);
+            }
+
+            ModuleItem::Stmt(_) => {}
+        }
+    }
+
+    let mut refs = RefCollector::default();
+    for item in &buf {
+        if item.used {
+            item.item
+                .visit_with(&Invalid { span: DUMMY_SP } as _, &mut refs);
+        }
+    }
+
+    loop {
+        let mut changed = false;
+
+        for item in &mut buf {
+            if item.used || !item.ids.iter().any(|id| refs.refs.contains(id)) {
+                continue;
+            }
+
+            item.used = true;
+            item.item
+                .visit_with(&Invalid { span: DUMMY_SP } as _, &mut refs);
+            changed = true;
+        }
+
+        if !changed {
+            break;
+        }
+    }
+
+    let mut has_local = false;
+    let mut items = vec![];
+
+    for Item {
+        item,
+        ids,
+        used,
+        local,
+    } in buf
+    {
+        match item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(mut import)) if !ids.is_empty() => {
+                import
+                    .specifiers
+                    .retain(|s| refs.refs.contains(&import_specifier_local(s).to_id()));
+                if import.specifiers.is_empty() {
+                    continue;
+                }
+
+                items.push(ModuleItem::ModuleDecl(ModuleDecl::Import(import)));
+            }
+
+            _ => {
+                if !used {
+                    continue;
+                }
+                has_local |= local;
+                items.push(item);
+            }
+        }
+    }
+
+    (items, has_local)
+}
+
+/// Returns the names of functions which have overload signatures.
+fn fn_overloads(items: &[ModuleItem]) -> FxHashSet<Id> {
+    items
+        .iter()
+        .filter_map(|item| match item {
+            ModuleItem::Stmt(Stmt::Decl(Decl::Fn(f)))
+            | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                decl: Decl::Fn(f),
+                ..
+            })) if f.function.body.is_none() && !f.declare => Some(f.ident.to_id()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn import_specifier_local(s: &ImportSpecifier) -> &Ident {
+    match s {
+        ImportSpecifier::Named(s) => &s.local,
+        ImportSpecifier::Default(s) => &s.local,
+        ImportSpecifier::Namespace(s) => &s.local,
+    }
+}
+
+fn decl_ids(decl: &Decl) -> Vec<Id> {
+    match decl {
+        Decl::Class(c) => vec![c.ident.to_id()],
+        Decl::Fn(f) => vec![f.ident.to_id()],
+        Decl::Var(v) => v
+            .decls
+            .iter()
+            .filter_map(|d| match &d.name {
+                Pat::Ident(i) => Some(i.id.to_id()),
+                _ => None,
+            })
+            .collect(),
+        Decl::TsInterface(i) => vec![i.id.to_id()],
+        Decl::TsTypeAlias(a) => vec![a.id.to_id()],
+        Decl::TsEnum(e) => vec![e.id.to_id()],
+        Decl::TsModule(m) => match &m.id {
+            TsModuleName::Ident(i) => vec![i.to_id()],
+            TsModuleName::Str(_) => vec![],
+        },
+    }
+}
+
+/// Converts a declaration into its ambient form.
+///
+/// Returns `None` if the declaration should be removed.
+fn convert_decl(decl: Decl, declare: bool, overloads: &FxHashSet<Id>) -> Option<Decl> {
+    match decl {
+        Decl::Class(mut c) => {
+            if !c.declare {
+                c.declare = declare;
+                class(&mut c.class);
+            }
+
+            Some(Decl::Class(c))
+        }
+
+        Decl::Fn(mut f) => {
+            if f.function.body.is_some() && overloads.contains(&f.ident.to_id()) {
+                return None;
+            }
+            if !f.declare {
+                f.declare = declare;
+                function(&mut f.function);
+            }
+
+            Some(Decl::Fn(f))
+        }
+
+        Decl::Var(mut v) => {
+            if !v.declare {
+                v.declare = declare;
+
+                let widen = v.kind != VarDeclKind::Const;
+                v.decls = v
+                    .decls
+                    .into_iter()
+                    .flat_map(|d| var_declarator(d, widen))
+                    .collect();
+                if v.decls.is_empty() {
+                    return None;
+                }
+            }
+
+            Some(Decl::Var(v))
+        }
+
+        Decl::TsEnum(mut e) => {
+            e.declare |= declare;
+
+            Some(Decl::TsEnum(e))
+        }
+
+        Decl::TsModule(mut m) => {
+            if !m.declare {
+                m.declare = declare;
+                m.body = m.body.map(namespace_body);
+            }
+
+            Some(Decl::TsModule(m))
+        }
+
+        Decl::TsInterface(..) | Decl::TsTypeAlias(..) => Some(decl),
+    }
+}
+
+fn namespace_body(body: TsNamespaceBody) -> TsNamespaceBody {
+    match body {
+        TsNamespaceBody::TsModuleBlock(mut block) => {
+            block.body = module_items(block.body, false, true).0;
+
+            TsNamespaceBody::TsModuleBlock(block)
+        }
+        TsNamespaceBody::TsNamespaceDecl(mut decl) => {
+            decl.body = Box::new(namespace_body(*decl.body));
+
+            TsNamespaceBody::TsNamespaceDecl(decl)
+        }
+    }
+}
+
+/// Destructuring patterns are expanded to a declarator for each binding,
+/// typed as `any`.
+fn var_declarator(d: VarDeclarator, widen: bool) -> Vec<VarDeclarator> {
+    let mut name = match d.name {
+        Pat::Ident(name) => name,
+        pat => {
+            let ids: Vec<Ident> = find_ids(&pat);
+            return ids
+                .into_iter()
+                .map(|id| VarDeclarator {
+                    span: d.span,
+                    name: Pat::Ident(BindingIdent {
+                        id,
+                        type_ann: Some(type_ann(any())),
+                    }),
+                    init: None,
+                    definite: false,
+                })
+                .collect();
+        }
+    };
+
+    if name.type_ann.is_none() {
+        let ty = d
+            .init
+            .as_deref()
+            .and_then(|init| infer_type(init, widen))
+            .unwrap_or_else(any);
+        name.type_ann = Some(type_ann(ty));
+    }
+
+    vec![VarDeclarator {
+        span: d.span,
+        name: Pat::Ident(name),
+        init: None,
+        definite: false,
+    }]
+}
+
+/// Removes the body of a function and infers the return type.
+fn function(f: &mut Function) {
+    f.decorators.clear();
+
+    if f.return_type.is_none() {
+        f.return_type = Some(type_ann(return_type(
+            f.is_async,
+            f.is_generator,
+            f.body.as_ref(),
+        )));
+    }
+
+    f.params = take(&mut f.params)
+        .into_iter()
+        .map(|p| Param {
+            decorators: vec![],
+            pat: param(p.pat),
+            ..p
+        })
+        .collect();
+    f.body = None;
+    f.is_async = false;
+    f.is_generator = false;
+}
+
+fn class(class: &mut Class) {
+    class.decorators.clear();
+
+    let mut has_ctor_overload = false;
+    let mut overloads = FxHashSet::default();
+    for member in &class.body {
+        match member {
+            ClassMember::Constructor(c) if c.body.is_none() => has_ctor_overload = true,
+            ClassMember::Method(m) if m.function.body.is_none() && !m.is_abstract => {
+                overloads.insert((m.is_static, prop_name_key(&m.key)));
+            }
+            _ => {}
+        }
+    }
+
+    let mut members = vec![];
+    let mut privates = FxHashSet::default();
+    let mut has_private_name = false;
+
+    for member in take(&mut class.body) {
+        match member {
+            ClassMember::Constructor(mut c) => {
+                if c.body.is_some() && has_ctor_overload {
+                    continue;
+                }
+                let is_private = c.accessibility == Some(Accessibility::Private);
+
+                for p in take(&mut c.params) {
+                    let pat = match p {
+                        ParamOrTsParamProp::TsParamProp(prop) => {
+                            let pat = param(match prop.param {
+                                TsParamPropParam::Ident(i) => Pat::Ident(i),
+                                TsParamPropParam::Assign(a) => Pat::Assign(a),
+                            });
+
+                            if let Pat::Ident(i) = &pat {
+                                let is_private_prop =
+                                    prop.accessibility == Some(Accessibility::Private);
+                                members.push(ClassMember::ClassProp(ClassProp {
+                                    span: prop.span,
+                                    key: Box::new(Expr::Ident(Ident::new(
+                                        i.id.sym.clone(),
+                                        i.id.span,
+                                    ))),
+                                    value: None,
+                                    type_ann: if is_private_prop {
+                                        None
+                                    } else {
+                                        i.type_ann.clone()
+                                    },
+                                    is_static: false,
+                                    decorators: vec![],
+                                    computed: false,
+                                    accessibility: prop.accessibility,
+                                    is_abstract: false,
+                                    is_optional: i.id.optional,
+                                    is_override: false,
+                                    readonly: prop.readonly,
+                                    declare: false,
+                                    definite: false,
+                                }));
+                            }
+
+                            Param {
+                                span: prop.span,
+                                decorators: vec![],
+                                pat,
+                            }
+                        }
+                        ParamOrTsParamProp::Param(p) => Param {
+                            decorators: vec![],
+                            pat: param(p.pat),
+                            ..p
+                        },
+                    };
+
+                    if !is_private {
+                        c.params.push(ParamOrTsParamProp::Param(pat));
+                    }
+                }
+
+                c.body = None;
+                members.push(ClassMember::Constructor(c));
+            }
+
+            ClassMember::Method(mut m) => {
+                let key = (m.is_static, prop_name_key(&m.key));
+                if m.function.body.is_some() && overloads.contains(&key) {
+                    continue;
+                }
+
+                if m.accessibility == Some(Accessibility::Private) {
+                    // Getters and setters share a property.
+                    if privates.insert(key) {
+                        members.push(ClassMember::ClassProp(private_prop(m.key, m.is_static)));
+                    }
+                    continue;
+                }
+
+                function(&mut m.function);
+                if m.kind == MethodKind::Setter {
+                    m.function.return_type = None;
+                }
+
+                members.push(ClassMember::Method(m));
+            }
+
+            ClassMember::ClassProp(mut p) => {
+                if p.accessibility == Some(Accessibility::Private) {
+                    p.type_ann = None;
+                } else if p.type_ann.is_none() {
+                    let ty = p
+                        .value
+                        .as_deref()
+                        .and_then(|value| infer_type(value, !p.readonly))
+                        .unwrap_or_else(any);
+                    p.type_ann = Some(type_ann(ty));
+                }
+
+                p.value = None;
+                p.decorators.clear();
+                p.declare = false;
+                p.definite = false;
+                members.push(ClassMember::ClassProp(p));
+            }
+
+            // All private names are replaced with a single `#private;`, which
+            // makes the class nominal.
+            ClassMember::PrivateMethod(..) | ClassMember::PrivateProp(..) => {
+                if !has_private_name {
+                    has_private_name = true;
+                    members.push(ClassMember::PrivateProp(PrivateProp {
+                        span: DUMMY_SP,
+                        key: PrivateName {
+                            span: DUMMY_SP,
+                            id: quote_ident!("private"),
+                        },
+                        value: None,
+                        type_ann: None,
+                        is_static: false,
+                        decorators: vec![],
+                        computed: false,
+                        accessibility: None,
+                        is_abstract: false,
+                        is_optional: false,
+                        is_override: false,
+                        readonly: false,
+                        definite: false,
+                    }));
+                }
+            }
+
+            ClassMember::TsIndexSignature(..) => members.push(member),
+
+            ClassMember::Empty(..) | ClassMember::StaticBlock(..) => {}
+        }
+    }
+
+    class.body = members;
+}
+
+fn private_prop(key: PropName, is_static: bool) -> ClassProp {
+    let computed = matches!(key, PropName::Computed(..));
+
+    ClassProp {
+        span: DUMMY_SP,
+        key: Box::new(prop_name_to_expr(key)),
+        value: None,
+        type_ann: None,
+        is_static,
+        decorators: vec![],
+        computed,
+        accessibility: Some(Accessibility::Private),
+        is_abstract: false,
+        is_optional: false,
+        is_override: false,
+        readonly: false,
+        declare: false,
+        definite: false,
+    }
+}
+
+fn prop_name_key(key: &PropName) -> Option<JsWord> {
+    match key {
+        PropName::Ident(i) => Some(i.sym.clone()),
+        PropName::Str(s) => Some(s.value.clone()),
+        _ => None,
+    }
+}
+
+/// Converts a parameter of a function into the form used by signatures.
+fn param(pat: Pat) -> Pat {
+    let (pat, optional, default_ty) = match pat {
+        Pat::Assign(p) => {
+            let ty = p
+                .type_ann
+                .map(|ty| ty.type_ann)
+                .or_else(|| infer_type(&p.right, true));
+            (*p.left, true, ty)
+        }
+        _ => (pat, false, None),
+    };
+
+    let mut pat = strip_defaults(pat);
+    let ty = default_ty.unwrap_or_else(any);
+
+    match &mut pat {
+        Pat::Ident(i) => {
+            i.id.optional |= optional;
+            i.type_ann.get_or_insert_with(|| type_ann(ty));
+        }
+        Pat::Array(p) => {
+            p.optional |= optional;
+            p.type_ann.get_or_insert_with(|| type_ann(ty));
+        }
+        Pat::Object(p) => {
+            p.optional |= optional;
+            p.type_ann.get_or_insert_with(|| type_ann(ty));
+        }
+        Pat::Rest(p) => {
+            p.type_ann.get_or_insert_with(|| {
+                type_ann(Box::new(TsType::TsArrayType(TsArrayType {
+                    span: DUMMY_SP,
+                    elem_type: any(),
+                })))
+            });
+        }
+        _ => {}
+    }
+
+    pat
+}
+
+/// Removes default values, which are not allowed in ambient contexts.
+fn strip_defaults(pat: Pat) -> Pat {
+    match pat {
+        Pat::Assign(p) => strip_defaults(*p.left),
+        Pat::Array(mut p) => {
+            p.elems = p
+                .elems
+                .into_iter()
+                .map(|elem| elem.map(strip_defaults))
+                .collect();
+            Pat::Array(p)
+        }
+        Pat::Object(mut p) => {
+            p.props = p
+                .props
+                .into_iter()
+                .map(|prop| match prop {
+                    ObjectPatProp::KeyValue(mut kv) => {
+                        kv.value = Box::new(strip_defaults(*kv.value));
+                        ObjectPatProp::KeyValue(kv)
+                    }
+                    ObjectPatProp::Assign(mut a) => {
+                        a.value = None;
+                        ObjectPatProp::Assign(a)
+                    }
+                    ObjectPatProp::Rest(r) => ObjectPatProp::Rest(r),
+                })
+                .collect();
+            Pat::Object(p)
+        }
+        Pat::Rest(mut p) => {
+            p.arg = Box::new(strip_defaults(*p.arg));
+            Pat::Rest(p)
+        }
+        _ => pat,
+    }
+}
+
+fn return_type(is_async: bool, is_generator: bool, body: Option<&BlockStmt>) -> Box<TsType> {
+    if is_generator {
+        return any();
+    }
+
+    let ty = match body {
+        Some(body) => {
+            let mut v = ReturnFinder::default();
+            body.visit_with(&Invalid { span: DUMMY_SP } as _, &mut v);
+            if v.found {
+                any()
+            } else {
+                keyword(TsKeywordTypeKind::TsVoidKeyword)
+            }
+        }
+        None => any(),
+    };
+
+    if is_async {
+        promise(ty)
+    } else {
+        ty
+    }
+}
+
+/// Infers the type of an expression without type checking.
+///
+/// If `widen` is false, literal types are used for literals.
+fn infer_type(e: &Expr, widen: bool) -> Option<Box<TsType>> {
+    let lit = |lit: TsLit, kind: TsKeywordTypeKind| {
+        if widen {
+            keyword(kind)
+        } else {
+            Box::new(TsType::TsLitType(TsLitType {
+                span: DUMMY_SP,
+                lit,
+            }))
+        }
+    };
+
+    Some(match e {
+        Expr::Lit(Lit::Str(s)) => lit(TsLit::Str(s.clone()), TsKeywordTypeKind::TsStringKeyword),
+        Expr::Lit(Lit::Num(n)) => lit(TsLit::Number(n.clone()), TsKeywordTypeKind::TsNumberKeyword),
+        Expr::Lit(Lit::Bool(b)) => lit(TsLit::Bool(b.clone()), TsKeywordTypeKind::TsBooleanKeyword),
+        Expr::Lit(Lit::BigInt(b)) => {
+            lit(TsLit::BigInt(b.clone()), TsKeywordTypeKind::TsBigIntKeyword)
+        }
+        Expr::Lit(Lit::Null(..)) if !widen => keyword(TsKeywordTypeKind::TsNullKeyword),
+        Expr::Lit(Lit::Regex(..)) => type_ref(quote_ident!("RegExp"), None),
+        Expr::Tpl(..) => keyword(TsKeywordTypeKind::TsStringKeyword),
+
+        Expr::Unary(UnaryExpr { op, arg, .. }) => match op {
+            UnaryOp::Minus | UnaryOp::Tilde if arg.is_lit() => match &**arg {
+                Expr::Lit(Lit::BigInt(..)) => keyword(TsKeywordTypeKind::TsBigIntKeyword),
+                _ => keyword(TsKeywordTypeKind::TsNumberKeyword),
+            },
+            UnaryOp::Plus => keyword(TsKeywordTypeKind::TsNumberKeyword),
+            UnaryOp::Bang | UnaryOp::Delete => keyword(TsKeywordTypeKind::TsBooleanKeyword),
+            UnaryOp::TypeOf => keyword(TsKeywordTypeKind::TsStringKeyword),
+            UnaryOp::Void => keyword(TsKeywordTypeKind::TsUndefinedKeyword),
+            _ => return None,
+        },
+
+        Expr::Bin(BinExpr { op, .. }) => match op {
+            BinaryOp::EqEq
+            | BinaryOp::NotEq
+            | BinaryOp::EqEqEq
+            | BinaryOp::NotEqEq
+            | BinaryOp::Lt
+            | BinaryOp::LtEq
+            | BinaryOp::Gt
+            | BinaryOp::GtEq
+            | BinaryOp::In
+            | BinaryOp::InstanceOf => keyword(TsKeywordTypeKind::TsBooleanKeyword),
+            _ => return None,
+        },
+
+        Expr::Paren(e) => return infer_type(&e.expr, widen),
+        Expr::TsAs(TsAsExpr { type_ann, .. })
+        | Expr::TsTypeAssertion(TsTypeAssertion { type_ann, .. }) => type_ann.clone(),
+        Expr::TsConstAssertion(e) => return infer_type(&e.expr, false),
+
+        Expr::New(NewExpr {
+            callee, type_args, ..
+        }) => match &**callee {
+            Expr::Ident(i) => type_ref(i.clone(), type_args.clone()),
+            _ => return None,
+        },
+
+        Expr::Arrow(f) => {
+            let ret = match (&f.return_type, &f.body) {
+                (Some(ty), _) => ty.type_ann.clone(),
+                (None, BlockStmtOrExpr::BlockStmt(body)) => {
+                    return_type(f.is_async, f.is_generator, Some(body))
+                }
+                (None, BlockStmtOrExpr::Expr(e)) => {
+                    let ty = infer_type(e, true).unwrap_or_else(any);
+                    if f.is_async {
+                        promise(ty)
+                    } else {
+                        ty
+                    }
+                }
+            };
+
+            fn_type(f.type_params.clone(), f.params.clone(), ret)
+        }
+
+        Expr::Fn(f) => {
+            let f = &f.function;
+            let ret = match &f.return_type {
+                Some(ty) => ty.type_ann.clone(),
+                None => return_type(f.is_async, f.is_generator, f.body.as_ref()),
+            };
+
+            fn_type(
+                f.type_params.clone(),
+                f.params.iter().map(|p| p.pat.clone()).collect(),
+                ret,
+            )
+        }
+
+        _ => return None,
+    })
+}
+
+fn fn_type(
+    type_params: Option<TsTypeParamDecl>,
+    params: Vec<Pat>,
+    ret: Box<TsType>,
+) -> Box<TsType> {
+    let params = params
+        .into_iter()
+        .filter_map(|pat| match param(pat) {
+            Pat::Ident(i) => Some(TsFnParam::Ident(i)),
+            Pat::Array(p) => Some(TsFnParam::Array(p)),
+            Pat::Rest(p) => Some(TsFnParam::Rest(p)),
+            Pat::Object(p) => Some(TsFnParam::Object(p)),
+            _ => None,
+        })
+        .collect();
+
+    Box::new(TsType::TsFnOrConstructorType(
+        TsFnOrConstructorType::TsFnType(TsFnType {
+            span: DUMMY_SP,
+            params,
+            type_params,
+            type_ann: type_ann(ret),
+        }),
+    ))
+}
+
+fn type_ann(ty: Box<TsType>) -> TsTypeAnn {
+    TsTypeAnn {
+        span: DUMMY_SP,
+        type_ann: ty,
+    }
+}
+
+fn keyword(kind: TsKeywordTypeKind) -> Box<TsType> {
+    Box::new(TsType::TsKeywordType(TsKeywordType {
+        span: DUMMY_SP,
+        kind,
+    }))
+}
+
+fn any() -> Box<TsType> {
+    keyword(TsKeywordTypeKind::TsAnyKeyword)
+}
+
+fn promise(ty: Box<TsType>) -> Box<TsType> {
+    type_ref(
+        quote_ident!("Promise"),
+        Some(TsTypeParamInstantiation {
+            span: DUMMY_SP,
+            params: vec![ty],
+        }),
+    )
+}
+
+fn type_ref(name: Ident, type_params: Option<TsTypeParamInstantiation>) -> Box<TsType> {
+    Box::new(TsType::TsTypeRef(TsTypeRef {
+        span: DUMMY_SP,
+        type_name: TsEntityName::Ident(name),
+        type_params,
+    }))
+}
+
+/// Finds `return` statements with a value, ignoring nested functions.
+#[derive(Default)]
+struct ReturnFinder {
+    found: bool,
+}
+
+impl Visit for ReturnFinder {
+    fn visit_return_stmt(&mut self, n: &ReturnStmt, _: &dyn Node) {
+        if n.arg.is_some() {
+            self.found = true;
+        }
+    }
+
+    fn visit_function(&mut self, _: &Function, _: &dyn Node) {}
+
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr, _: &dyn Node) {}
+
+    fn visit_class(&mut self, _: &Class, _: &dyn Node) {}
+}
+
+/// Collects identifiers which may refer to a declaration.
+#[derive(Default)]
+struct RefCollector {
+    refs: FxHashSet<Id>,
+}
+
+impl Visit for RefCollector {
+    fn visit_expr(&mut self, e: &Expr, _: &dyn Node) {
+        match e {
+            Expr::Ident(i) => {
+                self.refs.insert(i.to_id());
+            }
+            _ => e.visit_children_with(self),
+        }
+    }
+
+    fn visit_member_expr(&mut self, e: &MemberExpr, _: &dyn Node) {
+        e.obj.visit_with(e as _, self);
+        if e.computed {
+            e.prop.visit_with(e as _, self);
+        }
+    }
+
+    fn visit_class_prop(&mut self, p: &ClassProp, _: &dyn Node) {
+        if p.computed {
+            p.key.visit_with(p as _, self);
+        }
+        p.type_ann.visit_with(p as _, self);
+    }
+
+    fn visit_ts_property_signature(&mut self, s: &TsPropertySignature, _: &dyn Node) {
+        if s.computed {
+            s.key.visit_with(s as _, self);
+        }
+        s.params.visit_with(s as _, self);
+        s.type_ann.visit_with(s as _, self);
+        s.type_params.visit_with(s as _, self);
+    }
+
+    fn visit_ts_entity_name(&mut self, n: &TsEntityName, _: &dyn Node) {
+        match n {
+            TsEntityName::Ident(i) => {
+                self.refs.insert(i.to_id());
+            }
+            TsEntityName::TsQualifiedName(q) => q.left.visit_with(n as _, self),
+        }
+    }
+
+    fn visit_named_export(&mut self, e: &NamedExport, _: &dyn Node) {
+        if e.src.is_some() {
+            return;
+        }
+
+        for s in &e.specifiers {
+            if let ExportSpecifier::Named(s) = s {
+                self.refs.insert(s.orig.to_id());
+            }
+        }
+    }
+}
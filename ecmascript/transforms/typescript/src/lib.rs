@@ -1,3 +1,5 @@
+pub use self::dts::dts;
 pub use self::strip::strip;
 
+pub mod dts;
 pub mod strip;
@@ -609,6 +609,7 @@ impl Strip {
                                 op!(">>>") => ((l.round() as u64) >> (r.round() as u64)) as _,
                                 _ => Err(())?,
                             },
+                            raw: None,
                         })
                     }
                     (TsLit::Str(l), TsLit::Str(r)) if expr.op == op!(bin, "+") => TsLit::Str(Str {
@@ -640,7 +641,7 @@ impl Strip {
             if let Some(expr) = init {
                 match expr {
                     Expr::Lit(Lit::Str(s)) => return Ok(TsLit::Str(s.clone())),
-                    Expr::Lit(Lit::Num(s)) => return Ok(TsLit::Number(*s)),
+                    Expr::Lit(Lit::Num(s)) => return Ok(TsLit::Number(s.clone())),
                     Expr::Bin(ref bin) => return compute_bin(e, span, values, &bin),
                     Expr::Paren(ref paren) => {
                         return compute(e, span, values, default, Some(&paren.expr))
@@ -689,6 +690,7 @@ impl Strip {
                                         op!("~") => (!(v as i32)) as f64,
                                         _ => Err(())?,
                                     },
+                                    raw: None,
                                 }))
                             }
                             TsLit::Str(_) => {}
@@ -710,6 +712,7 @@ impl Strip {
                     return Ok(TsLit::Number(Number {
                         span,
                         value: value as _,
+                        raw: None,
                     }));
                 }
             }
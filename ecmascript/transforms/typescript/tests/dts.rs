@@ -0,0 +1,151 @@
+use swc_ecma_parser::{Syntax, TsConfig};
+use swc_ecma_transforms_testing::test;
+use swc_ecma_transforms_typescript::dts;
+
+macro_rules! to {
+    ($name:ident, $from:expr, $to:expr) => {
+        test!(
+            Syntax::Typescript(TsConfig {
+                decorators: true,
+                ..Default::default()
+            }),
+            |_| dts(),
+            $name,
+            $from,
+            $to,
+            ok_if_code_eq
+        );
+    };
+}
+
+to!(
+    fn_decl,
+    "export function foo(a: number, b = 'x') { return a; }
+export async function bar() { await foo(1); }",
+    "export declare function foo(a: number, b?: string): any;
+export declare function bar(): Promise<void>;"
+);
+
+to!(
+    fn_overloads,
+    "export function foo(a: string): string;
+export function foo(a: number): number;
+export function foo(a: any) { return a; }",
+    "export declare function foo(a: string): string;
+export declare function foo(a: number): number;"
+);
+
+to!(
+    var_decl,
+    "export const a = 1, b = 'b', c = foo();
+export let d = 1;
+export const e = <Foo>bar;",
+    "export declare const a: 1, b: 'b', c: any;
+export declare let d: number;
+export declare const e: Foo;"
+);
+
+to!(
+    class_members,
+    "export class Foo extends Bar implements Baz {
+    #secret = 1;
+    private p = 1;
+    readonly r = 'r';
+    static s = 1;
+    constructor(public a: string, private b: number, c = 1) { super(); }
+    get x() { return this.p; }
+    set x(v: number) {}
+    method(): void { console.log(1); }
+    private helper() {}
+}",
+    "export declare class Foo extends Bar implements Baz {
+    #private;
+    private p;
+    readonly r: 'r';
+    static s: number;
+    a: string;
+    private b;
+    constructor(a: string, b: number, c?: number);
+    get x(): any;
+    set x(v: number);
+    method(): void;
+    private helper;
+}"
+);
+
+to!(
+    unexported_decls,
+    "import { A, B } from './types';
+import './side-effect';
+interface Internal { a: A }
+function helper() {}
+export function foo(): Internal { return helper(); }",
+    "import { A } from './types';
+import './side-effect';
+interface Internal {
+    a: A;
+}
+export declare function foo(): Internal;
+export {};"
+);
+
+to!(
+    exported_types,
+    "export interface Foo { a: string }
+export type Bar = Foo | number;
+export enum E { A = 1, B }
+export default function (a: Foo) { return 1; }",
+    "export interface Foo {
+    a: string;
+}
+export type Bar = Foo | number;
+export declare enum E {
+    A = 1,
+    B
+}
+export default function(a: Foo): any;"
+);
+
+to!(
+    export_default_expr,
+    "export default 1 + 2 > 2;",
+    "declare const _default: boolean;
+export default _default;"
+);
+
+to!(
+    export_default_expr_conflict,
+    "export const _default = 1;
+export default 1 + 2;",
+    "export declare const _default: 1;
+declare const _default1: number;
+export default _default1;"
+);
+
+to!(
+    var_decl_destructuring,
+    "export const { a, b: [c, ...d], e = 1 } = obj, f = 1;",
+    "export declare const a: any, c: any, d: any, e: any, f: 1;"
+);
+
+to!(
+    namespace,
+    "export namespace NS {
+    export const a = 1;
+    const b = 2;
+    export function f() { return b; }
+}",
+    "export declare namespace NS {
+    export const a: 1;
+    export function f(): any;
+}"
+);
+
+to!(
+    script,
+    "function foo(a) {}
+const bar = true;
+foo(bar);",
+    "declare function foo(a: any): void;
+declare const bar: true;"
+);
@@ -155,6 +155,7 @@ fn correctness_tests(tests: &mut Vec<TestDescAndFn>) -> Result<(), io::Error> {
                             dts: false,
                             no_early_errors: false,
                             import_assertions: true,
+                            error_recovery: false,
                         }),
                         (&*src).into(),
                         None,
@@ -164,7 +165,10 @@ fn correctness_tests(tests: &mut Vec<TestDescAndFn>) -> Result<(), io::Error> {
 
                     {
                         let mut emitter = Emitter {
-                            cfg: swc_ecma_codegen::Config { minify: false },
+                            cfg: swc_ecma_codegen::Config {
+                                minify: false,
+                                ..Default::default()
+                            },
                             cm: cm.clone(),
                             wr: Box::new(swc_ecma_codegen::text_writer::JsWriter::new(
                                 cm.clone(),
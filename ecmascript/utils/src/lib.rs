@@ -1496,7 +1496,12 @@ pub fn undefined(span: Span) -> Box<Expr> {
     Expr::Unary(UnaryExpr {
         span,
         op: op!("void"),
-        arg: Expr::Lit(Lit::Num(Number { value: 0.0, span })).into(),
+        arg: Expr::Lit(Lit::Num(Number {
+            value: 0.0,
+            span,
+            raw: None,
+        }))
+        .into(),
     })
     .into()
 }
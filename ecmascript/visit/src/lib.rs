@@ -431,6 +431,7 @@ define!({
         PrivateProp(PrivateProp),
         TsIndexSignature(TsIndexSignature),
         Empty(EmptyStmt),
+        StaticBlock(StaticBlock),
     }
 
     pub struct ClassProp {
@@ -494,6 +495,10 @@ define!({
         pub accessibility: Option<Accessibility>,
         pub is_optional: bool,
     }
+    pub struct StaticBlock {
+        pub span: Span,
+        pub body: BlockStmt,
+    }
     pub struct Decorator {
         pub span: Span,
         pub expr: Box<Expr>,
@@ -886,6 +891,7 @@ define!({
     pub struct Number {
         pub span: Span,
         pub value: f64,
+        pub raw: Option<JsWord>,
     }
     pub enum Program {
         Module(Module),
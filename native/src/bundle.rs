@@ -2,25 +2,28 @@ use crate::{
     get_compiler,
     util::{CtxtExt, MapErr},
 };
-use anyhow::{bail, Error};
+use anyhow::Error;
 use fxhash::FxHashMap;
 use napi::{CallContext, Env, JsObject, Status, Task};
 use serde::Deserialize;
-use spack::resolvers::NodeResolver;
+use spack::{loaders::swc::SwcLoader, resolvers::NodeResolver};
 use std::{
     panic::{catch_unwind, AssertUnwindSafe},
     sync::Arc,
 };
-use swc::{config::SourceMapsConfig, Compiler, TransformOutput};
+use swc::{
+    config::{BoolOrObject, SourceMapsConfig},
+    Compiler, TransformOutput,
+};
 use swc_atoms::js_word;
-use swc_bundler::{BundleKind, Bundler, Load, ModuleRecord, Resolve};
+use swc_bundler::{BundleKind, Bundler, ModuleRecord, Resolve};
 use swc_common::Span;
 use swc_ecma_ast::{
     Bool, Expr, ExprOrSuper, Ident, KeyValueProp, Lit, MemberExpr, MetaPropExpr, PropName, Str,
 };
 
 struct ConfigItem {
-    loader: Box<dyn Load>,
+    loader: SwcLoader,
     resolver: Box<dyn Resolve>,
     static_items: StaticConfigItem,
 }
@@ -53,6 +56,18 @@ impl Task for BundleTask {
             .unwrap_or_default();
 
         let res = catch_unwind(AssertUnwindSafe(|| {
+            let split_chunks = self
+                .config
+                .static_items
+                .config
+                .optimization
+                .as_ref()
+                .and_then(|v| v.split_chunks.as_ref())
+                .map(|v| v.to_bundler_config())
+                .transpose()
+                .convert_err()?
+                .unwrap_or_default();
+
             let bundler = Bundler::new(
                 self.swc.globals(),
                 self.swc.cm.clone(),
@@ -98,40 +113,66 @@ impl Task for BundleTask {
                     .into_iter()
                     .map(From::from)
                     .collect(),
+                    split_dynamic_imports: true,
+                    split_chunks,
                     ..Default::default()
                 },
                 Box::new(Hook),
             );
 
-            let result = bundler
+            let mut result = bundler
                 .bundle(self.config.static_items.config.entry.clone().into())
                 .convert_err()?;
 
+            let minify = self
+                .config
+                .static_items
+                .config
+                .options
+                .as_ref()
+                .and_then(|v| v.config.minify.clone())
+                .and_then(BoolOrObject::into_obj);
+            if let Some(props) = minify.as_ref().and_then(|m| m.properties()) {
+                let mut modules = result
+                    .iter_mut()
+                    .map(|bundle| &mut bundle.module)
+                    .collect::<Vec<_>>();
+                props.mangle(&mut modules).convert_err()?;
+            }
+
+            let source_maps = self
+                .config
+                .static_items
+                .config
+                .options
+                .as_ref()
+                .and_then(|v| v.source_maps.clone())
+                .unwrap_or(SourceMapsConfig::Bool(true));
+            let orig_maps = self.config.loader.input_source_maps();
+
             let result = result
                 .into_iter()
                 .map(|bundle| match bundle.kind {
-                    BundleKind::Named { name } | BundleKind::Lib { name } => {
-                        Ok((name, bundle.module))
-                    }
-                    BundleKind::Dynamic => bail!("unimplemented: dynamic code splitting"),
+                    BundleKind::Named { name }
+                    | BundleKind::Lib { name }
+                    | BundleKind::Dynamic { name } => Ok::<_, Error>((name, bundle.module)),
                 })
                 .map(|res| {
                     res.and_then(|(k, m)| {
-                        // TODO: Source map
                         let minify = self
                             .config
                             .static_items
                             .config
                             .options
                             .as_ref()
-                            .and_then(|v| v.config.minify)
-                            .unwrap_or(false);
+                            .and_then(|v| v.config.minify.as_ref())
+                            .map_or(false, BoolOrObject::is_enabled);
 
-                        let output = self.swc.print(
+                        let output = self.swc.print_with_orig_maps(
                             &m,
                             codegen_target,
-                            SourceMapsConfig::Bool(true),
-                            None,
+                            source_maps.clone(),
+                            &orig_maps,
                             minify,
                         )?;
 
@@ -173,7 +214,7 @@ pub(crate) fn bundle(cx: CallContext) -> napi::Result<JsObject> {
 
     let static_items: StaticConfigItem = cx.get_deserialized(0)?;
 
-    let loader = Box::new(spack::loaders::swc::SwcLoader::new(
+    let loader = SwcLoader::new(
         c.clone(),
         static_items
             .config
@@ -183,7 +224,7 @@ pub(crate) fn bundle(cx: CallContext) -> napi::Result<JsObject> {
             .unwrap_or_else(|| {
                 serde_json::from_value(serde_json::Value::Object(Default::default())).unwrap()
             }),
-    ));
+    );
 
     cx.env
         .spawn(BundleTask {
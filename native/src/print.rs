@@ -5,7 +5,7 @@ use crate::{
 use napi::{CallContext, Env, Error, JsObject, JsString, Status, Task};
 use std::sync::Arc;
 use swc::{
-    config::{Options, SourceMapsConfig},
+    config::{BoolOrObject, Options, SourceMapsConfig},
     Compiler, TransformOutput,
 };
 use swc_ecma_ast::Program;
@@ -33,7 +33,11 @@ impl Task for PrintTask {
                     .clone()
                     .unwrap_or(SourceMapsConfig::Bool(false)),
                 None,
-                self.options.config.clone().minify.unwrap_or(false),
+                self.options
+                    .config
+                    .minify
+                    .as_ref()
+                    .map_or(false, BoolOrObject::is_enabled),
             )
             .convert_err()
     }
@@ -87,7 +91,11 @@ pub fn print_sync(cx: CallContext) -> napi::Result<JsObject> {
                 .clone()
                 .unwrap_or(SourceMapsConfig::Bool(false)),
             None,
-            options.config.minify.unwrap_or(false),
+            options
+                .config
+                .minify
+                .as_ref()
+                .map_or(false, BoolOrObject::is_enabled),
         )
     }
     .convert_err()?;
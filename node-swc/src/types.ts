@@ -199,7 +199,10 @@ export interface Config {
   env?: EnvConfig;
   jsc?: JscConfig;
   module?: ModuleConfig;
-  minify?: boolean;
+  /**
+   * Options of terser are accepted for `compress` and `mangle`.
+   */
+  minify?: boolean | MinifyOptions;
 
   /**
    * - true to generate a sourcemap for the code and include it in the result object.
@@ -220,6 +223,77 @@ export interface Config {
 /**
  * Configuration ported from babel-preset-env
  */
+export interface MinifyOptions {
+  compress?: boolean | MinifyCompressOptions;
+  mangle?: boolean | MinifyMangleOptions;
+  format?: MinifyFormatOptions;
+}
+
+export interface MinifyFormatOptions {
+  /**
+   * Comments to retain. `"some"` retains license comments, which start with
+   * `!` or contain `@license`, `@preserve` or `@cc_on`.
+   *
+   * Other strings are treated as a regex.
+   *
+   * Defaults to `"some"`.
+   */
+  comments?: boolean | "none" | "some" | "all" | string;
+
+  /**
+   * Escape characters outside of ASCII in strings, template literals, regular
+   * expressions and identifiers.
+   *
+   * Defaults to `false`.
+   */
+  ascii_only?: boolean;
+
+  /**
+   * Break lines longer than this after `;`, `,` or `{`.
+   */
+  max_line_len?: number;
+}
+
+export interface MinifyCompressOptions {
+  booleans?: boolean;
+  conditionals?: boolean;
+  drop_console?: boolean;
+  drop_return_undefined?: boolean;
+  /**
+   * Accepted for compatibility with terser. Has no effect.
+   */
+  ecma?: number;
+  hoist_vars?: boolean;
+  join_vars?: boolean;
+  merge_strings?: boolean;
+  passes?: number;
+  pure_funcs?: string[];
+  sequences?: boolean;
+}
+
+export interface MinifyMangleOptions {
+  keep_classnames?: boolean;
+  keep_fnames?: boolean;
+  /**
+   * Properties are mangled only while bundling.
+   */
+  properties?: MinifyPropertiesOptions;
+  reserved?: string[];
+  toplevel?: boolean;
+}
+
+export interface MinifyPropertiesOptions {
+  /**
+   * Note: The type is string because it follows rust's regex syntax.
+   */
+  regex: string;
+  reserved?: string[];
+  /**
+   * Path to a json file which stores mangled names across builds.
+   */
+  name_cache?: string;
+}
+
 export interface EnvConfig {
   mode?: "usage" | "entry";
   debug?: boolean;
@@ -282,9 +356,11 @@ export type JscTarget =
   | "es2017"
   | "es2018"
   | "es2019"
-  | "es2020";
+  | "es2020"
+  | "es2021"
+  | "es2022";
 
-export type ParserConfig = TsParserConfig | EsParserConfig;
+export type ParserConfig = TsParserConfig | EsParserConfig | FlowParserConfig;
 export interface TsParserConfig {
   syntax: "typescript";
   /**
@@ -301,6 +377,22 @@ export interface TsParserConfig {
   dynamicImport?: boolean;
 }
 
+export interface FlowParserConfig {
+  syntax: "flow";
+  /**
+   * Defaults to `false`.
+   */
+  jsx?: boolean;
+  /**
+   * Defaults to `false`.
+   */
+  decorators?: boolean;
+  /**
+   * Defaults to `false`
+   */
+  dynamicImport?: boolean;
+}
+
 export interface EsParserConfig {
   syntax: "ecmascript";
   /**
@@ -559,6 +651,10 @@ export interface Output {
    * Sourcemap (**not** base64 encoded)
    */
   map?: string;
+  /**
+   * License comments of the input, which can be written to `LICENSES.txt`.
+   */
+  licenses?: string[];
 }
 
 export interface MatchPattern { }
@@ -1176,6 +1272,11 @@ export interface NumericLiteral extends Node, HasSpan {
   type: "NumericLiteral";
 
   value: number;
+  /**
+   * The source text of the literal, e.g. `0xFF`. `null` for synthesized
+   * numbers.
+   */
+  raw?: string | null;
 }
 
 export type ModuleDeclaration =
@@ -1,6 +1,6 @@
 pub use self::{
     module::ModuleConfig,
-    optimization::OptimizationConfig,
+    optimization::{CacheGroupOptions, OptimizationConfig, SplitChunksOptions},
     output::OutputConfig,
     resolve::{AliasConfig, ResolveConfig},
 };
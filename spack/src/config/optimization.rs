@@ -1,5 +1,9 @@
 use crate::config::JsCallback;
+use anyhow::{Context, Error};
+use indexmap::IndexMap;
+use regex::Regex;
 use serde::Deserialize;
+use swc_bundler::{ChunkGroup, SplitChunksConfig};
 
 #[derive(Debug, Deserialize)]
 #[serde(rename = "Optimization", rename_all = "camelCase")]
@@ -10,6 +14,60 @@ pub struct OptimizationConfig {
     #[serde(skip)]
     pub minimizer: Option<JsCallback<String, String>>,
 
+    /// If not specified, modules used by two or more entries are still emitted
+    /// as separate bundles.
     #[serde(default)]
-    pub split_chunks: Option<()>,
+    pub split_chunks: Option<SplitChunksOptions>,
+}
+
+/// https://webpack.js.org/plugins/split-chunks-plugin/
+#[derive(Debug, Deserialize)]
+#[serde(rename = "Optimization.SplitChunks", rename_all = "camelCase")]
+pub struct SplitChunksOptions {
+    #[serde(default = "default_min_chunks")]
+    pub min_chunks: usize,
+
+    #[serde(default)]
+    pub min_size: usize,
+
+    /// Key is the name of emitted bundles. The first matching group is used,
+    /// in the order of the config.
+    #[serde(default)]
+    pub cache_groups: IndexMap<String, CacheGroupOptions>,
+}
+
+fn default_min_chunks() -> usize {
+    2
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(
+    rename = "Optimization.SplitChunks.CacheGroup",
+    rename_all = "camelCase"
+)]
+pub struct CacheGroupOptions {
+    /// Regex for file names of modules.
+    pub test: String,
+}
+
+impl SplitChunksOptions {
+    pub fn to_bundler_config(&self) -> Result<SplitChunksConfig, Error> {
+        let groups = self
+            .cache_groups
+            .iter()
+            .map(|(name, group)| -> Result<_, Error> {
+                Ok(ChunkGroup {
+                    name: name.clone(),
+                    test: Regex::new(&group.test)
+                        .with_context(|| format!("invalid regex: {}", group.test))?,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(SplitChunksConfig {
+            min_chunks: self.min_chunks,
+            min_size: self.min_size,
+            groups,
+        })
+    }
 }
@@ -2,10 +2,13 @@ use crate::loaders::json::load_json_as_module;
 use anyhow::{bail, Context, Error};
 use helpers::Helpers;
 use std::{collections::HashMap, env, sync::Arc};
-use swc::config::{InputSourceMap, JscConfig, TransformConfig};
+use swc::{
+    config::{BoolOrObject, InputSourceMap, JscConfig, TransformConfig},
+    sourcemap,
+};
 use swc_atoms::JsWord;
 use swc_bundler::{Load, ModuleData};
-use swc_common::{FileName, DUMMY_SP};
+use swc_common::{sync::Lock, FileName, DUMMY_SP};
 use swc_ecma_ast::Module;
 use swc_ecma_ast::{Expr, Lit, Program, Str};
 use swc_ecma_parser::JscTarget;
@@ -22,11 +25,26 @@ use swc_ecma_visit::FoldWith;
 pub struct SwcLoader {
     compiler: Arc<swc::Compiler>,
     options: swc::config::Options,
+    input_source_maps: Lock<HashMap<FileName, sourcemap::SourceMap>>,
 }
 
 impl SwcLoader {
     pub fn new(compiler: Arc<swc::Compiler>, options: swc::config::Options) -> Self {
-        SwcLoader { compiler, options }
+        SwcLoader {
+            compiler,
+            options,
+            input_source_maps: Default::default(),
+        }
+    }
+
+    /// Returns input source maps of loaded files, which can be passed to
+    /// [swc::Compiler::print_with_orig_maps].
+    ///
+    /// Input source maps are loaded only for files with a `sourceMappingURL`
+    /// comment, according to `input_source_map` of the options. Loading a file
+    /// fails if its input source map cannot be loaded.
+    pub fn input_source_maps(&self) -> HashMap<FileName, sourcemap::SourceMap> {
+        self.input_source_maps.lock().clone()
     }
 }
 
@@ -83,6 +101,36 @@ impl Load for SwcLoader {
 
         log::trace!("JsLoader.load: loaded");
 
+        if fm.src.contains("sourceMappingURL=") {
+            match &self.options.input_source_map {
+                InputSourceMap::Bool(false) => {
+                    log::debug!(
+                        "JsLoader.load: ignoring the input source map of `{}` as `inputSourceMap` \
+                         is disabled",
+                        fm.name
+                    );
+                }
+                input_source_map => {
+                    let map = self
+                        .compiler
+                        .get_orig_src_map(&fm, input_source_map)
+                        .with_context(|| {
+                            format!("failed to load input source map of `{}`", fm.name)
+                        })?;
+                    match map {
+                        Some(map) => {
+                            self.input_source_maps.lock().insert(fm.name.clone(), map);
+                        }
+                        None => log::warn!(
+                            "`{}` has a `sourceMappingURL` comment, but its input source map \
+                             could not be loaded",
+                            fm.name
+                        ),
+                    }
+                }
+            }
+        }
+
         let program = if fm.name.to_string().contains("node_modules") {
             let program = self.compiler.parse_js(
                 fm.clone(),
@@ -128,7 +176,7 @@ impl Load for SwcLoader {
                                 ..c.jsc
                             },
                             module: None,
-                            minify: Some(false),
+                            minify: Some(BoolOrObject::Bool(false)),
                             ..c.clone()
                         }
                     },
@@ -144,6 +144,9 @@ fn reference_tests(tests: &mut Vec<TestDescAndFn>, errors: bool) -> Result<(), i
                             require: true,
                             disable_inliner: true,
                             module: Default::default(),
+                            split_dynamic_imports: false,
+                            split_chunks: Default::default(),
+                            globals: Default::default(),
                             external_modules: vec![
                                 "assert",
                                 "buffer",
@@ -206,10 +209,9 @@ fn reference_tests(tests: &mut Vec<TestDescAndFn>, errors: bool) -> Result<(), i
                             .code;
 
                         let name = match bundled.kind {
-                            BundleKind::Named { name } | BundleKind::Lib { name } => {
-                                PathBuf::from(name)
-                            }
-                            BundleKind::Dynamic => format!("dynamic.{}.js", bundled.id).into(),
+                            BundleKind::Named { name }
+                            | BundleKind::Lib { name }
+                            | BundleKind::Dynamic { name } => PathBuf::from(name),
                         };
 
                         let output_path = entry
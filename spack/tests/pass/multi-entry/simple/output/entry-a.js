@@ -1,7 +1,4 @@
-console.log('loading d.js');
-function foo() {
-    console.log('d.js');
-}
+import { foo } from "./d-2w4j5tksz1e1k.js";
 console.log('loading c.js');
 function c() {
     foo();
@@ -1 +1,2 @@
+import { foo } from "./d-2w4j5tksz1e1k.js";
 foo();
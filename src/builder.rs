@@ -4,10 +4,14 @@ use either::Either;
 use std::{collections::HashMap, sync::Arc};
 use swc_atoms::JsWord;
 use swc_common::{chain, comments::Comments, errors::Handler, Mark, SourceMap};
+use swc_ecma_minifier::{
+    compressor, mangle_names,
+    option::{CompressOptions, MangleOptions},
+};
 use swc_ecma_parser::Syntax;
 use swc_ecma_transforms::hygiene::hygiene_with_config;
 use swc_ecma_transforms::{
-    compat, fixer, helpers, hygiene, modules, optimization::const_modules, pass::Optional,
+    compat, fixer, flow, helpers, hygiene, modules, optimization::const_modules, pass::Optional,
     proposals::import_assertions, typescript,
 };
 
@@ -21,6 +25,8 @@ pub struct PassBuilder<'a, 'b, P: swc_ecma_visit::Fold> {
     target: JscTarget,
     loose: bool,
     hygiene: Option<hygiene::Config>,
+    compress: Option<CompressOptions>,
+    mangle: Option<MangleOptions>,
     fixer: bool,
     inject_helpers: bool,
 }
@@ -41,6 +47,8 @@ impl<'a, 'b, P: swc_ecma_visit::Fold> PassBuilder<'a, 'b, P> {
             global_mark,
             loose,
             hygiene: Some(Default::default()),
+            compress: None,
+            mangle: None,
             env: None,
             fixer: true,
             inject_helpers: true,
@@ -59,6 +67,8 @@ impl<'a, 'b, P: swc_ecma_visit::Fold> PassBuilder<'a, 'b, P> {
             target: self.target,
             loose: self.loose,
             hygiene: self.hygiene,
+            compress: self.compress,
+            mangle: self.mangle,
             env: self.env,
             global_mark: self.global_mark,
             fixer: self.fixer,
@@ -85,6 +95,21 @@ impl<'a, 'b, P: swc_ecma_visit::Fold> PassBuilder<'a, 'b, P> {
         self
     }
 
+    /// Note: compressor is disabled by default.
+    pub fn compress(mut self, options: Option<CompressOptions>) -> Self {
+        self.compress = options;
+        self
+    }
+
+    /// Note: mangler is disabled by default.
+    ///
+    /// The mangler depends on the output of the `hygiene` pass, so it's
+    /// ignored if hygiene is disabled.
+    pub fn mangle(mut self, options: Option<MangleOptions>) -> Self {
+        self.mangle = options;
+        self
+    }
+
     pub fn const_modules(
         self,
         globals: HashMap<JsWord, HashMap<JsWord, String>>,
@@ -121,7 +146,9 @@ impl<'a, 'b, P: swc_ecma_visit::Fold> PassBuilder<'a, 'b, P> {
     ///  - compatibility helper
     ///  - module handler
     ///  - helper injector
+    ///  - compressor if enabled
     ///  - identifier hygiene handler if enabled
+    ///  - identifier mangler if enabled
     ///  - fixer if enabled
     pub fn finalize<'cmt>(
         self,
@@ -139,18 +166,28 @@ impl<'a, 'b, P: swc_ecma_visit::Fold> PassBuilder<'a, 'b, P> {
             Some(ModuleConfig::Es6) | None => false,
         };
 
+        let compress = self.compress.clone().map(|options| CompressOptions {
+            // Generated variables are renamed by the hygiene pass.
+            merge_strings: options.merge_strings && self.hygiene.is_some(),
+            ..options
+        });
+
         // compat
         let compat_pass = if let Some(env) = self.env {
             Either::Left(chain!(
                 import_assertions(),
-                Optional::new(typescript::strip(), syntax.typescript()),
+                Optional::new(typescript::strip(), syntax.typescript() && !syntax.flow()),
+                Optional::new(flow::strip(), syntax.flow()),
                 swc_ecma_preset_env::preset_env(self.global_mark, env)
             ))
         } else {
             Either::Right(chain!(
                 import_assertions(),
+                Optional::new(compat::es2022(), self.target < JscTarget::Es2022),
+                Optional::new(compat::es2021(), self.target < JscTarget::Es2021),
                 Optional::new(compat::es2020::es2020(), self.target < JscTarget::Es2020),
-                Optional::new(typescript::strip(), syntax.typescript()),
+                Optional::new(typescript::strip(), syntax.typescript() && !syntax.flow()),
+                Optional::new(flow::strip(), syntax.flow()),
                 Optional::new(compat::es2018(), self.target <= JscTarget::Es2018),
                 Optional::new(compat::es2017(), self.target <= JscTarget::Es2017),
                 Optional::new(compat::es2016(), self.target <= JscTarget::Es2016),
@@ -188,10 +225,18 @@ impl<'a, 'b, P: swc_ecma_visit::Fold> PassBuilder<'a, 'b, P> {
             ),
             Optional::new(helpers::inject_helpers(), self.inject_helpers),
             ModuleConfig::build(self.cm.clone(), self.global_mark, module),
+            Optional::new(
+                compressor(compress.clone().unwrap_or_default()),
+                compress.is_some()
+            ),
             Optional::new(
                 hygiene_with_config(self.hygiene.clone().unwrap_or_default()),
                 self.hygiene.is_some()
             ),
+            Optional::new(
+                mangle_names(self.mangle.clone().unwrap_or_default()),
+                self.mangle.is_some() && self.hygiene.is_some()
+            ),
             Optional::new(fixer(comments), self.fixer),
         )
     }
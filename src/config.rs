@@ -7,7 +7,7 @@ use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::{
     collections::{HashMap, HashSet},
-    env,
+    env, fs,
     path::{Path, PathBuf},
     sync::Arc,
     usize,
@@ -15,13 +15,18 @@ use std::{
 use swc_atoms::JsWord;
 pub use swc_common::chain;
 use swc_common::{comments::Comments, errors::Handler, FileName, Mark, SourceMap};
-use swc_ecma_ast::{Expr, ExprStmt, ModuleItem, Stmt};
+use swc_ecma_ast::{Expr, ExprStmt, Module, ModuleItem, Stmt};
+use swc_ecma_codegen::CommentPolicy;
 use swc_ecma_ext_transforms::jest;
+use swc_ecma_minifier::{
+    mangle_props,
+    option::{CompressOptions, MangleOptions, NameCache, PropMangleOptions},
+};
 pub use swc_ecma_parser::JscTarget;
 use swc_ecma_parser::{lexer::Lexer, Parser, StringInput, Syntax, TsConfig};
 use swc_ecma_transforms::hygiene;
 use swc_ecma_transforms::{
-    modules,
+    flow, modules,
     optimization::const_modules,
     optimization::{inline_globals, json_parse, simplifier},
     pass::{noop, Optional},
@@ -178,7 +183,7 @@ impl Options {
         is_module: bool,
         config: Option<Config>,
         comments: Option<&'a dyn Comments>,
-    ) -> BuiltConfig<impl 'a + swc_ecma_visit::Fold> {
+    ) -> Result<BuiltConfig<impl 'a + swc_ecma_visit::Fold>, Error> {
         let mut config = config.unwrap_or_else(Default::default);
         config.merge(&self.config);
 
@@ -195,7 +200,7 @@ impl Options {
         let syntax = syntax.unwrap_or_default();
         let mut transform = transform.unwrap_or_default();
 
-        if syntax.typescript() {
+        if syntax.typescript() && !syntax.flow() {
             transform.legacy_decorator = true;
         }
         let optimizer = transform.optimizer;
@@ -246,7 +251,8 @@ impl Options {
                 }),
                 syntax.decorators()
             ),
-            Optional::new(typescript::strip(), syntax.typescript()),
+            Optional::new(typescript::strip(), syntax.typescript() && !syntax.flow()),
+            Optional::new(flow::strip(), syntax.flow()),
             resolver_with_mark(root_mark),
             const_modules,
             optimization,
@@ -255,6 +261,16 @@ impl Options {
             json_parse_pass
         );
 
+        let minify = config.minify.and_then(BoolOrObject::into_obj);
+        let comment_policy = match &minify {
+            Some(minify) => minify.comment_policy()?,
+            None => CommentPolicy::All,
+        };
+        let format = minify
+            .as_ref()
+            .and_then(|m| m.format.clone())
+            .unwrap_or_default();
+
         let pass = PassBuilder::new(&cm, &handler, loose, root_mark, pass)
             .target(target)
             .skip_helper_injection(self.skip_helper_injection)
@@ -263,14 +279,23 @@ impl Options {
             } else {
                 Some(hygiene::Config { keep_class_names })
             })
+            .compress(minify.as_ref().and_then(|m| m.compress_options()))
+            .mangle(
+                minify
+                    .as_ref()
+                    .and_then(|m| m.mangle_options(keep_class_names)),
+            )
             .fixer(!self.disable_fixer)
             .preset_env(config.env)
             .finalize(syntax, config.module, comments);
 
         let pass = chain!(pass, Optional::new(jest::jest(), transform.hidden.jest));
 
-        BuiltConfig {
-            minify: config.minify.unwrap_or(false),
+        Ok(BuiltConfig {
+            minify: minify.is_some(),
+            comments: comment_policy,
+            ascii_only: format.ascii_only,
+            max_line_len: format.max_line_len,
             pass,
             external_helpers,
             syntax,
@@ -282,7 +307,7 @@ impl Options {
                 .or(config.source_maps)
                 .unwrap_or(SourceMapsConfig::Bool(false)),
             input_source_map: self.input_source_map.clone(),
-        }
+        })
     }
 }
 
@@ -454,8 +479,10 @@ pub struct Config {
     #[serde(default)]
     pub module: Option<ModuleConfig>,
 
+    /// Possible values are: `true`, `false` and an object with `compress` and
+    /// `mangle` options.
     #[serde(default)]
-    pub minify: Option<bool>,
+    pub minify: Option<BoolOrObject<MinifyOptions>>,
 
     /// Possible values are: `'inline'`, `true`, `false`.
     #[serde(default)]
@@ -481,6 +508,270 @@ impl Config {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum BoolOrObject<T> {
+    Bool(bool),
+    Obj(T),
+}
+
+impl<T> BoolOrObject<T> {
+    /// Returns `false` only for `false`.
+    pub fn is_enabled(&self) -> bool {
+        match self {
+            BoolOrObject::Bool(v) => *v,
+            BoolOrObject::Obj(..) => true,
+        }
+    }
+}
+
+impl<T: Default> BoolOrObject<T> {
+    /// Returns [None] if disabled.
+    pub fn into_obj(self) -> Option<T> {
+        match self {
+            BoolOrObject::Bool(false) => None,
+            BoolOrObject::Bool(true) => Some(Default::default()),
+            BoolOrObject::Obj(v) => Some(v),
+        }
+    }
+}
+
+/// Options for minification. Names of options are same as the ones of terser.
+///
+/// Both of the compressor and the mangler are enabled if not specified.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MinifyOptions {
+    #[serde(default)]
+    pub compress: Option<BoolOrObject<MinifyCompressOptions>>,
+
+    #[serde(default)]
+    pub mangle: Option<BoolOrObject<MinifyMangleOptions>>,
+
+    #[serde(default)]
+    pub format: Option<MinifyFormatOptions>,
+}
+
+impl MinifyOptions {
+    fn compress_options(&self) -> Option<CompressOptions> {
+        let c = self
+            .compress
+            .clone()
+            .unwrap_or(BoolOrObject::Bool(true))
+            .into_obj()?;
+        let default = CompressOptions::default();
+
+        Some(CompressOptions {
+            booleans: c.booleans.unwrap_or(default.booleans),
+            conditionals: c.conditionals.unwrap_or(default.conditionals),
+            drop_console: c.drop_console,
+            drop_return_undefined: c
+                .drop_return_undefined
+                .unwrap_or(default.drop_return_undefined),
+            hoist_vars: c.hoist_vars.unwrap_or(default.hoist_vars),
+            join_vars: c.join_vars.unwrap_or(default.join_vars),
+            merge_strings: c.merge_strings.unwrap_or(default.merge_strings),
+            passes: c.passes.unwrap_or(default.passes),
+            pure_funcs: c.pure_funcs,
+            sequences: c.sequences.unwrap_or(default.sequences),
+        })
+    }
+
+    fn comment_policy(&self) -> Result<CommentPolicy, Error> {
+        match self.format.as_ref().and_then(|f| f.comments.as_ref()) {
+            Some(c) => c.to_policy(),
+            None => Ok(CommentPolicy::Some),
+        }
+    }
+
+    /// Returns [None] if the mangler or property mangling is disabled.
+    pub fn properties(&self) -> Option<&MinifyPropertiesOptions> {
+        match &self.mangle {
+            Some(BoolOrObject::Obj(m)) => m.properties.as_ref(),
+            _ => None,
+        }
+    }
+
+    fn mangle_options(&self, keep_class_names: bool) -> Option<MangleOptions> {
+        let m = self
+            .mangle
+            .clone()
+            .unwrap_or(BoolOrObject::Bool(true))
+            .into_obj()?;
+
+        Some(MangleOptions {
+            top_level: m.toplevel,
+            keep_class_names: keep_class_names || m.keep_classnames,
+            keep_fn_names: m.keep_fnames,
+            reserved: m.reserved,
+        })
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MinifyFormatOptions {
+    /// Comments to retain. Defaults to `"some"`, which retains license
+    /// comments.
+    #[serde(default)]
+    pub comments: Option<CommentsConfig>,
+
+    /// Escapes characters outside of ASCII.
+    #[serde(default)]
+    pub ascii_only: bool,
+
+    /// Breaks lines longer than this.
+    #[serde(default)]
+    pub max_line_len: Option<usize>,
+}
+
+/// Possible values are: `true`, `false`, `"none"`, `"some"`, `"all"` and a
+/// regex like `"^!"` or `"/^!/"`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CommentsConfig {
+    Bool(bool),
+    Str(String),
+}
+
+impl CommentsConfig {
+    pub fn to_policy(&self) -> Result<CommentPolicy, Error> {
+        Ok(match self {
+            CommentsConfig::Bool(false) => CommentPolicy::None,
+            CommentsConfig::Bool(true) => CommentPolicy::All,
+            CommentsConfig::Str(s) => match &**s {
+                "none" => CommentPolicy::None,
+                "some" => CommentPolicy::Some,
+                "all" => CommentPolicy::All,
+                _ => {
+                    let re = if s.len() >= 2 && s.starts_with('/') && s.ends_with('/') {
+                        &s[1..s.len() - 1]
+                    } else {
+                        &**s
+                    };
+
+                    CommentPolicy::Regex(
+                        Regex::new(re).with_context(|| format!("invalid regex: {}", s))?,
+                    )
+                }
+            },
+        })
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MinifyCompressOptions {
+    #[serde(default)]
+    pub booleans: Option<bool>,
+
+    #[serde(default)]
+    pub conditionals: Option<bool>,
+
+    #[serde(default)]
+    pub drop_console: bool,
+
+    #[serde(default)]
+    pub drop_return_undefined: Option<bool>,
+
+    /// Accepted for compatibility with terser.
+    ///
+    /// As the compressor does not emit syntax which does not exist in the
+    /// input, this option has no effect.
+    #[serde(default)]
+    pub ecma: Option<usize>,
+
+    #[serde(default)]
+    pub hoist_vars: Option<bool>,
+
+    #[serde(default)]
+    pub join_vars: Option<bool>,
+
+    /// Ignored if the `hygiene` pass is disabled, as generated variables are
+    /// renamed by it.
+    #[serde(default)]
+    pub merge_strings: Option<bool>,
+
+    #[serde(default)]
+    pub passes: Option<usize>,
+
+    #[serde(default)]
+    pub pure_funcs: Vec<String>,
+
+    #[serde(default)]
+    pub sequences: Option<bool>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MinifyMangleOptions {
+    /// `jsc.keepClassNames` is respected even if this is `false`.
+    #[serde(default)]
+    pub keep_classnames: bool,
+
+    #[serde(default)]
+    pub keep_fnames: bool,
+
+    /// Properties are mangled only while bundling, as they should be renamed
+    /// consistently across all modules.
+    #[serde(default)]
+    pub properties: Option<MinifyPropertiesOptions>,
+
+    #[serde(default)]
+    pub reserved: Vec<JsWord>,
+
+    #[serde(default)]
+    pub toplevel: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MinifyPropertiesOptions {
+    /// Only properties matching this regex are mangled, e.g. `^_`.
+    pub regex: String,
+
+    #[serde(default)]
+    pub reserved: Vec<JsWord>,
+
+    /// Path to a json file which stores mangled names.
+    ///
+    /// If the file exists, names stored in it are reused. The file is updated
+    /// after mangling, so names are stable across builds.
+    #[serde(default)]
+    pub name_cache: Option<PathBuf>,
+}
+
+impl MinifyPropertiesOptions {
+    /// Mangles properties of all `modules` consistently.
+    pub fn mangle(&self, modules: &mut [&mut Module]) -> Result<(), Error> {
+        let options = PropMangleOptions {
+            regex: Regex::new(&self.regex)
+                .with_context(|| format!("invalid regex: {}", self.regex))?,
+            reserved: self.reserved.clone(),
+        };
+
+        let mut cache = match &self.name_cache {
+            Some(path) if path.exists() => {
+                let s = fs::read_to_string(path)
+                    .with_context(|| format!("failed to read name cache: {}", path.display()))?;
+                serde_json::from_str(&s)
+                    .with_context(|| format!("failed to parse name cache: {}", path.display()))?
+            }
+            _ => NameCache::default(),
+        };
+
+        mangle_props(modules, &options, &mut cache);
+
+        if let Some(path) = &self.name_cache {
+            let s = serde_json::to_string_pretty(&cache)?;
+            fs::write(path, s)
+                .with_context(|| format!("failed to write name cache: {}", path.display()))?;
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum FileMatcher {
@@ -558,6 +849,10 @@ pub struct BuiltConfig<P: swc_ecma_visit::Fold> {
     pub syntax: Syntax,
     pub target: JscTarget,
     pub minify: bool,
+    /// Comments to retain.
+    pub comments: CommentPolicy,
+    pub ascii_only: bool,
+    pub max_line_len: Option<usize>,
     pub external_helpers: bool,
     pub source_maps: SourceMapsConfig,
     pub input_source_map: InputSourceMap,
@@ -802,6 +1097,15 @@ impl Merge for Config {
     }
 }
 
+impl Merge for BoolOrObject<MinifyOptions> {
+    fn merge(&mut self, from: &Self) {
+        match (&*self, from) {
+            (_, BoolOrObject::Bool(false)) | (BoolOrObject::Obj(..), BoolOrObject::Bool(true)) => {}
+            _ => *self = from.clone(),
+        }
+    }
+}
+
 impl Merge for SourceMapsConfig {
     fn merge(&mut self, from: &Self) {
         match self {
@@ -1,4 +1,4 @@
-use super::Rc;
+use super::{BoolOrObject, MinifyCompressOptions, Rc};
 use crate::Options;
 use serde_json;
 
@@ -19,3 +19,64 @@ fn issue_1532() {
     let err = res.expect_err("should fail");
     assert!(err.to_string().contains("unknown variant `esnext`"));
 }
+
+#[test]
+fn minify_options() {
+    let rc: Rc = serde_json::from_str(include_str!("minify.json")).expect("failed to parse");
+    let config = rc.into_config(None).unwrap().unwrap();
+
+    let minify = match config.minify {
+        Some(BoolOrObject::Obj(v)) => v,
+        _ => panic!("minify should be an object"),
+    };
+
+    let compress = minify.compress_options().unwrap();
+    assert!(compress.drop_console);
+    assert_eq!(compress.passes, 2);
+    assert_eq!(compress.pure_funcs, vec![String::from("Math.floor")]);
+    assert!(!compress.merge_strings);
+    assert!(!compress.drop_return_undefined);
+
+    let mangle = minify.mangle_options(false).unwrap();
+    assert!(mangle.top_level);
+    assert!(mangle.keep_fn_names);
+    assert!(!mangle.keep_class_names);
+    assert_eq!(mangle.reserved, vec!["$".into()]);
+
+    let props = minify.properties().unwrap();
+    assert_eq!(props.regex, "^_");
+    assert_eq!(props.reserved, vec!["_keep".into()]);
+    assert_eq!(props.name_cache, None);
+}
+
+#[test]
+fn minify_unsupported_compress_options() {
+    for option in &["keep_fnames", "toplevel"] {
+        let json = format!(r#"{{ "{}": true }}"#, option);
+        let err = serde_json::from_str::<MinifyCompressOptions>(&json).expect_err("should fail");
+        assert!(
+            err.to_string()
+                .contains(&format!("unknown field `{}`", option)),
+            "{}",
+            err
+        );
+    }
+}
+
+#[test]
+fn minify_compress_ecma() {
+    let c: MinifyCompressOptions =
+        serde_json::from_str(r#"{ "ecma": 2015, "passes": 3 }"#).expect("failed to parse");
+    assert_eq!(c.ecma, Some(2015));
+    assert_eq!(c.passes, Some(3));
+}
+
+#[test]
+fn minify_disabled_compress() {
+    let rc: Rc = serde_json::from_str(r#"{ "minify": { "compress": false } }"#).unwrap();
+    let config = rc.into_config(None).unwrap().unwrap();
+
+    let minify = config.minify.and_then(BoolOrObject::into_obj).unwrap();
+    assert!(minify.compress_options().is_none());
+    assert!(minify.mangle_options(false).is_some());
+}
@@ -12,19 +12,21 @@ use dashmap::DashMap;
 use serde::Serialize;
 use serde_json::error::Category;
 use std::{
+    collections::HashMap,
     fs::{read_to_string, File},
     path::{Path, PathBuf},
     sync::Arc,
 };
 use swc_common::{
     chain,
-    comments::{Comment, Comments},
+    comments::{Comment, CommentKind, Comments},
     errors::Handler,
     input::StringInput,
-    BytePos, FileName, Globals, SourceFile, SourceMap, Spanned, GLOBALS,
+    BytePos, DefaultSourceMapGenConfig, FileName, Globals, SourceFile, SourceMap,
+    SourceMapGenConfig, Span, Spanned, GLOBALS,
 };
 use swc_ecma_ast::Program;
-use swc_ecma_codegen::{self, Emitter, Node};
+use swc_ecma_codegen::{self, is_license_comment, CommentPolicy, Emitter, Node};
 use swc_ecma_parser::{lexer::Lexer, Parser, Syntax};
 use swc_ecma_transforms::{
     helpers::{self, Helpers},
@@ -51,6 +53,24 @@ pub struct TransformOutput {
     pub code: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub map: Option<String>,
+    /// License comments of the input, regardless of the comments retained in
+    /// `code`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub licenses: Vec<String>,
+}
+
+impl TransformOutput {
+    /// Returns the content of a `LICENSES.txt` file, or [None] if there's no
+    /// license comment.
+    pub fn licenses_txt(&self) -> Option<String> {
+        if self.licenses.is_empty() {
+            return None;
+        }
+
+        let mut s = self.licenses.join("\n\n");
+        s.push('\n');
+        Some(s)
+    }
 }
 
 /// These are **low-level** apis.
@@ -73,7 +93,8 @@ impl Compiler {
         GLOBALS.set(&self.globals, || op())
     }
 
-    fn get_orig_src_map(
+    /// Loads the input source map of `fm`.
+    pub fn get_orig_src_map(
         &self,
         fm: &SourceFile,
         input_src_map: &InputSourceMap,
@@ -228,6 +249,8 @@ impl Compiler {
 
     /// Converts ast node to source string and sourcemap.
     ///
+    /// If `minify` is true, only license comments are retained.
+    ///
     /// TODO: Receive target file path to fix https://github.com/swc-project/swc/issues/1255
     pub fn print<T>(
         &self,
@@ -237,6 +260,91 @@ impl Compiler {
         orig: Option<&sourcemap::SourceMap>,
         minify: bool,
     ) -> Result<TransformOutput, Error>
+    where
+        T: Node,
+    {
+        self.print_with_config(
+            node,
+            target,
+            source_map,
+            orig,
+            swc_ecma_codegen::Config {
+                minify,
+                comments: if minify {
+                    CommentPolicy::Some
+                } else {
+                    CommentPolicy::All
+                },
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Converts ast node to source string and sourcemap using `cfg`.
+    pub fn print_with_config<T>(
+        &self,
+        node: &T,
+        target: JscTarget,
+        source_map: SourceMapsConfig,
+        orig: Option<&sourcemap::SourceMap>,
+        cfg: swc_ecma_codegen::Config,
+    ) -> Result<TransformOutput, Error>
+    where
+        T: Node,
+    {
+        self.print_inner(
+            node,
+            target,
+            source_map,
+            orig,
+            cfg,
+            DefaultSourceMapGenConfig,
+        )
+    }
+
+    /// Converts a module built from multiple files, like a bundle, to source
+    /// string and sourcemap.
+    ///
+    /// Mappings into a file are chained with its input source map in
+    /// `orig_maps`, if any.
+    pub fn print_with_orig_maps<T>(
+        &self,
+        node: &T,
+        target: JscTarget,
+        source_map: SourceMapsConfig,
+        orig_maps: &HashMap<FileName, sourcemap::SourceMap>,
+        minify: bool,
+    ) -> Result<TransformOutput, Error>
+    where
+        T: Node,
+    {
+        self.print_inner(
+            node,
+            target,
+            source_map,
+            None,
+            swc_ecma_codegen::Config {
+                minify,
+                comments: if minify {
+                    CommentPolicy::Some
+                } else {
+                    CommentPolicy::All
+                },
+                ..Default::default()
+            },
+            OrigSourceMaps(orig_maps),
+        )
+    }
+
+    fn print_inner<T>(
+        &self,
+        node: &T,
+        target: JscTarget,
+        source_map: SourceMapsConfig,
+        orig: Option<&sourcemap::SourceMap>,
+        cfg: swc_ecma_codegen::Config,
+        gen_config: impl SourceMapGenConfig,
+    ) -> Result<TransformOutput, Error>
     where
         T: Node,
     {
@@ -246,21 +354,23 @@ impl Compiler {
             let src = {
                 let mut buf = vec![];
                 {
+                    let wr = swc_ecma_codegen::text_writer::JsWriter::with_target(
+                        self.cm.clone(),
+                        "\n",
+                        &mut buf,
+                        if source_map.enabled() {
+                            Some(&mut src_map_buf)
+                        } else {
+                            None
+                        },
+                        target,
+                    )
+                    .with_config(&cfg);
                     let mut emitter = Emitter {
-                        cfg: swc_ecma_codegen::Config { minify },
-                        comments: if minify { None } else { Some(&self.comments) },
+                        cfg,
+                        comments: Some(&self.comments),
                         cm: self.cm.clone(),
-                        wr: Box::new(swc_ecma_codegen::text_writer::JsWriter::with_target(
-                            self.cm.clone(),
-                            "\n",
-                            &mut buf,
-                            if source_map.enabled() {
-                                Some(&mut src_map_buf)
-                            } else {
-                                None
-                            },
-                            target,
-                        )),
+                        wr: Box::new(wr),
                     };
 
                     node.emit_with(&mut emitter)
@@ -275,7 +385,7 @@ impl Compiler {
                         let mut buf = vec![];
 
                         self.cm
-                            .build_source_map_from(&mut src_map_buf, orig)
+                            .build_source_map_with_config(&mut src_map_buf, orig, &gen_config)
                             .to_writer(&mut buf)
                             .context("failed to write source map")?;
                         let map = String::from_utf8(buf).context("source map is not utf-8")?;
@@ -290,7 +400,7 @@ impl Compiler {
                     let mut buf = vec![];
 
                     self.cm
-                        .build_source_map_from(&mut src_map_buf, orig)
+                        .build_source_map_with_config(&mut src_map_buf, orig, &gen_config)
                         .to_writer(&mut buf)
                         .context("failed to write source map file")?;
                     let map = String::from_utf8(buf).context("source map is not utf-8")?;
@@ -305,7 +415,11 @@ impl Compiler {
                 }
             };
 
-            Ok(TransformOutput { code, map })
+            Ok(TransformOutput {
+                code,
+                map,
+                licenses: vec![],
+            })
         })
     }
 }
@@ -417,7 +531,7 @@ impl Compiler {
                 opts.is_module,
                 Some(config),
                 Some(&self.comments),
-            );
+            )?;
             Ok(Some(built))
         })
         .with_context(|| format!("failed to load config for file '{:?}'", name))
@@ -469,6 +583,9 @@ impl Compiler {
                 syntax: config.syntax,
                 target: config.target,
                 minify: config.minify,
+                comments: config.comments,
+                ascii_only: config.ascii_only,
+                max_line_len: config.max_line_len,
                 external_helpers: config.external_helpers,
                 source_maps: config.source_maps,
                 input_source_map: config.input_source_map,
@@ -526,14 +643,16 @@ impl Compiler {
         config: BuiltConfig<impl swc_ecma_visit::Fold>,
     ) -> Result<TransformOutput, Error> {
         self.run(|| {
-            if config.minify {
-                let preserve_excl = |_: &BytePos, vc: &mut Vec<Comment>| -> bool {
-                    vc.retain(|c: &Comment| c.text.starts_with("!"));
-                    !vc.is_empty()
-                };
-                self.comments.leading.retain(preserve_excl);
-                self.comments.trailing.retain(preserve_excl);
-            }
+            let licenses = self.license_comments(program.span());
+
+            let policy = &config.comments;
+            let retain = |_: &BytePos, vc: &mut Vec<Comment>| -> bool {
+                vc.retain(|c: &Comment| policy.retains(c));
+                !vc.is_empty()
+            };
+            self.comments.leading.retain(retain);
+            self.comments.trailing.retain(retain);
+
             let mut pass = config.pass;
             let program = helpers::HELPERS.set(&Helpers::new(config.external_helpers), || {
                 swc_ecma_utils::HANDLER.set(&self.handler, || {
@@ -542,15 +661,47 @@ impl Compiler {
                 })
             });
 
-            self.print(
+            let mut output = self.print_with_config(
                 &program,
                 config.target,
                 config.source_maps,
                 orig,
-                config.minify,
-            )
+                swc_ecma_codegen::Config {
+                    minify: config.minify,
+                    comments: config.comments,
+                    ascii_only: config.ascii_only,
+                    max_line_len: config.max_line_len,
+                    ..Default::default()
+                },
+            )?;
+            output.licenses = licenses;
+
+            Ok(output)
         })
     }
+
+    /// Returns license comments in `span`, in source order.
+    fn license_comments(&self, span: Span) -> Vec<String> {
+        let mut comments = self
+            .comments
+            .leading
+            .iter()
+            .chain(self.comments.trailing.iter())
+            .filter(|entry| span.lo() <= *entry.key() && *entry.key() <= span.hi())
+            .flat_map(|entry| entry.value().clone())
+            .filter(|c| is_license_comment(&c.text))
+            .collect::<Vec<_>>();
+        comments.sort_by_key(|c| c.span.lo());
+        comments.dedup_by_key(|c| c.span.lo());
+
+        comments
+            .into_iter()
+            .map(|c| match c.kind {
+                CommentKind::Block => format!("/*{}*/", c.text),
+                CommentKind::Line => format!("//{}", c.text),
+            })
+            .collect()
+    }
 }
 
 fn load_swcrc(path: &Path) -> Result<Rc, Error> {
@@ -640,3 +791,16 @@ impl Comments for SwcComments {
         self.trailing.remove(&pos).map(|v| v.1)
     }
 }
+
+/// Chains mappings into each file with its input source map.
+struct OrigSourceMaps<'a>(&'a HashMap<FileName, sourcemap::SourceMap>);
+
+impl SourceMapGenConfig for OrigSourceMaps<'_> {
+    fn file_name_to_source(&self, f: &FileName) -> String {
+        DefaultSourceMapGenConfig.file_name_to_source(f)
+    }
+
+    fn orig_source_map(&self, f: &FileName) -> Option<&sourcemap::SourceMap> {
+        self.0.get(f)
+    }
+}
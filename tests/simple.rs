@@ -1,7 +1,10 @@
 use std::sync::Arc;
 use swc::{
-    config::{Config, JscConfig, Options},
-    Compiler,
+    config::{
+        BoolOrObject, CommentsConfig, Config, JscConfig, MinifyCompressOptions,
+        MinifyFormatOptions, MinifyOptions, Options,
+    },
+    Compiler, TransformOutput,
 };
 use swc_common::FileName;
 use swc_ecma_ast::EsVersion;
@@ -10,6 +13,10 @@ use swc_ecma_parser::{EsConfig, Syntax};
 use testing::Tester;
 
 fn compile(src: &str, options: Options) -> String {
+    compile_output(src, options).code
+}
+
+fn compile_output(src: &str, options: Options) -> TransformOutput {
     Tester::new()
         .print_errors(|cm, handler| {
             let c = Compiler::new(cm.clone(), Arc::new(handler));
@@ -28,7 +35,7 @@ fn compile(src: &str, options: Options) -> String {
                     if c.handler.has_errors() {
                         Err(())
                     } else {
-                        Ok(v.code.into())
+                        Ok(v)
                     }
                 }
                 Err(..) => Err(()),
@@ -148,3 +155,97 @@ fn test_tsx_escape_xhtml() {
 
     assert_eq!(compiled_es2020, expected);
 }
+
+#[test]
+fn minify_drop_console() {
+    let output = compile(
+        "console.log('foo'); bar();",
+        Options {
+            config: Config {
+                minify: Some(BoolOrObject::Obj(MinifyOptions {
+                    compress: Some(BoolOrObject::Obj(MinifyCompressOptions {
+                        drop_console: true,
+                        ..Default::default()
+                    })),
+                    ..Default::default()
+                })),
+                ..Default::default()
+            },
+            swcrc: false,
+            ..Default::default()
+        },
+    );
+
+    assert!(!output.contains("console"), "{}", output);
+    assert!(output.contains("bar()"), "{}", output);
+}
+
+#[test]
+fn minify_license_comments() {
+    let output = compile_output(
+        "/*! banner */\n/* normal */\n/** @license MIT */\nfoo();",
+        Options {
+            config: Config {
+                minify: Some(BoolOrObject::Bool(true)),
+                ..Default::default()
+            },
+            swcrc: false,
+            ..Default::default()
+        },
+    );
+
+    assert!(output.code.contains("/*! banner */"), "{}", output.code);
+    assert!(output.code.contains("@license MIT"), "{}", output.code);
+    assert!(!output.code.contains("normal"), "{}", output.code);
+    assert_eq!(
+        output.licenses_txt().as_deref(),
+        Some("/*! banner */\n\n/** @license MIT */\n")
+    );
+}
+
+#[test]
+fn minify_comments_none() {
+    let output = compile_output(
+        "/*! banner */\nfoo();",
+        Options {
+            config: Config {
+                minify: Some(BoolOrObject::Obj(MinifyOptions {
+                    format: Some(MinifyFormatOptions {
+                        comments: Some(CommentsConfig::Bool(false)),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                })),
+                ..Default::default()
+            },
+            swcrc: false,
+            ..Default::default()
+        },
+    );
+
+    assert!(!output.code.contains("banner"), "{}", output.code);
+    assert_eq!(output.licenses, vec![String::from("/*! banner */")]);
+}
+
+#[test]
+fn minify_ascii_only() {
+    let output = compile(
+        "export const greeting = \"héllo\";",
+        Options {
+            config: Config {
+                minify: Some(BoolOrObject::Obj(MinifyOptions {
+                    format: Some(MinifyFormatOptions {
+                        ascii_only: true,
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                })),
+                ..Default::default()
+            },
+            swcrc: false,
+            ..Default::default()
+        },
+    );
+
+    assert!(output.contains("h\\u00e9llo"), "{}", output);
+}
@@ -1,11 +1,14 @@
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::process::Command;
 use std::process::Output;
 use std::{fs::canonicalize, sync::Arc};
 use swc::{
     config::{Options, SourceMapsConfig},
-    Compiler,
+    sourcemap, Compiler,
 };
+use swc_common::FileName;
+use swc_ecma_ast::Program;
 use testing::assert_eq;
 use testing::{StdErr, Tester};
 use walkdir::WalkDir;
@@ -105,6 +108,107 @@ fn issue_706() {
     inline("tests/srcmap/issue-706/index.js").unwrap();
 }
 
+#[test]
+fn chain_orig_maps() {
+    Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), Arc::new(handler));
+
+            let fm = cm.new_source_file(FileName::Real("a.js".into()), "foo();\n".into());
+            let program = c
+                .parse_js(
+                    fm.clone(),
+                    Default::default(),
+                    Default::default(),
+                    true,
+                    false,
+                )
+                .expect("failed to parse");
+
+            let mut builder = sourcemap::SourceMapBuilder::new(None);
+            let src_id = builder.add_source("a.ts");
+            builder.add_raw(0, 0, 4, 2, Some(src_id), None);
+            let mut orig_maps = HashMap::default();
+            orig_maps.insert(fm.name.clone(), builder.into_sourcemap());
+
+            let output = c
+                .print_with_orig_maps(
+                    &program,
+                    Default::default(),
+                    SourceMapsConfig::Bool(true),
+                    &orig_maps,
+                    false,
+                )
+                .expect("failed to print");
+
+            let map = sourcemap::SourceMap::from_slice(output.map.unwrap().as_bytes())
+                .expect("failed to parse source map");
+            let token = map.lookup_token(0, 0).expect("failed to find a token");
+            assert_eq!(token.get_source(), Some("a.ts"));
+            assert_eq!(token.get_src_line(), 4);
+
+            Ok(())
+        })
+        .unwrap();
+}
+
+#[test]
+fn chain_orig_maps_per_token() {
+    Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), Arc::new(handler));
+
+            let fm = cm.new_source_file(FileName::Real("a.js".into()), "foo();\nbar();\n".into());
+            let mut program = c
+                .parse_js(
+                    fm.clone(),
+                    Default::default(),
+                    Default::default(),
+                    true,
+                    false,
+                )
+                .expect("failed to parse");
+            // `bar()` is mapped by the input source map, but `foo()` is not. `foo()` is
+            // emitted after `bar()`.
+            match &mut program {
+                Program::Module(m) => m.body.reverse(),
+                Program::Script(s) => s.body.reverse(),
+            }
+
+            let mut builder = sourcemap::SourceMapBuilder::new(None);
+            let src_id = builder.add_source("a.ts");
+            builder.set_source_contents(src_id, Some("bar();"));
+            builder.add_raw(1, 0, 4, 2, Some(src_id), None);
+            let mut orig_maps = HashMap::default();
+            orig_maps.insert(fm.name.clone(), builder.into_sourcemap());
+
+            let output = c
+                .print_with_orig_maps(
+                    &program,
+                    Default::default(),
+                    SourceMapsConfig::Bool(true),
+                    &orig_maps,
+                    false,
+                )
+                .expect("failed to print");
+
+            let map = sourcemap::SourceMap::from_slice(output.map.unwrap().as_bytes())
+                .expect("failed to parse source map");
+
+            let token = map.lookup_token(0, 0).expect("failed to find a token");
+            assert_eq!(token.get_source(), Some("a.ts"));
+            assert_eq!((token.get_src_line(), token.get_src_col()), (4, 2));
+            assert_eq!(map.get_source_contents(token.get_src_id()), Some("bar();"));
+
+            let token = map.lookup_token(1, 0).expect("failed to find a token");
+            assert_eq!(token.get_source(), Some("a.js"));
+            assert_eq!(token.get_src_line(), 0);
+
+            Ok(())
+        })
+        .unwrap();
+}
+
 #[testing::fixture("stacktrace/**/input/")]
 fn stacktrace(input_dir: PathBuf) {
     Tester::new()
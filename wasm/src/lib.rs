@@ -5,7 +5,7 @@ use std::{
     sync::{Arc, RwLock},
 };
 use swc::{
-    config::{JscTarget, Options, ParseOptions, SourceMapsConfig},
+    config::{BoolOrObject, JscTarget, Options, ParseOptions, SourceMapsConfig},
     Compiler,
 };
 use swc_common::{
@@ -55,7 +55,10 @@ pub fn print_sync(s: JsValue, opts: JsValue) -> Result<JsValue, JsValue> {
                 .clone()
                 .unwrap_or(SourceMapsConfig::Bool(false)),
             None,
-            opts.config.minify.unwrap_or_default(),
+            opts.config
+                .minify
+                .as_ref()
+                .map_or(false, BoolOrObject::is_enabled),
         )
         .map_err(|err| format!("failed to print: {}\n{}", err, errors))?;
 